@@ -1,7 +1,7 @@
 use core::error::Error;
 use std::{
     env,
-    fs::File,
+    fs::{self, File},
     io::Write,
     path::{Path, PathBuf},
 };
@@ -629,6 +629,116 @@ fn build_zmq() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+fn use_system_libzmq() -> bool {
+    cfg!(feature = "system-libzmq")
+        || env::var("ARZMQ_SYS_USE_PKG_CONFIG").is_ok_and(|value| value != "0" && !value.is_empty())
+}
+
+fn vendored_version() -> Result<String, Box<dyn Error>> {
+    let zmq_header = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("vendor")
+        .join("include")
+        .join("zmq.h");
+    let header = fs::read_to_string(zmq_header)?;
+
+    let version_part = |part: &str| {
+        let define = format!("#define ZMQ_VERSION_{part} ");
+        header
+            .lines()
+            .find_map(|line| line.strip_prefix(&define))
+            .map(|value| value.trim().to_string())
+            .ok_or_else(|| format!("ZMQ_VERSION_{part} not found in zmq.h"))
+    };
+
+    Ok(format!(
+        "{}.{}.{}",
+        version_part("MAJOR")?,
+        version_part("MINOR")?,
+        version_part("PATCH")?
+    ))
+}
+
+fn link_system_zmq() -> Result<(), Box<dyn Error>> {
+    // the bindings are generated from the vendored header, so the system library has to provide
+    // at least the same API.
+    let version = vendored_version()?;
+
+    let library = pkg_config::Config::new()
+        .atleast_version(&version)
+        .probe("libzmq")?;
+
+    ["curve", "gssapi", "pgm", "norm", "vmci"]
+        .iter()
+        .filter(|feature| env::var(format!("CARGO_FEATURE_{}", feature.to_uppercase())).is_ok())
+        .for_each(|feature| {
+            println!(
+                "cargo:warning=feature `{feature}` has no effect with the system libzmq {}, its \
+                 capabilities are determined by the library",
+                library.version
+            )
+        });
+
+    // libzmq announces a build with the draft API through the flags in its pkg-config file
+    #[cfg(feature = "draft-api")]
+    if !library.defines.contains_key("ZMQ_BUILD_DRAFT_API") {
+        return Err(format!(
+            "the `draft-api` feature needs a system libzmq built with the draft API, but the \
+             system libzmq {} was built without it",
+            library.version
+        )
+        .into());
+    }
+
+    #[cfg(unix)]
+    link_system_zmq_into_out_dir(&library.link_paths)?;
+
+    library
+        .include_paths
+        .iter()
+        .for_each(|include_dir| println!("cargo:include={}", include_dir.display()));
+    library
+        .link_paths
+        .iter()
+        .for_each(|lib_dir| println!("cargo:lib={}", lib_dir.display()));
+
+    Ok(())
+}
+
+/// The `zmq_has` probe in the build script of `arzmq` loads the library while building. Cargo only
+/// puts library search paths within the target directory on the library path of build scripts,
+/// so the shared libraries of the system libzmq are linked into `OUT_DIR` and searched there.
+#[cfg(unix)]
+fn link_system_zmq_into_out_dir(link_paths: &[PathBuf]) -> Result<(), Box<dyn Error>> {
+    let lib_dir = PathBuf::from(env::var("OUT_DIR")?).join("lib");
+    fs::create_dir_all(&lib_dir)?;
+
+    let is_shared_zmq = |name: &str| {
+        name.starts_with("libzmq.so") || (name.starts_with("libzmq") && name.ends_with(".dylib"))
+    };
+
+    for link_path in link_paths {
+        let Ok(entries) = fs::read_dir(link_path) else {
+            continue;
+        };
+
+        for entry in entries.filter_map(Result::ok) {
+            if !is_shared_zmq(&entry.file_name().to_string_lossy()) {
+                continue;
+            }
+
+            let link = lib_dir.join(entry.file_name());
+            if fs::symlink_metadata(&link).is_ok() {
+                fs::remove_file(&link)?;
+            }
+            std::os::unix::fs::symlink(entry.path(), link)?;
+        }
+    }
+
+    println!("cargo:rustc-link-search=native={}", lib_dir.display());
+
+    Ok(())
+}
+
 fn generate_bindings() -> Result<(), Box<dyn Error>> {
     let vendor_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR")?).join("vendor");
     let include_dir = vendor_dir.join("include");
@@ -663,8 +773,13 @@ fn main() -> Result<(), Box<dyn Error>> {
     println!("cargo:rerun-if-changed=build.rs");
     println!("cargo:rerun-if-env-changed=PROFILE");
     println!("cargo:rerun-if-env-changed=CARGO_CFG_FEATURE");
+    println!("cargo:rerun-if-env-changed=ARZMQ_SYS_USE_PKG_CONFIG");
 
-    build_zmq()?;
+    if use_system_libzmq() {
+        link_system_zmq()?;
+    } else {
+        build_zmq()?;
+    }
 
     generate_bindings()
 }
@@ -2,23 +2,27 @@
 use core::sync::atomic::Ordering;
 
 use arzmq::prelude::{ChannelSocket, Context, ZmqResult};
-use futures::{executor::ThreadPool, join, task::SpawnExt};
+use futures::{executor::ThreadPool, task::SpawnExt};
 
 mod common;
 
 use common::{ITERATIONS, KEEP_RUNNING};
 
-async fn run_channel_server(channel: ChannelSocket, msg: &str) {
+async fn run_channel_server(channel: ChannelSocket, msg: &str) -> ZmqResult<()> {
     while KEEP_RUNNING.load(Ordering::Acquire) {
-        common::run_recv_send_async(&channel, msg).await;
+        common::run_recv_send_async(&channel, msg).await?;
     }
+
+    Ok(())
 }
 
-async fn run_channel_client(channel: ChannelSocket, msg: &str) {
+async fn run_channel_client(channel: ChannelSocket, msg: &str) -> ZmqResult<()> {
     while ITERATIONS.load(Ordering::Acquire) > 0 {
-        common::run_send_recv_async(&channel, msg).await;
+        common::run_send_recv_async(&channel, msg).await?;
     }
     KEEP_RUNNING.store(false, Ordering::Release);
+
+    Ok(())
 }
 
 #[cfg(feature = "examples-futures")]
@@ -40,11 +44,11 @@ fn main() -> ZmqResult<()> {
         let channel_client_handle = executor
             .spawn_with_handle(run_channel_client(channel_client, "Hello"))
             .unwrap();
-        let channel_server_handle = executor
+        let _channel_server_handle = executor
             .spawn_with_handle(run_channel_server(channel_server, "World"))
             .unwrap();
 
-        let _ = join!(channel_server_handle, channel_client_handle);
+        let _ = channel_client_handle.await;
 
         Ok(())
     })
@@ -2,23 +2,27 @@
 use core::sync::atomic::Ordering;
 
 use arzmq::prelude::{ChannelSocket, Context, ZmqResult};
-use tokio::{join, task};
+use tokio::task;
 
 mod common;
 
 use common::{ITERATIONS, KEEP_RUNNING};
 
-async fn run_channel_server(channel: ChannelSocket, msg: &str) {
+async fn run_channel_server(channel: ChannelSocket, msg: &str) -> ZmqResult<()> {
     while KEEP_RUNNING.load(Ordering::Acquire) {
-        common::run_recv_send_async(&channel, msg).await;
+        common::run_recv_send_async(&channel, msg).await?;
     }
+
+    Ok(())
 }
 
-async fn run_channel_client(channel: ChannelSocket, msg: &str) {
+async fn run_channel_client(channel: ChannelSocket, msg: &str) -> ZmqResult<()> {
     while ITERATIONS.load(Ordering::Acquire) > 0 {
-        common::run_send_recv_async(&channel, msg).await;
+        common::run_send_recv_async(&channel, msg).await?;
     }
     KEEP_RUNNING.store(false, Ordering::Release);
+
+    Ok(())
 }
 
 #[tokio::main(flavor = "multi_thread", worker_threads = 2)]
@@ -36,9 +40,9 @@ async fn main() -> ZmqResult<()> {
     channel_client.connect(endpoint)?;
 
     let channel_client_handle = task::spawn(run_channel_client(channel_client, "Hello"));
-    let channel_server_handle = task::spawn(run_channel_server(channel_server, "World"));
+    let _channel_server_handle = task::spawn(run_channel_server(channel_server, "World"));
 
-    let _ = join!(channel_server_handle, channel_client_handle);
+    let _ = channel_client_handle.await;
 
     Ok(())
 }
@@ -25,7 +25,7 @@ fn main() -> ZmqResult<()> {
 
     let server = ServerSocket::from_context(&context)?;
     server.bind("tcp://127.0.0.1:*")?;
-    let client_endpoint = server.last_endpoint()?;
+    let client_endpoint = server.last_endpoint()?.unwrap();
 
     thread::spawn(move || {
         while KEEP_RUNNING.load(Ordering::Acquire) {
@@ -38,22 +38,19 @@ where
 
 #[allow(dead_code)]
 #[cfg(feature = "futures")]
-pub async fn run_send_recv_async<S>(send_recv: &S, msg: &str)
+pub async fn run_send_recv_async<S>(send_recv: &S, msg: &str) -> ZmqResult<()>
 where
     S: Sender + Receiver + Sync,
 {
     println!("Sending message: {msg:?}");
-    let _ = send_recv.send_msg_async(msg, SendFlags::empty()).await;
+    send_recv.send_msg_async(msg, SendFlags::empty()).await?;
 
-    loop {
-        if let Some(message) = send_recv.recv_msg_async().await {
-            println!("Received mesaage: {message:?}");
+    let message = send_recv.recv_msg_async().await?;
+    println!("Received mesaage: {message:?}");
 
-            ITERATIONS.fetch_sub(1, Ordering::Release);
+    ITERATIONS.fetch_sub(1, Ordering::Release);
 
-            break;
-        }
-    }
+    Ok(())
 }
 
 #[allow(dead_code)]
@@ -69,14 +66,14 @@ where
 
 #[allow(dead_code)]
 #[cfg(feature = "futures")]
-pub async fn run_recv_send_async<S>(send_recv: &S, msg: &str)
+pub async fn run_recv_send_async<S>(send_recv: &S, msg: &str) -> ZmqResult<()>
 where
     S: Sender + Receiver + Sync,
 {
-    if let Some(message) = send_recv.recv_msg_async().await {
-        println!("Received request: {message:?}");
-        send_recv.send_msg_async(msg, SendFlags::empty()).await;
-    }
+    let message = send_recv.recv_msg_async().await?;
+    println!("Received request: {message:?}");
+
+    send_recv.send_msg_async(msg, SendFlags::empty()).await
 }
 
 #[allow(dead_code)]
@@ -99,23 +96,25 @@ where
 
 #[allow(dead_code)]
 #[cfg(feature = "futures")]
-pub async fn run_multipart_send_recv_async<S>(send_recv: &S, msg: &str)
+pub async fn run_multipart_send_recv_async<S>(send_recv: &S, msg: &str) -> ZmqResult<()>
 where
     S: MultipartReceiver + MultipartSender + Sync,
 {
     println!("Sending message {msg:?}");
     let multipart: Vec<Message> = vec![vec![].into(), msg.into()];
-    let _ = send_recv
+    send_recv
         .send_multipart_async(multipart, SendFlags::empty())
-        .await;
+        .await?;
 
-    let mut message = send_recv.recv_multipart_async().await;
+    let mut message = send_recv.recv_multipart_async().await?;
     let content = message.pop_back().unwrap();
     if !content.is_empty() {
         println!("Received reply: {content:?}",);
 
         ITERATIONS.fetch_sub(1, Ordering::Release);
     }
+
+    Ok(())
 }
 
 #[allow(dead_code)]
@@ -136,11 +135,11 @@ where
 
 #[allow(dead_code)]
 #[cfg(feature = "futures")]
-pub async fn run_multipart_recv_reply_async<S>(recv_send: &S, msg: &str)
+pub async fn run_multipart_recv_reply_async<S>(recv_send: &S, msg: &str) -> ZmqResult<()>
 where
     S: MultipartSender + MultipartReceiver + Sync,
 {
-    let mut multipart = recv_send.recv_multipart_async().await;
+    let mut multipart = recv_send.recv_multipart_async().await?;
     let content = multipart.pop_back().unwrap();
     if !content.is_empty() {
         println!("Received request: {content:?}");
@@ -148,7 +147,7 @@ where
     multipart.push_back(msg.into());
     recv_send
         .send_multipart_async(multipart, SendFlags::empty())
-        .await;
+        .await
 }
 
 #[allow(dead_code)]
@@ -169,18 +168,19 @@ where
 
 #[allow(dead_code)]
 #[cfg(feature = "futures")]
-pub async fn run_subscribe_client_async<S>(socket: &S, subscribed_topic: &str)
+pub async fn run_subscribe_client_async<S>(socket: &S, subscribed_topic: &str) -> ZmqResult<()>
 where
     S: Receiver + Sync,
 {
-    if let Some(zmq_msg) = socket.recv_msg_async().await {
-        let zmq_str = zmq_msg.to_string();
-        let pubsub_item = zmq_str.split_once(" ");
-        assert_eq!(Some((subscribed_topic, "important update")), pubsub_item);
+    let zmq_msg = socket.recv_msg_async().await?;
+    let zmq_str = zmq_msg.to_string();
+    let pubsub_item = zmq_str.split_once(" ");
+    assert_eq!(Some((subscribed_topic, "important update")), pubsub_item);
 
-        let (topic, item) = pubsub_item.unwrap();
-        println!("Received msg for topic {topic:?}: {item}",);
+    let (topic, item) = pubsub_item.unwrap();
+    println!("Received msg for topic {topic:?}: {item}",);
 
-        ITERATIONS.fetch_sub(1, Ordering::Release);
-    }
+    ITERATIONS.fetch_sub(1, Ordering::Release);
+
+    Ok(())
 }
@@ -11,7 +11,7 @@ fn main() -> ZmqResult<()> {
 
     let dealer_server = DealerSocket::from_context(&context)?;
     dealer_server.bind("tcp://127.0.0.1:*")?;
-    let client_endpoint = dealer_server.last_endpoint()?;
+    let client_endpoint = dealer_server.last_endpoint()?.unwrap();
 
     thread::spawn(move || {
         (0..iterations)
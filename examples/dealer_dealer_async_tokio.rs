@@ -2,22 +2,26 @@
 use core::sync::atomic::Ordering;
 
 use arzmq::prelude::{Context, DealerSocket, ZmqResult};
-use tokio::{join, task};
+use tokio::task;
 
 mod common;
 
 use common::ITERATIONS;
 
-async fn run_dealer_server(dealer: DealerSocket, msg: &str) {
+async fn run_dealer_server(dealer: DealerSocket, msg: &str) -> ZmqResult<()> {
     while ITERATIONS.load(Ordering::Acquire) > 1 {
-        common::run_multipart_recv_reply_async(&dealer, msg).await;
+        common::run_multipart_recv_reply_async(&dealer, msg).await?;
     }
+
+    Ok(())
 }
 
-async fn run_dealer_client(dealer: DealerSocket, msg: &str) {
+async fn run_dealer_client(dealer: DealerSocket, msg: &str) -> ZmqResult<()> {
     while ITERATIONS.load(Ordering::Acquire) > 0 {
-        common::run_multipart_send_recv_async(&dealer, msg).await;
+        common::run_multipart_send_recv_async(&dealer, msg).await?;
     }
+
+    Ok(())
 }
 
 #[tokio::main(flavor = "multi_thread", worker_threads = 2)]
@@ -28,15 +32,15 @@ async fn main() -> ZmqResult<()> {
 
     let dealer_server = DealerSocket::from_context(&context)?;
     dealer_server.bind("tcp://127.0.0.1:*")?;
-    let client_endpoint = dealer_server.last_endpoint()?;
+    let client_endpoint = dealer_server.last_endpoint()?.unwrap();
 
     let dealer_client = DealerSocket::from_context(&context)?;
     dealer_client.connect(client_endpoint)?;
 
     let client_handle = task::spawn(run_dealer_client(dealer_client, "Hello"));
-    let server_handle = task::spawn(run_dealer_server(dealer_server, "World"));
+    let _server_handle = task::spawn(run_dealer_server(dealer_server, "World"));
 
-    let _ = join!(server_handle, client_handle);
+    let _ = client_handle.await;
 
     Ok(())
 }
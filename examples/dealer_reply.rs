@@ -11,7 +11,7 @@ fn main() -> ZmqResult<()> {
 
     let reply = ReplySocket::from_context(&context)?;
     reply.bind("tcp://127.0.0.1:*")?;
-    let dealer_endpoint = reply.last_endpoint()?;
+    let dealer_endpoint = reply.last_endpoint()?.unwrap();
 
     thread::spawn(move || {
         (0..iterations)
@@ -2,22 +2,26 @@
 use core::sync::atomic::Ordering;
 
 use arzmq::prelude::{Context, DealerSocket, RouterSocket, ZmqResult};
-use futures::{executor::ThreadPool, join, task::SpawnExt};
+use futures::{executor::ThreadPool, task::SpawnExt};
 
 mod common;
 
 use common::ITERATIONS;
 
-async fn run_router(router: RouterSocket, msg: &str) {
+async fn run_router(router: RouterSocket, msg: &str) -> ZmqResult<()> {
     while ITERATIONS.load(Ordering::Acquire) > 1 {
-        common::run_multipart_recv_reply_async(&router, msg).await;
+        common::run_multipart_recv_reply_async(&router, msg).await?;
     }
+
+    Ok(())
 }
 
-async fn run_dealer(dealer: DealerSocket, msg: &str) {
+async fn run_dealer(dealer: DealerSocket, msg: &str) -> ZmqResult<()> {
     while ITERATIONS.load(Ordering::Acquire) > 0 {
-        common::run_multipart_send_recv_async(&dealer, msg).await;
+        common::run_multipart_send_recv_async(&dealer, msg).await?;
     }
+
+    Ok(())
 }
 
 #[cfg(feature = "examples-futures")]
@@ -30,7 +34,7 @@ fn main() -> ZmqResult<()> {
 
         let router = RouterSocket::from_context(&context)?;
         router.bind("tcp://127.0.0.1:*")?;
-        let dealer_endpoint = router.last_endpoint()?;
+        let dealer_endpoint = router.last_endpoint()?.unwrap();
 
         let dealer = DealerSocket::from_context(&context)?;
         dealer.connect(dealer_endpoint)?;
@@ -38,11 +42,11 @@ fn main() -> ZmqResult<()> {
         let dealer_handle = executor
             .spawn_with_handle(run_dealer(dealer, "Hello"))
             .unwrap();
-        let reply_handle = executor
+        let _reply_handle = executor
             .spawn_with_handle(run_router(router, "World"))
             .unwrap();
 
-        let _ = join!(reply_handle, dealer_handle);
+        let _ = dealer_handle.await;
 
         Ok(())
     })
@@ -2,22 +2,26 @@
 use core::sync::atomic::Ordering;
 
 use arzmq::prelude::{Context, DealerSocket, RouterSocket, ZmqResult};
-use tokio::{join, task};
+use tokio::task;
 
 mod common;
 
 use common::ITERATIONS;
 
-async fn run_router(router: RouterSocket, msg: &str) {
+async fn run_router(router: RouterSocket, msg: &str) -> ZmqResult<()> {
     while ITERATIONS.load(Ordering::Acquire) > 1 {
-        common::run_multipart_recv_reply_async(&router, msg).await;
+        common::run_multipart_recv_reply_async(&router, msg).await?;
     }
+
+    Ok(())
 }
 
-async fn run_dealer_client(dealer: DealerSocket, msg: &str) {
+async fn run_dealer_client(dealer: DealerSocket, msg: &str) -> ZmqResult<()> {
     while ITERATIONS.load(Ordering::Acquire) > 0 {
-        common::run_multipart_send_recv_async(&dealer, msg).await;
+        common::run_multipart_send_recv_async(&dealer, msg).await?;
     }
+
+    Ok(())
 }
 
 #[tokio::main(flavor = "multi_thread", worker_threads = 2)]
@@ -28,15 +32,15 @@ async fn main() -> ZmqResult<()> {
 
     let router = RouterSocket::from_context(&context)?;
     router.bind("tcp://127.0.0.1:*")?;
-    let dealer_endpoint = router.last_endpoint()?;
+    let dealer_endpoint = router.last_endpoint()?.unwrap();
 
     let dealer = DealerSocket::from_context(&context)?;
     dealer.connect(dealer_endpoint)?;
 
     let client_handle = task::spawn(run_dealer_client(dealer, "Hello"));
-    let server_handle = task::spawn(run_router(router, "World"));
+    let _server_handle = task::spawn(run_router(router, "World"));
 
-    let _ = join!(server_handle, client_handle);
+    let _ = client_handle.await;
 
     Ok(())
 }
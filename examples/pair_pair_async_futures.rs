@@ -2,23 +2,27 @@
 use core::sync::atomic::Ordering;
 
 use arzmq::prelude::{Context, PairSocket, ZmqResult};
-use futures::{executor::ThreadPool, join, task::SpawnExt};
+use futures::{executor::ThreadPool, task::SpawnExt};
 
 mod common;
 
 use common::{ITERATIONS, KEEP_RUNNING};
 
-async fn run_pair_server(pair: PairSocket, msg: &str) {
+async fn run_pair_server(pair: PairSocket, msg: &str) -> ZmqResult<()> {
     while KEEP_RUNNING.load(Ordering::Acquire) {
-        common::run_recv_send_async(&pair, msg).await;
+        common::run_recv_send_async(&pair, msg).await?;
     }
+
+    Ok(())
 }
 
-async fn run_pair_client(pair: PairSocket, msg: &str) {
+async fn run_pair_client(pair: PairSocket, msg: &str) -> ZmqResult<()> {
     while ITERATIONS.load(Ordering::Acquire) > 0 {
-        common::run_send_recv_async(&pair, msg).await;
+        common::run_send_recv_async(&pair, msg).await?;
     }
     KEEP_RUNNING.store(false, Ordering::Release);
+
+    Ok(())
 }
 
 #[cfg(feature = "examples-futures")]
@@ -40,11 +44,11 @@ fn main() -> ZmqResult<()> {
         let pair_client_handle = executor
             .spawn_with_handle(run_pair_client(pair_client, "Hello"))
             .unwrap();
-        let pair_server_handle = executor
+        let _pair_server_handle = executor
             .spawn_with_handle(run_pair_server(pair_server, "World"))
             .unwrap();
 
-        let _ = join!(pair_server_handle, pair_client_handle);
+        let _ = pair_client_handle.await;
 
         Ok(())
     })
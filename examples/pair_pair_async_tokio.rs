@@ -2,23 +2,27 @@
 use core::sync::atomic::Ordering;
 
 use arzmq::prelude::{Context, PairSocket, ZmqResult};
-use tokio::{join, task};
+use tokio::task;
 
 mod common;
 
 use common::{ITERATIONS, KEEP_RUNNING};
 
-async fn run_pair_server(pair: PairSocket, msg: &str) {
+async fn run_pair_server(pair: PairSocket, msg: &str) -> ZmqResult<()> {
     while KEEP_RUNNING.load(Ordering::Acquire) {
-        common::run_recv_send_async(&pair, msg).await;
+        common::run_recv_send_async(&pair, msg).await?;
     }
+
+    Ok(())
 }
 
-async fn run_pair_client(pair: PairSocket, msg: &str) {
+async fn run_pair_client(pair: PairSocket, msg: &str) -> ZmqResult<()> {
     while ITERATIONS.load(Ordering::Acquire) > 0 {
-        common::run_send_recv_async(&pair, msg).await;
+        common::run_send_recv_async(&pair, msg).await?;
     }
     KEEP_RUNNING.store(false, Ordering::Release);
+
+    Ok(())
 }
 
 #[tokio::main(flavor = "multi_thread", worker_threads = 2)]
@@ -36,9 +40,9 @@ async fn main() -> ZmqResult<()> {
     pair_client.connect(endpoint)?;
 
     let pair_client_handle = task::spawn(run_pair_client(pair_client, "Hello"));
-    let pair_server_handle = task::spawn(run_pair_server(pair_server, "World"));
+    let _pair_server_handle = task::spawn(run_pair_server(pair_server, "World"));
 
-    let _ = join!(pair_server_handle, pair_client_handle);
+    let _ = pair_client_handle.await;
 
     Ok(())
 }
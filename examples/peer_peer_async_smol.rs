@@ -5,7 +5,6 @@ use arzmq::{
     message::Message,
     prelude::{Context, PeerSocket, Receiver, SendFlags, Sender, ZmqResult},
 };
-use futures::join;
 use smol_macros::{Executor, main};
 
 mod common;
@@ -14,13 +13,12 @@ use common::{ITERATIONS, KEEP_RUNNING};
 
 async fn run_peer_server(peer: PeerSocket, msg: &str) -> ZmqResult<()> {
     while KEEP_RUNNING.load(Ordering::Acquire) {
-        if let Some(message) = peer.recv_msg_async().await {
-            println!("Received request: {message:?}");
+        let message = peer.recv_msg_async().await?;
+        println!("Received request: {message:?}");
 
-            let response: Message = msg.into();
-            response.set_routing_id(message.routing_id().unwrap())?;
-            peer.send_msg_async(response, SendFlags::empty()).await;
-        }
+        let response: Message = msg.into();
+        response.set_routing_id(message.routing_id().unwrap())?;
+        peer.send_msg_async(response, SendFlags::empty()).await?;
     }
 
     Ok(())
@@ -31,17 +29,12 @@ async fn run_peer_client(peer: PeerSocket, routing_id: u32, msg: &str) -> ZmqRes
         println!("Sending message: {msg:?}");
         let message: Message = msg.into();
         message.set_routing_id(routing_id)?;
-        let _ = peer.send_msg_async(message, SendFlags::empty()).await;
+        peer.send_msg_async(message, SendFlags::empty()).await?;
 
-        loop {
-            if let Some(message) = peer.recv_msg_async().await {
-                println!("Received mesaage: {message:?}");
+        let message = peer.recv_msg_async().await?;
+        println!("Received mesaage: {message:?}");
 
-                ITERATIONS.fetch_sub(1, Ordering::Release);
-
-                break;
-            }
-        }
+        ITERATIONS.fetch_sub(1, Ordering::Release);
     }
     KEEP_RUNNING.store(false, Ordering::Release);
 
@@ -63,9 +56,9 @@ main! {
         let routing_id = peer_client.connect_peer(endpoint)?;
 
         let peer_client_handle = executor.spawn(run_peer_client(peer_client, routing_id, "Hello"));
-        let peer_server_handle = executor.spawn(run_peer_server(peer_server, "World"));
+        let _peer_server_handle = executor.spawn(run_peer_server(peer_server, "World"));
 
-        let _ = join!(peer_server_handle, peer_client_handle);
+        let _ = peer_client_handle.await;
 
         Ok(())
     }
@@ -16,7 +16,7 @@ fn main() -> ZmqResult<()> {
 
     let publish = PublishSocket::from_context(&context)?;
     publish.bind("tcp://127.0.0.1:*")?;
-    let xsubscribe_endpoint = publish.last_endpoint()?;
+    let xsubscribe_endpoint = publish.last_endpoint()?.unwrap();
 
     thread::spawn(move || {
         let published_msg = format!("{SUBSCRIBED_TOPIC} important update");
@@ -2,23 +2,27 @@
 use core::sync::atomic::Ordering;
 
 use arzmq::prelude::{Context, PublishSocket, SendFlags, Sender, XSubscribeSocket, ZmqResult};
-use futures::{executor::ThreadPool, join, task::SpawnExt};
+use futures::{executor::ThreadPool, task::SpawnExt};
 
 mod common;
 
 use common::{ITERATIONS, KEEP_RUNNING};
 
-async fn run_subscriber(subscribe: XSubscribeSocket, subscribed_topic: &str) {
+async fn run_subscriber(subscribe: XSubscribeSocket, subscribed_topic: &str) -> ZmqResult<()> {
     while ITERATIONS.load(Ordering::Acquire) > 0 {
-        common::run_subscribe_client_async(&subscribe, subscribed_topic).await;
+        common::run_subscribe_client_async(&subscribe, subscribed_topic).await?;
     }
     KEEP_RUNNING.store(false, Ordering::Release);
+
+    Ok(())
 }
 
-async fn run_publisher(publisher: PublishSocket, msg: &str) {
+async fn run_publisher(publisher: PublishSocket, msg: &str) -> ZmqResult<()> {
     while KEEP_RUNNING.load(Ordering::Acquire) {
-        publisher.send_msg_async(msg, SendFlags::DONT_WAIT).await;
+        publisher.send_msg_async(msg, SendFlags::DONT_WAIT).await?;
     }
+
+    Ok(())
 }
 
 #[cfg(feature = "examples-futures")]
@@ -31,20 +35,20 @@ fn main() -> ZmqResult<()> {
 
         let publish = PublishSocket::from_context(&context)?;
         publish.bind("tcp://127.0.0.1:*")?;
-        let xsubscribe_endpoint = publish.last_endpoint()?;
+        let xsubscribe_endpoint = publish.last_endpoint()?.unwrap();
 
         let xsubscribe = XSubscribeSocket::from_context(&context)?;
         xsubscribe.subscribe("arzmq-example")?;
         xsubscribe.connect(xsubscribe_endpoint)?;
 
-        let publish_handle = executor
+        let _publish_handle = executor
             .spawn_with_handle(run_publisher(publish, "arzmq-example important update"))
             .unwrap();
         let subscribe_handle = executor
             .spawn_with_handle(run_subscriber(xsubscribe, "arzmq-example"))
             .unwrap();
 
-        let _ = join!(publish_handle, subscribe_handle);
+        let _ = subscribe_handle.await;
 
         Ok(())
     })
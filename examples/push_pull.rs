@@ -14,7 +14,7 @@ fn main() -> ZmqResult<()> {
 
     let push = PushSocket::from_context(&context)?;
     push.bind("tcp://127.0.0.1:*")?;
-    let pull_endpoint = push.last_endpoint()?;
+    let pull_endpoint = push.last_endpoint()?.unwrap();
 
     thread::spawn(move || common::run_publisher(&push, "important update").unwrap());
 
@@ -5,26 +5,29 @@ use arzmq::{
     ZmqResult,
     prelude::{Context, PullSocket, PushSocket, Receiver, SendFlags, Sender},
 };
-use futures::{executor::ThreadPool, join, task::SpawnExt};
+use futures::{executor::ThreadPool, task::SpawnExt};
 
 mod common;
 
 use common::{ITERATIONS, KEEP_RUNNING};
 
-async fn run_subscriber(pull: PullSocket) {
+async fn run_subscriber(pull: PullSocket) -> ZmqResult<()> {
     while ITERATIONS.load(Ordering::Acquire) > 0 {
-        if let Some(message) = pull.recv_msg_async().await {
-            println!("Received message: {message:?}");
-            ITERATIONS.fetch_sub(1, Ordering::Release);
-        }
+        let message = pull.recv_msg_async().await?;
+        println!("Received message: {message:?}");
+        ITERATIONS.fetch_sub(1, Ordering::Release);
     }
     KEEP_RUNNING.store(false, Ordering::Release);
+
+    Ok(())
 }
 
-async fn run_publisher(push: PushSocket, msg: &str) {
+async fn run_publisher(push: PushSocket, msg: &str) -> ZmqResult<()> {
     while KEEP_RUNNING.load(Ordering::Acquire) {
-        push.send_msg_async(msg, SendFlags::DONT_WAIT).await;
+        push.send_msg_async(msg, SendFlags::DONT_WAIT).await?;
     }
+
+    Ok(())
 }
 
 #[cfg(feature = "examples-futures")]
@@ -37,17 +40,17 @@ fn main() -> ZmqResult<()> {
 
         let push = PushSocket::from_context(&context)?;
         push.bind("tcp://127.0.0.1:*")?;
-        let pull_endpoint = push.last_endpoint()?;
+        let pull_endpoint = push.last_endpoint()?.unwrap();
 
         let pull = PullSocket::from_context(&context)?;
         pull.connect(pull_endpoint)?;
 
-        let push_handle = executor
+        let _push_handle = executor
             .spawn_with_handle(run_publisher(push, "important update"))
             .unwrap();
         let pull_handle = executor.spawn_with_handle(run_subscriber(pull)).unwrap();
 
-        let _ = join!(push_handle, pull_handle);
+        let _ = pull_handle.await;
 
         Ok(())
     })
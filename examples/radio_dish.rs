@@ -25,7 +25,7 @@ fn main() -> ZmqResult<()> {
 
     let radio = RadioSocket::from_context(&context)?;
     radio.bind("tcp://127.0.0.1:*")?;
-    let dish_endpoint = radio.last_endpoint()?;
+    let dish_endpoint = radio.last_endpoint()?.unwrap();
 
     thread::spawn(move || {
         while KEEP_RUNNING.load(Ordering::Acquire) {
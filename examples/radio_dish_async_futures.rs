@@ -5,7 +5,7 @@ use arzmq::{
     ZmqResult,
     prelude::{Context, DishSocket, Message, RadioSocket, Receiver, SendFlags, Sender},
 };
-use futures::{executor::ThreadPool, join, task::SpawnExt};
+use futures::{executor::ThreadPool, task::SpawnExt};
 
 mod common;
 
@@ -13,21 +13,22 @@ use common::{ITERATIONS, KEEP_RUNNING};
 
 static GROUP: &str = "radio-dish-ex";
 
-async fn run_dish(dish: DishSocket) {
+async fn run_dish(dish: DishSocket) -> ZmqResult<()> {
     while ITERATIONS.load(Ordering::Acquire) > 0 {
-        if let Some(message) = dish.recv_msg_async().await {
-            println!("Received message: {message:?}");
-            ITERATIONS.fetch_sub(1, Ordering::Release);
-        }
+        let message = dish.recv_msg_async().await?;
+        println!("Received message: {message:?}");
+        ITERATIONS.fetch_sub(1, Ordering::Release);
     }
     KEEP_RUNNING.store(false, Ordering::Release);
+
+    Ok(())
 }
 
 async fn run_radio(radio: RadioSocket, msg: &str) -> ZmqResult<()> {
     while KEEP_RUNNING.load(Ordering::Acquire) {
         let message: Message = msg.into();
         message.set_group(GROUP)?;
-        radio.send_msg_async(message, SendFlags::DONT_WAIT).await;
+        radio.send_msg_async(message, SendFlags::DONT_WAIT).await?;
     }
 
     Ok(())
@@ -43,18 +44,18 @@ fn main() -> ZmqResult<()> {
 
         let radio = RadioSocket::from_context(&context)?;
         radio.bind("tcp://127.0.0.1:*")?;
-        let dish_endpoint = radio.last_endpoint()?;
+        let dish_endpoint = radio.last_endpoint()?.unwrap();
 
         let dish = DishSocket::from_context(&context)?;
         dish.connect(dish_endpoint)?;
         dish.join(GROUP)?;
 
-        let radio_handle = executor
+        let _radio_handle = executor
             .spawn_with_handle(run_radio(radio, "important update"))
             .unwrap();
         let dish_handle = executor.spawn_with_handle(run_dish(dish)).unwrap();
 
-        let _ = join!(radio_handle, dish_handle);
+        let _ = dish_handle.await;
 
         Ok(())
     })
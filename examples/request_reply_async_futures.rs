@@ -2,23 +2,27 @@
 use core::sync::atomic::Ordering;
 
 use arzmq::prelude::{Context, ReplySocket, RequestSocket, ZmqResult};
-use futures::{executor::ThreadPool, join, task::SpawnExt};
+use futures::{executor::ThreadPool, task::SpawnExt};
 
 mod common;
 
 use common::{ITERATIONS, KEEP_RUNNING};
 
-async fn run_replier(reply: ReplySocket, msg: &str) {
+async fn run_replier(reply: ReplySocket, msg: &str) -> ZmqResult<()> {
     while KEEP_RUNNING.load(Ordering::Acquire) {
-        common::run_recv_send_async(&reply, msg).await;
+        common::run_recv_send_async(&reply, msg).await?;
     }
+
+    Ok(())
 }
 
-async fn run_requester(request: RequestSocket, msg: &str) {
+async fn run_requester(request: RequestSocket, msg: &str) -> ZmqResult<()> {
     while ITERATIONS.load(Ordering::Acquire) > 0 {
-        common::run_send_recv_async(&request, msg).await;
+        common::run_send_recv_async(&request, msg).await?;
     }
     KEEP_RUNNING.store(false, Ordering::Release);
+
+    Ok(())
 }
 
 #[cfg(feature = "examples-futures")]
@@ -31,7 +35,7 @@ fn main() -> ZmqResult<()> {
 
         let reply = ReplySocket::from_context(&context)?;
         reply.bind("tcp://127.0.0.1:*")?;
-        let request_endpoint = reply.last_endpoint()?;
+        let request_endpoint = reply.last_endpoint()?.unwrap();
 
         let request = RequestSocket::from_context(&context)?;
         request.connect(request_endpoint)?;
@@ -39,11 +43,11 @@ fn main() -> ZmqResult<()> {
         let request_handle = executor
             .spawn_with_handle(run_requester(request, "Hello"))
             .unwrap();
-        let reply_handle = executor
+        let _reply_handle = executor
             .spawn_with_handle(run_replier(reply, "World"))
             .unwrap();
 
-        let _ = join!(reply_handle, request_handle);
+        let _ = request_handle.await;
 
         Ok(())
     })
@@ -2,24 +2,27 @@
 use core::sync::atomic::Ordering;
 
 use arzmq::prelude::{Context, ReplySocket, RequestSocket, ZmqResult};
-use futures::join;
 use smol_macros::{Executor, main};
 
 mod common;
 
 use common::{ITERATIONS, KEEP_RUNNING};
 
-async fn run_replier(reply: ReplySocket, msg: &str) {
+async fn run_replier(reply: ReplySocket, msg: &str) -> ZmqResult<()> {
     while KEEP_RUNNING.load(Ordering::Acquire) {
-        common::run_recv_send_async(&reply, msg).await;
+        common::run_recv_send_async(&reply, msg).await?;
     }
+
+    Ok(())
 }
 
-async fn run_requester(request: RequestSocket, msg: &str) {
+async fn run_requester(request: RequestSocket, msg: &str) -> ZmqResult<()> {
     while ITERATIONS.load(Ordering::Acquire) > 0 {
-        common::run_send_recv_async(&request, msg).await;
+        common::run_send_recv_async(&request, msg).await?;
     }
     KEEP_RUNNING.store(false, Ordering::Release);
+
+    Ok(())
 }
 
 main! {
@@ -30,15 +33,15 @@ main! {
 
         let reply = ReplySocket::from_context(&context)?;
         reply.bind("tcp://127.0.0.1:*")?;
-        let request_endpoint = reply.last_endpoint()?;
+        let request_endpoint = reply.last_endpoint()?.unwrap();
 
         let request = RequestSocket::from_context(&context)?;
         request.connect(request_endpoint)?;
 
         let request_handle = executor.spawn(run_requester(request, "Hello"));
-        let reply_handle = executor.spawn(run_replier(reply, "World"));
+        let _reply_handle = executor.spawn(run_replier(reply, "World"));
 
-        let _ = join!(reply_handle, request_handle);
+        let _ = request_handle.await;
 
         Ok(())
     }
@@ -11,7 +11,7 @@ fn main() -> ZmqResult<()> {
 
     let router = RouterSocket::from_context(&context)?;
     router.bind("tcp://127.0.0.1:*")?;
-    let request_endpoint = router.last_endpoint()?;
+    let request_endpoint = router.last_endpoint()?.unwrap();
 
     thread::spawn(move || {
         (0..iterations)
@@ -2,23 +2,27 @@
 use core::sync::atomic::Ordering;
 
 use arzmq::prelude::{Context, RequestSocket, RouterSocket, ZmqResult};
-use tokio::{join, task};
+use tokio::task;
 
 mod common;
 
 use common::{ITERATIONS, KEEP_RUNNING};
 
-async fn run_router(router: RouterSocket, msg: &str) {
+async fn run_router(router: RouterSocket, msg: &str) -> ZmqResult<()> {
     while ITERATIONS.load(Ordering::Acquire) > 1 {
-        common::run_multipart_recv_reply_async(&router, msg).await;
+        common::run_multipart_recv_reply_async(&router, msg).await?;
     }
+
+    Ok(())
 }
 
-async fn run_requester(request: RequestSocket, msg: &str) {
+async fn run_requester(request: RequestSocket, msg: &str) -> ZmqResult<()> {
     while ITERATIONS.load(Ordering::Acquire) > 0 {
-        common::run_send_recv_async(&request, msg).await;
+        common::run_send_recv_async(&request, msg).await?;
     }
     KEEP_RUNNING.store(false, Ordering::Release);
+
+    Ok(())
 }
 
 #[tokio::main(flavor = "multi_thread", worker_threads = 2)]
@@ -29,15 +33,15 @@ async fn main() -> ZmqResult<()> {
 
     let router = RouterSocket::from_context(&context)?;
     router.bind("tcp://127.0.0.1:*")?;
-    let request_endpoint = router.last_endpoint()?;
+    let request_endpoint = router.last_endpoint()?.unwrap();
 
     let request = RequestSocket::from_context(&context)?;
     request.connect(request_endpoint)?;
 
     let request_handle = task::spawn(run_requester(request, "Hello"));
-    let router_handle = task::spawn(run_router(router, "World"));
+    let _router_handle = task::spawn(run_router(router, "World"));
 
-    let _ = join!(router_handle, request_handle);
+    let _ = request_handle.await;
 
     Ok(())
 }
@@ -16,7 +16,7 @@ fn main() -> ZmqResult<()> {
 
     let scatter = ScatterSocket::from_context(&context)?;
     scatter.bind("tcp://127.0.0.1:*")?;
-    let gather_endpoint = scatter.last_endpoint()?;
+    let gather_endpoint = scatter.last_endpoint()?.unwrap();
 
     thread::spawn(move || {
         common::run_publisher(&scatter, "important update").unwrap();
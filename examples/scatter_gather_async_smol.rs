@@ -5,27 +5,29 @@ use arzmq::{
     ZmqResult,
     prelude::{Context, GatherSocket, Receiver, ScatterSocket, SendFlags, Sender},
 };
-use futures::join;
 use smol_macros::{Executor, main};
 
 mod common;
 
 use common::{ITERATIONS, KEEP_RUNNING};
 
-async fn run_gather(gather: GatherSocket) {
+async fn run_gather(gather: GatherSocket) -> ZmqResult<()> {
     while ITERATIONS.load(Ordering::Acquire) > 0 {
-        if let Some(message) = gather.recv_msg_async().await {
-            println!("Received message: {message:?}");
-            ITERATIONS.fetch_sub(1, Ordering::Release);
-        }
+        let message = gather.recv_msg_async().await?;
+        println!("Received message: {message:?}");
+        ITERATIONS.fetch_sub(1, Ordering::Release);
     }
     KEEP_RUNNING.store(false, Ordering::Release);
+
+    Ok(())
 }
 
-async fn run_scatter(scatter: ScatterSocket, msg: &str) {
+async fn run_scatter(scatter: ScatterSocket, msg: &str) -> ZmqResult<()> {
     while KEEP_RUNNING.load(Ordering::Acquire) {
-        scatter.send_msg_async(msg, SendFlags::DONT_WAIT).await;
+        scatter.send_msg_async(msg, SendFlags::DONT_WAIT).await?;
     }
+
+    Ok(())
 }
 
 main! {
@@ -36,15 +38,15 @@ main! {
 
         let scatter = ScatterSocket::from_context(&context)?;
         scatter.bind("tcp://127.0.0.1:*")?;
-        let gather_endpoint = scatter.last_endpoint()?;
+        let gather_endpoint = scatter.last_endpoint()?.unwrap();
 
         let gather = GatherSocket::from_context(&context)?;
         gather.connect(gather_endpoint)?;
 
-        let scatter_handle = executor.spawn(run_scatter(scatter, "important update"));
+        let _scatter_handle = executor.spawn(run_scatter(scatter, "important update"));
         let gather_handle = executor.spawn(run_gather(gather));
 
-        let _ = join!(scatter_handle, gather_handle);
+        let _ = gather_handle.await;
 
         Ok(())
     }
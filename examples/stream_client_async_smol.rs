@@ -7,8 +7,8 @@ use std::str;
 
 use arzmq::prelude::{
     Context, MultipartMessage, MultipartReceiver, MultipartSender, SendFlags, StreamSocket,
+    ZmqResult,
 };
-use futures::join;
 use smol::{
     io::{AsyncReadExt, AsyncWriteExt},
     net::TcpListener,
@@ -40,22 +40,28 @@ async fn run_tcp_server(listener: TcpListener, msg: &str) {
     }
 }
 
-async fn run_stream_socket(zmq_stream: StreamSocket, routing_id: Vec<u8>, msg: &str) {
+async fn run_stream_socket(
+    zmq_stream: StreamSocket,
+    routing_id: Vec<u8>,
+    msg: &str,
+) -> ZmqResult<()> {
     while ITERATIONS.load(Ordering::Acquire) > 0 {
         let mut multipart = MultipartMessage::new();
         multipart.push_back(routing_id.clone().into());
         multipart.push_back(msg.into());
         zmq_stream
             .send_multipart_async(multipart, SendFlags::empty())
-            .await;
+            .await?;
 
-        let mut message = zmq_stream.recv_multipart_async().await;
+        let mut message = zmq_stream.recv_multipart_async().await?;
         println!("Received reply {:?}", message.pop_back().unwrap());
 
         ITERATIONS.fetch_sub(1, Ordering::Release);
     }
 
     KEEP_RUNNING.store(false, Ordering::Release);
+
+    Ok(())
 }
 
 main! {
@@ -74,14 +80,14 @@ main! {
         let stream_endpoint = format!("tcp://127.0.0.1:{port}");
         zmq_stream.connect(stream_endpoint)?;
 
-        let mut connect_msg = zmq_stream.recv_multipart_async().await;
+        let mut connect_msg = zmq_stream.recv_multipart_async().await?;
         let routing_id = connect_msg.pop_front().unwrap();
 
         let zmq_stream_handle =
             executor.spawn(run_stream_socket(zmq_stream, routing_id.bytes(), "Hello"));
-        let tcp_handle = executor.spawn(run_tcp_server(tcp_listener, "World"));
+        let _tcp_handle = executor.spawn(run_tcp_server(tcp_listener, "World"));
 
-        let _ = join!(zmq_stream_handle, tcp_handle);
+        let _ = zmq_stream_handle.await;
 
         Ok(())
     }
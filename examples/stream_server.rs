@@ -49,7 +49,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     let zmq_stream = StreamSocket::from_context(&context)?;
 
     zmq_stream.bind("tcp://127.0.0.1:*")?;
-    let tcp_endpoint = zmq_stream.last_endpoint()?;
+    let tcp_endpoint = zmq_stream.last_endpoint()?.unwrap();
 
     thread::spawn(move || {
         let mut connect_msg = zmq_stream.recv_multipart(RecvFlags::empty()).unwrap();
@@ -60,7 +60,10 @@ fn main() -> Result<(), Box<dyn Error>> {
         }
     });
 
-    run_tcp_client(tcp_endpoint.strip_prefix("tcp://").unwrap(), iterations)?;
+    run_tcp_client(
+        &format!("127.0.0.1:{}", tcp_endpoint.port().unwrap()),
+        iterations,
+    )?;
 
     Ok(())
 }
@@ -6,11 +6,10 @@ use std::net::TcpStream;
 #[rustversion::before(1.87)]
 use std::str;
 
-use arzmq::prelude::{Context, MultipartReceiver, StreamSocket};
+use arzmq::prelude::{Context, MultipartReceiver, StreamSocket, ZmqResult};
 use futures::{
     executor::ThreadPool,
     io::{self, AllowStdIo, AsyncReadExt, AsyncWriteExt},
-    join,
     task::SpawnExt,
 };
 
@@ -18,13 +17,15 @@ mod common;
 
 use common::ITERATIONS;
 
-async fn run_stream_server(zmq_stream: StreamSocket, msg: &str) {
-    let mut connect_msg = zmq_stream.recv_multipart_async().await;
+async fn run_stream_server(zmq_stream: StreamSocket, msg: &str) -> ZmqResult<()> {
+    let mut connect_msg = zmq_stream.recv_multipart_async().await?;
     let _routing_id = connect_msg.pop_front().unwrap();
 
     while ITERATIONS.load(Ordering::Acquire) > 1 {
-        common::run_multipart_recv_reply_async(&zmq_stream, msg).await;
+        common::run_multipart_recv_reply_async(&zmq_stream, msg).await?;
     }
+
+    Ok(())
 }
 
 async fn run_tcp_client(mut tcp_stream: AllowStdIo<TcpStream>, msg: &str) -> io::Result<()> {
@@ -58,16 +59,16 @@ fn main() -> Result<(), Box<dyn Error>> {
 
         let zmq_stream = StreamSocket::from_context(&context)?;
         zmq_stream.bind("tcp://127.0.0.1:*")?;
-        let tcp_endpoint = zmq_stream.last_endpoint()?;
+        let tcp_endpoint = zmq_stream.last_endpoint()?.unwrap();
 
-        let tcp_stream = TcpStream::connect(tcp_endpoint.strip_prefix("tcp://").unwrap())?;
+        let tcp_stream = TcpStream::connect(("127.0.0.1", tcp_endpoint.port().unwrap()))?;
 
         let tcp_handle =
             executor.spawn_with_handle(run_tcp_client(AllowStdIo::new(tcp_stream), "Hello"))?;
-        let zmq_stream_handle =
+        let _zmq_stream_handle =
             executor.spawn_with_handle(run_stream_server(zmq_stream, "World"))?;
 
-        let _ = join!(zmq_stream_handle, tcp_handle);
+        let _ = tcp_handle.await;
 
         Ok(())
     })
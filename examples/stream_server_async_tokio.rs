@@ -5,10 +5,9 @@ use core::{error::Error, sync::atomic::Ordering};
 #[rustversion::before(1.87)]
 use std::str;
 
-use arzmq::prelude::{Context, MultipartReceiver, StreamSocket};
+use arzmq::prelude::{Context, MultipartReceiver, StreamSocket, ZmqResult};
 use tokio::{
     io::{AsyncReadExt, AsyncWriteExt},
-    join,
     net::TcpStream,
     spawn,
 };
@@ -17,13 +16,15 @@ mod common;
 
 use common::ITERATIONS;
 
-async fn run_stream_server(zmq_stream: StreamSocket, msg: &str) {
-    let mut connect_msg = zmq_stream.recv_multipart_async().await;
+async fn run_stream_server(zmq_stream: StreamSocket, msg: &str) -> ZmqResult<()> {
+    let mut connect_msg = zmq_stream.recv_multipart_async().await?;
     let _routing_id = connect_msg.pop_front().unwrap();
 
     while ITERATIONS.load(Ordering::Acquire) > 1 {
-        common::run_multipart_recv_reply_async(&zmq_stream, msg).await;
+        common::run_multipart_recv_reply_async(&zmq_stream, msg).await?;
     }
+
+    Ok(())
 }
 
 async fn run_tcp_client(mut tcp_stream: TcpStream, msg: &str) {
@@ -53,14 +54,14 @@ async fn main() -> Result<(), Box<dyn Error>> {
 
     let zmq_stream = StreamSocket::from_context(&context)?;
     zmq_stream.bind("tcp://127.0.0.1:*")?;
-    let tcp_endpoint = zmq_stream.last_endpoint()?;
+    let tcp_endpoint = zmq_stream.last_endpoint()?.unwrap();
 
-    let tcp_stream = TcpStream::connect(tcp_endpoint.strip_prefix("tcp://").unwrap()).await?;
+    let tcp_stream = TcpStream::connect(("127.0.0.1", tcp_endpoint.port().unwrap())).await?;
 
     let tcp_handle = spawn(run_tcp_client(tcp_stream, "Hello"));
-    let zmq_stream_handle = spawn(run_stream_server(zmq_stream, "World"));
+    let _zmq_stream_handle = spawn(run_stream_server(zmq_stream, "World"));
 
-    let _ = join!(zmq_stream_handle, tcp_handle);
+    let _ = tcp_handle.await;
 
     Ok(())
 }
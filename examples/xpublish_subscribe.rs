@@ -35,7 +35,7 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     let xpublish = XPublishSocket::from_context(&context)?;
     xpublish.bind("tcp://127.0.0.1:*")?;
-    let subscribe_endpoint = xpublish.last_endpoint()?;
+    let subscribe_endpoint = xpublish.last_endpoint()?.unwrap();
 
     thread::spawn(move || {
         while KEEP_RUNNING.load(Ordering::Acquire) {
@@ -35,7 +35,7 @@ fn main() -> ZmqResult<()> {
 
     let xpublish = XPublishSocket::from_context(&context)?;
     xpublish.bind("tcp://127.0.0.1:*")?;
-    let xsubscribe_endpoint = xpublish.last_endpoint()?;
+    let xsubscribe_endpoint = xpublish.last_endpoint()?.unwrap();
 
     thread::spawn(move || {
         while KEEP_RUNNING.load(Ordering::Acquire) {
@@ -0,0 +1,347 @@
+//! # Background actors
+//!
+//! An [`Actor`] runs a closure on a dedicated thread, and talks to it over a pair of connected
+//! [`PairSocket`]s on a unique `inproc://` endpoint, the pipe. The closure receives its end of the
+//! pipe, and the [`Actor`] handle sends and receives messages on the other end, implementing
+//! [`Sender`], [`Receiver`], [`MultipartSender`], and [`MultipartReceiver`].
+//!
+//! When the handle is [`stop()`]ped or dropped, it sends the [`TERM`] command down the pipe, and
+//! waits for the thread to finish. The closure is therefore expected to return once it receives
+//! that command, see [`is_term()`].
+//!
+//! ```
+//! # use arzmq::{
+//! #     actor::{Actor, is_term},
+//! #     prelude::{Context, Receiver, RecvFlags, SendFlags, Sender, ZmqResult},
+//! # };
+//! #
+//! fn main() -> ZmqResult<()> {
+//!     let context = Context::new()?;
+//!
+//!     let actor = Actor::new(&context, |pipe| {
+//!         loop {
+//!             let message = pipe.recv_msg(RecvFlags::empty())?;
+//!             if is_term(&message) {
+//!                 return Ok(());
+//!             }
+//!             pipe.send_msg(message, SendFlags::empty())?;
+//!         }
+//!     })?;
+//!
+//!     actor.send_msg("Hello", SendFlags::empty())?;
+//!     assert_eq!(actor.recv_msg(RecvFlags::empty())?.to_string(), "Hello");
+//!
+//!     actor.stop()
+//! }
+//! ```
+//!
+//! [`stop()`]: Actor::stop
+use core::sync::atomic::{AtomicUsize, Ordering};
+use std::{
+    panic,
+    thread::{self, JoinHandle},
+};
+
+#[cfg(feature = "futures")]
+use async_trait::async_trait;
+use derive_more::Debug as DebugDeriveMore;
+
+use crate::{
+    ZmqError, ZmqResult,
+    context::Context,
+    message::Message,
+    socket::{
+        MultipartReceiver, MultipartSender, PairSocket, PollEvents, Receiver, RecvFlags, SendFlags,
+        Sender,
+    },
+};
+
+/// Command sent down the pipe to make an actor return
+pub const TERM: &str = "$TERM";
+
+const TERM_RETRY_INTERVAL_MS: i64 = 10;
+
+static ACTOR_PIPE_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Whether the message is the [`TERM`] command.
+pub fn is_term(message: &Message) -> bool {
+    !message.get_more() && message.as_slice() == TERM.as_bytes()
+}
+
+#[derive(DebugDeriveMore)]
+#[debug("Actor {{ ... }}")]
+/// # Handle of a background actor
+///
+/// Sends messages to, and receives messages from the actor thread. Terminates the actor thread
+/// when [`stop()`]ped or dropped.
+///
+/// See the [module-level documentation](self) for an example.
+///
+/// [`stop()`]: Actor::stop
+pub struct Actor {
+    pipe: PairSocket,
+    handle: Option<JoinHandle<ZmqResult<()>>>,
+}
+
+impl Actor {
+    /// # Start a new actor
+    ///
+    /// Creates a pipe on a unique `inproc://` endpoint, and runs the provided closure on a
+    /// dedicated thread with its end of the pipe.
+    pub fn new<F>(context: &Context, actor: F) -> ZmqResult<Self>
+    where
+        F: FnOnce(PairSocket) -> ZmqResult<()> + Send + 'static,
+    {
+        let endpoint = format!(
+            "inproc://actor-pipe-{}",
+            ACTOR_PIPE_ID.fetch_add(1, Ordering::Relaxed)
+        );
+
+        let pipe = PairSocket::from_context(context)?;
+        pipe.set_linger(0)?;
+        pipe.bind(&endpoint)?;
+
+        let actor_pipe = PairSocket::from_context(context)?;
+        actor_pipe.set_linger(0)?;
+        actor_pipe.connect(&endpoint)?;
+
+        let handle = thread::spawn(move || actor(actor_pipe));
+
+        Ok(Self {
+            pipe,
+            handle: Some(handle),
+        })
+    }
+
+    /// The parent end of the pipe, e.g. for registering it with a [`Poller`].
+    ///
+    /// [`Poller`]: crate::poller::Poller
+    pub fn pipe(&self) -> &PairSocket {
+        &self.pipe
+    }
+
+    /// Whether the actor thread already finished.
+    pub fn is_finished(&self) -> bool {
+        self.handle
+            .as_ref()
+            .is_none_or(|handle| handle.is_finished())
+    }
+
+    /// # Stop the actor
+    ///
+    /// Sends the [`TERM`] command to the actor, and waits for its thread to finish. Returns the
+    /// result of the actor closure. If the actor thread panicked, the panic is resumed on the
+    /// calling thread. While the pipe is full, sending the command waits for the actor to receive
+    /// the pending messages.
+    pub fn stop(mut self) -> ZmqResult<()> {
+        match self.shutdown()? {
+            Some(Ok(result)) => result,
+            Some(Err(panic)) => panic::resume_unwind(panic),
+            None => Ok(()),
+        }
+    }
+
+    fn shutdown(&mut self) -> ZmqResult<Option<thread::Result<ZmqResult<()>>>> {
+        let Some(handle) = self.handle.as_ref() else {
+            return Ok(None);
+        };
+
+        // waits for room on a full pipe, unless the actor already returned, and closed its end of
+        // the pipe, in which case the command would never be sent
+        while !handle.is_finished() {
+            match self.pipe.send_msg(TERM, SendFlags::DONT_WAIT) {
+                Ok(()) => break,
+                Err(ZmqError::Again) => {
+                    self.pipe
+                        .poll(PollEvents::POLL_OUT, TERM_RETRY_INTERVAL_MS)?;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(self.handle.take().map(JoinHandle::join))
+    }
+}
+
+impl Drop for Actor {
+    fn drop(&mut self) {
+        // panics of the actor thread are not resumed while dropping the handle
+        let _ = self.shutdown();
+    }
+}
+
+#[cfg_attr(feature = "futures", async_trait)]
+impl Sender for Actor {
+    fn send_msg<M, F>(&self, msg: M, flags: F) -> ZmqResult<()>
+    where
+        M: Into<Message>,
+        F: Into<SendFlags> + Copy,
+    {
+        self.pipe.send_msg(msg, flags)
+    }
+
+    #[cfg(feature = "futures")]
+    async fn send_msg_async<M, F>(&self, msg: M, flags: F) -> ZmqResult<()>
+    where
+        M: Into<Message> + Send,
+        F: Into<SendFlags> + Copy + Send,
+    {
+        self.pipe.send_msg_async(msg, flags).await
+    }
+}
+
+impl MultipartSender for Actor {}
+
+#[cfg_attr(feature = "futures", async_trait)]
+impl Receiver for Actor {
+    fn recv_msg<F>(&self, flags: F) -> ZmqResult<Message>
+    where
+        F: Into<RecvFlags> + Copy,
+    {
+        self.pipe.recv_msg(flags)
+    }
+
+    #[cfg(feature = "futures")]
+    async fn recv_msg_async(&self) -> ZmqResult<Message> {
+        self.pipe.recv_msg_async().await
+    }
+}
+
+impl MultipartReceiver for Actor {}
+
+#[cfg(test)]
+mod actor_tests {
+    use core::time::Duration;
+    use std::{sync::mpsc, thread};
+
+    use super::{Actor, TERM, is_term};
+    use crate::{
+        ZmqError, ZmqResult,
+        context::Context,
+        message::{Message, MultipartMessage},
+        socket::{
+            MultipartReceiver, MultipartSender, PairSocket, Receiver, RecvFlags, SendFlags, Sender,
+        },
+    };
+
+    fn echo(pipe: PairSocket) -> ZmqResult<()> {
+        loop {
+            let multipart = pipe.recv_multipart(RecvFlags::empty())?;
+            if multipart.len() == 1 && is_term(multipart.get(0).unwrap()) {
+                return Ok(());
+            }
+            pipe.send_multipart(multipart, SendFlags::empty())?;
+        }
+    }
+
+    #[test]
+    fn is_term_recognizes_term_command() {
+        assert!(is_term(&Message::from(TERM)));
+        assert!(!is_term(&Message::from("TERM")));
+    }
+
+    #[test]
+    fn actor_echoes_multipart_messages() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let actor = Actor::new(&context, echo)?;
+
+        let multipart: MultipartMessage =
+            vec![Message::from("Hello"), Message::from("World")].into();
+        actor.send_multipart(multipart, SendFlags::empty())?;
+
+        let reply = actor.recv_multipart(RecvFlags::empty())?;
+        assert_eq!(reply.len(), 2);
+        assert_eq!(reply.get(0).unwrap().to_string(), "Hello");
+        assert_eq!(reply.get(1).unwrap().to_string(), "World");
+
+        assert!(!actor.is_finished());
+        actor.stop()
+    }
+
+    #[test]
+    fn stop_returns_actor_error() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let actor = Actor::new(&context, |pipe| {
+            pipe.recv_msg(RecvFlags::empty())?;
+            Err(ZmqError::InvalidArgument)
+        })?;
+
+        assert!(
+            actor
+                .stop()
+                .is_err_and(|err| err == ZmqError::InvalidArgument)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn stop_after_actor_returned() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let actor = Actor::new(&context, |_pipe| Ok(()))?;
+        while !actor.is_finished() {
+            thread::sleep(Duration::from_millis(1));
+        }
+
+        actor.stop()
+    }
+
+    #[test]
+    #[should_panic(expected = "actor panicked")]
+    fn stop_resumes_panicked_actor() {
+        let context = Context::new().unwrap();
+
+        let actor = Actor::new(&context, |_pipe| panic!("actor panicked")).unwrap();
+
+        let _ = actor.stop();
+    }
+
+    #[test]
+    fn drop_ignores_panicked_actor() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let actor = Actor::new(&context, |_pipe| panic!("actor panicked"))?;
+        drop(actor);
+
+        Ok(())
+    }
+
+    #[test]
+    fn stop_waits_for_room_on_full_pipe() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let (start_sender, start_receiver) = mpsc::channel();
+        let actor = Actor::new(&context, move |pipe| {
+            let _ = start_receiver.recv();
+            loop {
+                if is_term(&pipe.recv_msg(RecvFlags::empty())?) {
+                    return Ok(());
+                }
+            }
+        })?;
+
+        while actor.send_msg("Hello", SendFlags::DONT_WAIT).is_ok() {}
+
+        let starter = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            let _ = start_sender.send(());
+        });
+        actor.stop()?;
+        starter.join().unwrap();
+
+        Ok(())
+    }
+
+    #[test]
+    fn drop_terminates_actor() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let actor = Actor::new(&context, echo)?;
+        actor.send_msg("Hello", SendFlags::empty())?;
+        drop(actor);
+
+        Ok(())
+    }
+}
@@ -1,11 +1,33 @@
 //! # 0MQ authentification mechanisms
 //!
-//! Currently just [`ZapDomain`]s are supported.
+//! [`ZapDomain`]s select the domain of a socket for the 0MQ authentication protocol (ZAP). A
+//! [`ZapHandler`] answers the ZAP requests of a context by consulting an [`Authenticator`]. There
+//! are ready-made authenticators for IP addresses, [`Plain`] usernames and passwords, as well as
+//! `CURVE` public keys.
 //!
 //! [`ZapDomain`]: ZapDomain
+//! [`ZapHandler`]: ZapHandler
+//! [`Authenticator`]: Authenticator
+//! [`Plain`]: ZapMechanism::Plain
+use std::{
+    collections::{HashMap, HashSet},
+    fs, io,
+    path::Path,
+};
+
+use derive_more::{Debug as DebugDeriveMore, Display};
+
 use crate::{
-    ZmqResult, sealed,
-    socket::{Socket, SocketOption},
+    ZmqError, ZmqResult,
+    actor::Actor,
+    context::Context,
+    message::{Message, MultipartMessage},
+    poller::Poller,
+    sealed,
+    socket::{
+        MultipartReceiver, MultipartSender, PollEvents, RecvFlags, ReplySocket, SendFlags, Socket,
+        SocketOption,
+    },
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
@@ -37,3 +59,666 @@ impl<T: Into<String>> From<T> for ZapDomain {
         ZapDomain::new(value.into())
     }
 }
+
+/// Endpoint on which `libzmq` sends ZAP requests. A [`ZapHandler`] binds to it.
+pub const ZAP_ENDPOINT: &str = "inproc://zeromq.zap.01";
+
+const ZAP_VERSION: &str = "1.0";
+
+#[derive(Debug, Display, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+/// Security mechanism of a ZAP request along with the credentials provided by the client
+pub enum ZapMechanism {
+    /// no credentials
+    #[display("NULL")]
+    Null,
+    /// username and password of a [`Plain`] client
+    ///
+    /// [`Plain`]: crate::security::SecurityMechanism::Plain
+    #[display("PLAIN")]
+    Plain {
+        /// username provided by the client
+        username: String,
+        /// password provided by the client
+        password: String,
+    },
+    /// long-term public key of a `CURVE` client
+    #[display("CURVE")]
+    Curve {
+        /// long-term public key of the client in binary form
+        public_key: Vec<u8>,
+    },
+    /// principal of a `GSSAPI` client
+    #[display("GSSAPI")]
+    GssApi {
+        /// principal name of the client
+        principal: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// ZAP 1.0 request sent by `libzmq` for every incoming connection on a socket with a security
+/// mechanism or [`ZapDomain`]
+pub struct ZapRequest {
+    /// identifies the request, and is handed back in the reply
+    pub request_id: Vec<u8>,
+    /// ZAP domain of the socket the connection was accepted on
+    pub domain: String,
+    /// origin network IP address of the client
+    pub address: String,
+    /// routing id of the client connection
+    pub routing_id: Vec<u8>,
+    /// mechanism and credentials of the client
+    pub mechanism: ZapMechanism,
+}
+
+impl TryFrom<MultipartMessage> for ZapRequest {
+    type Error = ZmqError;
+
+    fn try_from(value: MultipartMessage) -> Result<Self, Self::Error> {
+        let mut frames = value.into_iter();
+        let mut next_frame = || frames.next().ok_or(ZmqError::InvalidArgument);
+
+        if next_frame()?.to_string() != ZAP_VERSION {
+            return Err(ZmqError::InvalidArgument);
+        }
+
+        let request_id = next_frame()?.bytes();
+        let domain = next_frame()?.to_string();
+        let address = next_frame()?.to_string();
+        let routing_id = next_frame()?.bytes();
+        let mechanism = match next_frame()?.to_string().as_str() {
+            "NULL" => ZapMechanism::Null,
+            "PLAIN" => ZapMechanism::Plain {
+                username: next_frame()?.to_string(),
+                password: next_frame()?.to_string(),
+            },
+            "CURVE" => ZapMechanism::Curve {
+                public_key: next_frame()?.bytes(),
+            },
+            "GSSAPI" => ZapMechanism::GssApi {
+                principal: next_frame()?.to_string(),
+            },
+            _ => return Err(ZmqError::InvalidArgument),
+        };
+
+        Ok(Self {
+            request_id,
+            domain,
+            address,
+            routing_id,
+            mechanism,
+        })
+    }
+}
+
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Hash)]
+/// Status codes of a ZAP reply
+pub enum ZapStatus {
+    /// the client is authenticated
+    #[display("200")]
+    Success,
+    /// temporary failure, the client should retry later
+    #[display("300")]
+    TemporaryError,
+    /// the client is denied access
+    #[display("400")]
+    AuthenticationFailure,
+    /// the request could not be processed
+    #[display("500")]
+    InternalError,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Decision of an [`Authenticator`] for a [`ZapRequest`]
+pub struct ZapReply {
+    /// status of the authentication
+    pub status: ZapStatus,
+    /// human-readable reason for the status
+    pub status_text: String,
+    /// user id of the authenticated client, that is attached to all its messages
+    pub user_id: String,
+    /// ZMTP metadata attached to all messages of the authenticated client
+    pub metadata: Vec<u8>,
+}
+
+impl ZapReply {
+    /// Successful authentication of the client identified by `user_id`
+    pub fn success<V: Into<String>>(user_id: V) -> Self {
+        Self {
+            status: ZapStatus::Success,
+            status_text: "OK".to_string(),
+            user_id: user_id.into(),
+            metadata: Vec::new(),
+        }
+    }
+
+    /// Denied access, with a human-readable `reason`
+    pub fn denied<V: Into<String>>(reason: V) -> Self {
+        Self {
+            status: ZapStatus::AuthenticationFailure,
+            status_text: reason.into(),
+            user_id: String::new(),
+            metadata: Vec::new(),
+        }
+    }
+
+    fn into_multipart(self, request_id: Vec<u8>) -> MultipartMessage {
+        vec![
+            Message::from(ZAP_VERSION),
+            Message::from(request_id),
+            Message::from(self.status.to_string().as_str()),
+            Message::from(self.status_text.as_str()),
+            Message::from(self.user_id.as_str()),
+            Message::from(self.metadata),
+        ]
+        .into()
+    }
+}
+
+/// Policy for answering [`ZapRequest`]s in a [`ZapHandler`]
+pub trait Authenticator: Send + 'static {
+    /// Decides whether the client of the provided `request` is allowed to connect.
+    fn authenticate(&mut self, request: &ZapRequest) -> ZapReply;
+}
+
+impl<F> Authenticator for F
+where
+    F: FnMut(&ZapRequest) -> ZapReply + Send + 'static,
+{
+    fn authenticate(&mut self, request: &ZapRequest) -> ZapReply {
+        self(request)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ZapHandlerItem {
+    Requests,
+    Control,
+}
+
+#[derive(DebugDeriveMore)]
+#[debug("ZapHandler {{ ... }}")]
+/// # ZAP handler
+///
+/// Answers the ZAP requests of all sockets of a [`Context`] on a dedicated thread, by consulting
+/// the provided [`Authenticator`]. Only one handler can be started per [`Context`]. The handler
+/// runs until it is [`stop()`]ped or dropped.
+///
+/// ```
+/// # use arzmq::{
+/// #     auth::{IpAuthenticator, ZapHandler},
+/// #     prelude::{Context, ZmqResult},
+/// # };
+/// #
+/// # fn main() -> ZmqResult<()> {
+/// let context = Context::new()?;
+///
+/// let handler = ZapHandler::start(&context, IpAuthenticator::allow(["127.0.0.1"]))?;
+///
+/// handler.stop()?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`stop()`]: ZapHandler::stop
+pub struct ZapHandler {
+    actor: Actor,
+}
+
+impl ZapHandler {
+    /// Binds the handler to [`ZAP_ENDPOINT`] in the provided `context`, and starts answering ZAP
+    /// requests with the provided `authenticator`.
+    pub fn start<A: Authenticator>(context: &Context, mut authenticator: A) -> ZmqResult<Self> {
+        let zap = ReplySocket::from_context(context)?;
+        zap.bind(ZAP_ENDPOINT)?;
+
+        let actor = Actor::new(context, move |pipe| {
+            let mut poller = Poller::new();
+            poller.add_socket(&zap, PollEvents::POLL_IN, ZapHandlerItem::Requests);
+            poller.add_socket(&pipe, PollEvents::POLL_IN, ZapHandlerItem::Control);
+
+            loop {
+                for event in poller.wait(-1)? {
+                    match event.into_token() {
+                        ZapHandlerItem::Control => return Ok(()),
+                        ZapHandlerItem::Requests => Self::handle_request(&zap, &mut authenticator)?,
+                    }
+                }
+            }
+        })?;
+
+        Ok(Self { actor })
+    }
+
+    fn handle_request<A: Authenticator>(zap: &ReplySocket, authenticator: &mut A) -> ZmqResult<()> {
+        let multipart = zap.recv_multipart(RecvFlags::empty())?;
+        let request_id = multipart
+            .get(1)
+            .map(|request_id| request_id.bytes())
+            .unwrap_or_default();
+
+        let reply = match ZapRequest::try_from(multipart) {
+            Ok(request) => authenticator.authenticate(&request),
+            Err(_) => ZapReply {
+                status: ZapStatus::InternalError,
+                status_text: "Invalid ZAP request".to_string(),
+                user_id: String::new(),
+                metadata: Vec::new(),
+            },
+        };
+
+        zap.send_multipart(reply.into_multipart(request_id), SendFlags::empty())
+    }
+
+    /// Stops the handler, and waits for its thread to finish. If the handler thread panicked, the
+    /// panic is resumed on the calling thread.
+    pub fn stop(self) -> ZmqResult<()> {
+        self.actor.stop()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Allows or denies clients by their IP address
+///
+/// If any address is allowed, clients from all other addresses are denied. Otherwise, clients
+/// from all but the denied addresses are allowed.
+pub struct IpAuthenticator {
+    allowed: HashSet<String>,
+    denied: HashSet<String>,
+}
+
+impl IpAuthenticator {
+    /// Allows only clients from the provided `addresses`
+    pub fn allow<I, V>(addresses: I) -> Self
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        Self {
+            allowed: addresses.into_iter().map(Into::into).collect(),
+            denied: HashSet::new(),
+        }
+    }
+
+    /// Denies clients from the provided `addresses`, and allows all others
+    pub fn deny<I, V>(addresses: I) -> Self
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        Self {
+            allowed: HashSet::new(),
+            denied: addresses.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl Authenticator for IpAuthenticator {
+    fn authenticate(&mut self, request: &ZapRequest) -> ZapReply {
+        let allowed = if self.allowed.is_empty() {
+            !self.denied.contains(&request.address)
+        } else {
+            self.allowed.contains(&request.address)
+        };
+
+        if allowed {
+            ZapReply::success("")
+        } else {
+            ZapReply::denied("Address not allowed")
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Authenticates [`Plain`] clients against a set of usernames and passwords. The username is used
+/// as user id of authenticated clients.
+///
+/// [`Plain`]: ZapMechanism::Plain
+pub struct PlainAuthenticator {
+    passwords: HashMap<String, String>,
+}
+
+impl PlainAuthenticator {
+    /// Reads usernames and passwords from a password file with one `username=password` entry per
+    /// line. Empty lines and lines starting with `#` are ignored.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(fs::read_to_string(path)?
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| line.split_once('='))
+            .collect())
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for PlainAuthenticator {
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        Self {
+            passwords: iter
+                .into_iter()
+                .map(|(username, password)| (username.into(), password.into()))
+                .collect(),
+        }
+    }
+}
+
+impl Authenticator for PlainAuthenticator {
+    fn authenticate(&mut self, request: &ZapRequest) -> ZapReply {
+        match &request.mechanism {
+            ZapMechanism::Plain { username, password }
+                if self.passwords.get(username) == Some(password) =>
+            {
+                ZapReply::success(username.as_str())
+            }
+            ZapMechanism::Plain { .. } => ZapReply::denied("Invalid username or password"),
+            _ => ZapReply::denied("Security mechanism not supported"),
+        }
+    }
+}
+
+#[cfg(zmq_has = "curve")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Authenticates `CURVE` clients by their long-term public key. The Z85-encoded public key is used
+/// as user id of authenticated clients.
+pub struct CurveAuthenticator {
+    public_keys: HashSet<Vec<u8>>,
+}
+
+#[cfg(zmq_has = "curve")]
+impl CurveAuthenticator {
+    /// Allows only clients with one of the provided binary `public_keys`
+    pub fn new<I, K>(public_keys: I) -> Self
+    where
+        I: IntoIterator<Item = K>,
+        K: AsRef<[u8]>,
+    {
+        Self {
+            public_keys: public_keys
+                .into_iter()
+                .map(|key| key.as_ref().to_vec())
+                .collect(),
+        }
+    }
+}
+
+#[cfg(zmq_has = "curve")]
+impl Authenticator for CurveAuthenticator {
+    fn authenticate(&mut self, request: &ZapRequest) -> ZapReply {
+        match &request.mechanism {
+            ZapMechanism::Curve { public_key } if self.public_keys.contains(public_key) => {
+                ZapReply::success(crate::security::curve::encode(public_key).unwrap_or_default())
+            }
+            ZapMechanism::Curve { .. } => ZapReply::denied("Public key not allowed"),
+            _ => ZapReply::denied("Security mechanism not supported"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod zap_tests {
+    use std::{env, fs};
+
+    use rstest::*;
+
+    use super::{
+        Authenticator, IpAuthenticator, PlainAuthenticator, ZapHandler, ZapMechanism, ZapReply,
+        ZapRequest, ZapStatus,
+    };
+    use crate::{
+        ZmqError,
+        auth::ZapDomain,
+        prelude::{
+            Context, DealerSocket, Message, MonitorFlags, MonitorReceiver, MonitorSocketEvent,
+            MultipartMessage, Receiver, RecvFlags, SendFlags, Sender, SocketOption, ZmqResult,
+        },
+    };
+
+    fn zap_request(mechanism: &str, credentials: &[&str]) -> MultipartMessage {
+        ["1.0", "1", "global", "127.0.0.1", "client", mechanism]
+            .iter()
+            .chain(credentials)
+            .map(|frame| Message::from(*frame))
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    fn plain_request(username: &str, password: &str) -> ZapRequest {
+        ZapRequest {
+            request_id: b"1".to_vec(),
+            domain: "global".to_string(),
+            address: "127.0.0.1".to_string(),
+            routing_id: b"client".to_vec(),
+            mechanism: ZapMechanism::Plain {
+                username: username.to_string(),
+                password: password.to_string(),
+            },
+        }
+    }
+
+    #[rstest]
+    #[case("NULL", &[], ZapMechanism::Null)]
+    #[case(
+        "PLAIN",
+        &["username", "password"],
+        ZapMechanism::Plain { username: "username".to_string(), password: "password".to_string() }
+    )]
+    #[case("CURVE", &["public key"], ZapMechanism::Curve { public_key: b"public key".to_vec() })]
+    #[case("GSSAPI", &["principal"], ZapMechanism::GssApi { principal: "principal".to_string() })]
+    fn zap_request_try_from_multipart(
+        #[case] mechanism: &str,
+        #[case] credentials: &[&str],
+        #[case] expected: ZapMechanism,
+    ) -> ZmqResult<()> {
+        let request = ZapRequest::try_from(zap_request(mechanism, credentials))?;
+
+        assert_eq!(request.request_id, b"1");
+        assert_eq!(request.domain, "global");
+        assert_eq!(request.address, "127.0.0.1");
+        assert_eq!(request.routing_id, b"client");
+        assert_eq!(request.mechanism, expected);
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(zap_request("PLAIN", &["username"]))]
+    #[case(zap_request("UNKNOWN", &[]))]
+    #[case(vec![Message::from("2.0")].into())]
+    #[case(MultipartMessage::new())]
+    fn zap_request_try_from_invalid_multipart(#[case] multipart: MultipartMessage) {
+        assert!(ZapRequest::try_from(multipart).is_err_and(|err| err == ZmqError::InvalidArgument));
+    }
+
+    #[rstest]
+    #[case(IpAuthenticator::allow(["127.0.0.1"]), "127.0.0.1", ZapStatus::Success)]
+    #[case(IpAuthenticator::allow(["127.0.0.1"]), "10.0.0.1", ZapStatus::AuthenticationFailure)]
+    #[case(IpAuthenticator::deny(["127.0.0.1"]), "127.0.0.1", ZapStatus::AuthenticationFailure)]
+    #[case(IpAuthenticator::deny(["127.0.0.1"]), "10.0.0.1", ZapStatus::Success)]
+    #[case(IpAuthenticator::default(), "10.0.0.1", ZapStatus::Success)]
+    fn ip_authenticator_authenticate(
+        #[case] mut authenticator: IpAuthenticator,
+        #[case] address: &str,
+        #[case] expected: ZapStatus,
+    ) {
+        let request = ZapRequest {
+            address: address.to_string(),
+            ..plain_request("username", "password")
+        };
+
+        assert_eq!(authenticator.authenticate(&request).status, expected);
+    }
+
+    #[rstest]
+    #[case("username", "password", ZapReply::success("username"))]
+    #[case("username", "wrong", ZapReply::denied("Invalid username or password"))]
+    #[case(
+        "unknown",
+        "password",
+        ZapReply::denied("Invalid username or password")
+    )]
+    fn plain_authenticator_authenticate(
+        #[case] username: &str,
+        #[case] password: &str,
+        #[case] expected: ZapReply,
+    ) {
+        let mut authenticator = PlainAuthenticator::from_iter([("username", "password")]);
+
+        assert_eq!(
+            authenticator.authenticate(&plain_request(username, password)),
+            expected
+        );
+    }
+
+    #[test]
+    fn plain_authenticator_from_file() -> ZmqResult<()> {
+        let path = env::temp_dir().join(format!(
+            "arzmq-plain-authenticator-passwords-{}",
+            std::process::id()
+        ));
+        fs::write(
+            &path,
+            "# comment\n\nadmin=secret\n  guest=guest  \ninvalid\n",
+        )
+        .unwrap();
+
+        let authenticator = PlainAuthenticator::from_file(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            authenticator,
+            PlainAuthenticator::from_iter([("admin", "secret"), ("guest", "guest")])
+        );
+
+        Ok(())
+    }
+
+    #[cfg(zmq_has = "curve")]
+    #[test]
+    fn curve_authenticator_authenticate() -> ZmqResult<()> {
+        use super::CurveAuthenticator;
+        use crate::security::curve::{curve_keypair, encode};
+
+        let (allowed_key, _) = curve_keypair()?;
+        let (denied_key, _) = curve_keypair()?;
+
+        let mut authenticator = CurveAuthenticator::new([&allowed_key]);
+
+        let request = ZapRequest {
+            mechanism: ZapMechanism::Curve {
+                public_key: allowed_key.clone(),
+            },
+            ..plain_request("username", "password")
+        };
+        assert_eq!(
+            authenticator.authenticate(&request),
+            ZapReply::success(encode(&allowed_key).unwrap())
+        );
+
+        let request = ZapRequest {
+            mechanism: ZapMechanism::Curve {
+                public_key: denied_key,
+            },
+            ..plain_request("username", "password")
+        };
+        assert_eq!(
+            authenticator.authenticate(&request).status,
+            ZapStatus::AuthenticationFailure
+        );
+
+        Ok(())
+    }
+
+    fn plain_server_and_client(
+        context: &Context,
+        password: &str,
+    ) -> ZmqResult<(DealerSocket, DealerSocket)> {
+        let server = DealerSocket::from_context(context)?;
+        server.set_sockopt_bool(SocketOption::PlainServer, true)?;
+        server.set_zap_domain(&ZapDomain::from("global"))?;
+        server.bind("tcp://127.0.0.1:*")?;
+
+        let client = DealerSocket::from_context(context)?;
+        client.set_sockopt_string(SocketOption::PlainUsername, "username")?;
+        client.set_sockopt_string(SocketOption::PlainPassword, password)?;
+
+        Ok((server, client))
+    }
+
+    #[test]
+    fn zap_handler_allows_authenticated_client() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let handler = ZapHandler::start(
+            &context,
+            PlainAuthenticator::from_iter([("username", "password")]),
+        )?;
+
+        let (server, client) = plain_server_and_client(&context, "password")?;
+        client.connect(server.last_endpoint()?.unwrap())?;
+
+        client.send_msg("Hello", SendFlags::empty())?;
+        let msg = server.recv_msg(RecvFlags::empty())?;
+        assert_eq!(msg.to_string(), "Hello");
+        assert_eq!(msg.user_id(), Some("username".to_string()));
+
+        handler.stop()
+    }
+
+    #[test]
+    fn zap_handler_denies_unauthenticated_client() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let handler = ZapHandler::start(
+            &context,
+            PlainAuthenticator::from_iter([("username", "password")]),
+        )?;
+
+        let (server, client) = plain_server_and_client(&context, "wrong")?;
+        let monitor = server.monitor(MonitorFlags::HandshakeFailedAuth)?;
+        client.connect(server.last_endpoint()?.unwrap())?;
+
+        loop {
+            match monitor.recv_monitor_event() {
+                Err(_) => continue,
+                Ok(event) => {
+                    assert_eq!(event, MonitorSocketEvent::HandshakeFailedAuth(400));
+                    break;
+                }
+            }
+        }
+
+        handler.stop()
+    }
+
+    #[test]
+    fn zap_handler_passes_requests_to_closures() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let handler = ZapHandler::start(&context, |request: &ZapRequest| {
+            ZapReply::success(request.domain.as_str())
+        })?;
+
+        let (server, client) = plain_server_and_client(&context, "password")?;
+        client.connect(server.last_endpoint()?.unwrap())?;
+
+        client.send_msg("Hello", SendFlags::empty())?;
+        assert_eq!(server.recv_msg(RecvFlags::empty())?.to_string(), "Hello");
+
+        handler.stop()
+    }
+
+    #[test]
+    fn zap_handler_only_starts_once_per_context() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let _handler = ZapHandler::start(&context, IpAuthenticator::default())?;
+
+        assert!(
+            ZapHandler::start(&context, IpAuthenticator::default())
+                .is_err_and(|err| err == ZmqError::AddressInUse)
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,693 @@
+//! # Typed messages
+//!
+//! A [`Codec`] encodes serde-serializable values into the payload of a single [`Message`], and
+//! decodes them back. With a codec at hand, [`send_typed()`] and [`recv_typed()`] send and
+//! receive typed values on any socket, while [`send_multipart_typed()`] and
+//! [`recv_multipart_typed()`] map the fields of structs, and the elements of tuples and sequences
+//! to the frames of a [`MultipartMessage`], encoding every field on its own.
+//!
+//! The codecs `Json`, `MessagePack`, and `Bincode` are available behind the features `json`,
+//! `msgpack`, and `bincode` respectively. Failing encodings and decodings are reported as
+//! [`CodecError`].
+//!
+//! [`send_typed()`]: crate::socket::Sender::send_typed
+//! [`recv_typed()`]: crate::socket::Receiver::recv_typed
+//! [`send_multipart_typed()`]: crate::socket::MultipartSender::send_multipart_typed
+//! [`recv_multipart_typed()`]: crate::socket::MultipartReceiver::recv_multipart_typed
+use alloc::{slice::Iter, string::String, vec::Vec};
+use core::{fmt::Display, marker::PhantomData};
+
+use serde::{
+    Deserialize, Serialize,
+    de::{self, DeserializeOwned, DeserializeSeed, SeqAccess, Visitor},
+    ser::{self, Impossible},
+};
+use thiserror::Error;
+
+use crate::{
+    ZmqError,
+    message::{Message, MessageSlice, MultipartMessage},
+};
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+/// Errors of sending and receiving typed messages
+pub enum CodecError {
+    /// sending or receiving the message failed
+    #[error("{0}")]
+    Zmq(#[from] ZmqError),
+    /// the value could not be encoded
+    #[error("Encoding failed: {0}")]
+    Encode(String),
+    /// the message could not be decoded into the requested type
+    #[error("Decoding failed: {0}")]
+    Decode(String),
+}
+
+impl ser::Error for CodecError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self::Encode(msg.to_string())
+    }
+}
+
+impl de::Error for CodecError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self::Decode(msg.to_string())
+    }
+}
+
+/// # Encoding of typed values
+///
+/// Encodes serde-serializable values into the payload of a single [`Message`], and decodes them
+/// back.
+pub trait Codec {
+    /// Encodes the value.
+    fn encode<T>(&self, value: &T) -> Result<Vec<u8>, CodecError>
+    where
+        T: Serialize + ?Sized;
+
+    /// Decodes the complete payload with the provided seed.
+    fn decode_seed<'de, S>(&self, bytes: &'de [u8], seed: S) -> Result<S::Value, CodecError>
+    where
+        S: DeserializeSeed<'de>;
+
+    /// Decodes the complete payload into a value.
+    fn decode<'de, T>(&self, bytes: &'de [u8]) -> Result<T, CodecError>
+    where
+        T: Deserialize<'de>,
+    {
+        self.decode_seed(bytes, PhantomData)
+    }
+}
+
+#[cfg(feature = "json")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+/// # JSON codec
+///
+/// Encodes values as JSON through [`serde_json`].
+///
+/// ```
+/// # use arzmq::{
+/// #     codec::Json,
+/// #     prelude::{
+/// #         Context, MultipartReceiver, MultipartSender, PairSocket, Receiver, RecvFlags, SendFlags,
+/// #         Sender,
+/// #     },
+/// # };
+/// # use serde::{Deserialize, Serialize};
+/// #
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Temperature {
+///     sensor: String,
+///     celsius: f64,
+/// }
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let context = Context::new()?;
+///
+///     let receiver = PairSocket::from_context(&context)?;
+///     receiver.bind("inproc://codec-example")?;
+///
+///     let sender = PairSocket::from_context(&context)?;
+///     sender.connect("inproc://codec-example")?;
+///
+///     let temperature = Temperature {
+///         sensor: "kitchen".to_string(),
+///         celsius: 21.5,
+///     };
+///
+///     sender.send_typed(Json, &temperature, SendFlags::empty())?;
+///     let received: Temperature = receiver.recv_typed(Json, RecvFlags::empty())?;
+///     assert_eq!(received, temperature);
+///
+///     sender.send_multipart_typed(Json, &temperature, SendFlags::empty())?;
+///     let frames = receiver.recv_multipart(RecvFlags::empty())?;
+///     assert_eq!(frames.len(), 2);
+///     assert_eq!(frames.get(0).unwrap().to_string(), "\"kitchen\"");
+///
+///     Ok(())
+/// }
+/// ```
+pub struct Json;
+
+#[cfg(feature = "json")]
+impl Codec for Json {
+    fn encode<T>(&self, value: &T) -> Result<Vec<u8>, CodecError>
+    where
+        T: Serialize + ?Sized,
+    {
+        serde_json::to_vec(value).map_err(|err| CodecError::Encode(err.to_string()))
+    }
+
+    fn decode_seed<'de, S>(&self, bytes: &'de [u8], seed: S) -> Result<S::Value, CodecError>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        let mut deserializer = serde_json::Deserializer::from_slice(bytes);
+        let value = seed.deserialize(&mut deserializer);
+
+        value
+            .and_then(|value| deserializer.end().map(|_| value))
+            .map_err(|err| CodecError::Decode(err.to_string()))
+    }
+}
+
+#[cfg(feature = "msgpack")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+/// # MessagePack codec
+///
+/// Encodes values as MessagePack through [`rmp_serde`], with structs encoded as maps.
+pub struct MessagePack;
+
+#[cfg(feature = "msgpack")]
+impl Codec for MessagePack {
+    fn encode<T>(&self, value: &T) -> Result<Vec<u8>, CodecError>
+    where
+        T: Serialize + ?Sized,
+    {
+        rmp_serde::to_vec_named(value).map_err(|err| CodecError::Encode(err.to_string()))
+    }
+
+    fn decode_seed<'de, S>(&self, bytes: &'de [u8], seed: S) -> Result<S::Value, CodecError>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        let mut deserializer = rmp_serde::Deserializer::new(std::io::Cursor::new(bytes));
+        let value = seed
+            .deserialize(&mut deserializer)
+            .map_err(|err| CodecError::Decode(err.to_string()))?;
+
+        if deserializer.position() != bytes.len() as u64 {
+            return Err(CodecError::Decode("trailing bytes".to_string()));
+        }
+
+        Ok(value)
+    }
+}
+
+#[cfg(feature = "bincode")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+/// # Bincode codec
+///
+/// Encodes values through [`bincode`] with its standard configuration.
+pub struct Bincode;
+
+#[cfg(feature = "bincode")]
+impl Codec for Bincode {
+    fn encode<T>(&self, value: &T) -> Result<Vec<u8>, CodecError>
+    where
+        T: Serialize + ?Sized,
+    {
+        bincode::serde::encode_to_vec(value, bincode::config::standard())
+            .map_err(|err| CodecError::Encode(err.to_string()))
+    }
+
+    fn decode_seed<'de, S>(&self, bytes: &'de [u8], seed: S) -> Result<S::Value, CodecError>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        let (value, read) =
+            bincode::serde::seed_decode_from_slice(seed, bytes, bincode::config::standard())
+                .map_err(|err| CodecError::Decode(err.to_string()))?;
+
+        if read != bytes.len() {
+            return Err(CodecError::Decode("trailing bytes".to_string()));
+        }
+
+        Ok(value)
+    }
+}
+
+/// Encodes the value into the frames of a multipart message, one frame per field or element.
+pub(crate) fn encode_frames<C, T>(codec: &C, value: &T) -> Result<MultipartMessage, CodecError>
+where
+    C: Codec,
+    T: Serialize + ?Sized,
+{
+    value.serialize(FramesSerializer {
+        codec,
+        frames: MultipartMessage::new(),
+    })
+}
+
+/// Decodes the frames of a multipart message into a value, one frame per field or element.
+pub(crate) fn decode_frames<C, T>(codec: &C, frames: &MultipartMessage) -> Result<T, CodecError>
+where
+    C: Codec,
+    T: DeserializeOwned,
+{
+    // the frames stay locked while they are decoded
+    let frames = frames.iter().map(Message::as_slice).collect::<Vec<_>>();
+
+    T::deserialize(FramesDeserializer {
+        codec,
+        frames: frames.iter(),
+    })
+}
+
+fn unsupported_frames() -> CodecError {
+    CodecError::Encode(
+        "only structs, tuples, and sequences can be mapped to multipart frames".to_string(),
+    )
+}
+
+struct FramesSerializer<'c, C> {
+    codec: &'c C,
+    frames: MultipartMessage,
+}
+
+impl<C: Codec> FramesSerializer<'_, C> {
+    fn push_frame<T>(&mut self, value: &T) -> Result<(), CodecError>
+    where
+        T: Serialize + ?Sized,
+    {
+        let frame = self.codec.encode(value)?;
+        self.frames.push_back(Message::from(frame));
+        Ok(())
+    }
+}
+
+macro_rules! unsupported_frames {
+    ($($method:ident($type:ty)),* $(,)?) => {
+        $(
+            fn $method(self, _value: $type) -> Result<Self::Ok, Self::Error> {
+                Err(unsupported_frames())
+            }
+        )*
+    };
+}
+
+impl<C: Codec> ser::Serializer for FramesSerializer<'_, C> {
+    type Ok = MultipartMessage;
+    type Error = CodecError;
+
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Impossible<MultipartMessage, CodecError>;
+    type SerializeMap = Impossible<MultipartMessage, CodecError>;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Impossible<MultipartMessage, CodecError>;
+
+    unsupported_frames!(
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+        serialize_bytes(&[u8]),
+        serialize_unit_struct(&'static str),
+    );
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported_frames())
+    }
+
+    fn serialize_some<T>(self, _value: &T) -> Result<Self::Ok, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        Err(unsupported_frames())
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported_frames())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported_frames())
+    }
+
+    fn serialize_newtype_struct<T>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        Err(unsupported_frames())
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(self)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(unsupported_frames())
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Err(unsupported_frames())
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(unsupported_frames())
+    }
+}
+
+impl<C: Codec> ser::SerializeSeq for FramesSerializer<'_, C> {
+    type Ok = MultipartMessage;
+    type Error = CodecError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push_frame(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.frames)
+    }
+}
+
+impl<C: Codec> ser::SerializeTuple for FramesSerializer<'_, C> {
+    type Ok = MultipartMessage;
+    type Error = CodecError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push_frame(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.frames)
+    }
+}
+
+impl<C: Codec> ser::SerializeTupleStruct for FramesSerializer<'_, C> {
+    type Ok = MultipartMessage;
+    type Error = CodecError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push_frame(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.frames)
+    }
+}
+
+impl<C: Codec> ser::SerializeStruct for FramesSerializer<'_, C> {
+    type Ok = MultipartMessage;
+    type Error = CodecError;
+
+    fn serialize_field<T>(&mut self, _key: &'static str, value: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push_frame(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.frames)
+    }
+}
+
+struct FramesDeserializer<'de, 'c, C> {
+    codec: &'c C,
+    frames: Iter<'de, MessageSlice<'de>>,
+}
+
+impl<'de, C: Codec> de::Deserializer<'de> for FramesDeserializer<'de, '_, C> {
+    type Error = CodecError;
+
+    fn deserialize_any<V>(mut self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let value = visitor.visit_seq(&mut self)?;
+
+        let remaining = self.frames.len();
+        if remaining != 0 {
+            return Err(CodecError::Decode(format!(
+                "{remaining} trailing frames in multipart message"
+            )));
+        }
+
+        Ok(value)
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        option unit unit_struct seq tuple tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+impl<'de, C: Codec> SeqAccess<'de> for FramesDeserializer<'de, '_, C> {
+    type Error = CodecError;
+
+    fn next_element_seed<S>(&mut self, seed: S) -> Result<Option<S::Value>, Self::Error>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        self.frames
+            .next()
+            .map(|frame| self.codec.decode_seed(frame, seed))
+            .transpose()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.frames.len())
+    }
+}
+
+#[cfg(all(test, any(feature = "json", feature = "msgpack", feature = "bincode")))]
+mod codec_tests {
+    use rstest::*;
+    use serde::{Deserialize, Serialize};
+
+    use super::{Codec, CodecError, decode_frames, encode_frames};
+    use crate::message::{Message, MultipartMessage};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Reading {
+        sensor: String,
+        values: Vec<u32>,
+        unit: Option<String>,
+    }
+
+    fn reading() -> Reading {
+        Reading {
+            sensor: "kitchen".to_string(),
+            values: vec![21, 22],
+            unit: None,
+        }
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_encodes_values() -> Result<(), CodecError> {
+        let codec = crate::codec::Json;
+
+        let encoded = codec.encode(&reading())?;
+        assert_eq!(
+            String::from_utf8(encoded.clone()).unwrap(),
+            r#"{"sensor":"kitchen","values":[21,22],"unit":null}"#
+        );
+        assert_eq!(codec.decode::<Reading>(&encoded)?, reading());
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[cfg_attr(feature = "json", case(crate::codec::Json))]
+    #[cfg_attr(feature = "msgpack", case(crate::codec::MessagePack))]
+    #[cfg_attr(feature = "bincode", case(crate::codec::Bincode))]
+    fn codec_round_trips<C: Codec>(#[case] codec: C) -> Result<(), CodecError> {
+        let encoded = codec.encode(&reading())?;
+        assert_eq!(codec.decode::<Reading>(&encoded)?, reading());
+
+        let mut trailing = encoded;
+        trailing.push(0);
+        assert!(matches!(
+            codec.decode::<Reading>(&trailing),
+            Err(CodecError::Decode(_))
+        ));
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[cfg_attr(feature = "json", case(crate::codec::Json))]
+    #[cfg_attr(feature = "msgpack", case(crate::codec::MessagePack))]
+    #[cfg_attr(feature = "bincode", case(crate::codec::Bincode))]
+    fn frames_round_trip<C: Codec>(#[case] codec: C) -> Result<(), CodecError> {
+        let frames = encode_frames(&codec, &reading())?;
+        assert_eq!(frames.len(), 3);
+        assert_eq!(
+            codec.decode::<String>(&frames.get(0).unwrap().as_slice())?,
+            "kitchen"
+        );
+        assert_eq!(decode_frames::<_, Reading>(&codec, &frames)?, reading());
+
+        let tuple = encode_frames(&codec, &("id", 42_u64))?;
+        assert_eq!(tuple.len(), 2);
+        assert_eq!(
+            decode_frames::<_, (String, u64)>(&codec, &tuple)?,
+            ("id".to_string(), 42)
+        );
+
+        let sequence = encode_frames(&codec, &[1_u8, 2, 3][..])?;
+        assert_eq!(sequence.len(), 3);
+        assert_eq!(
+            decode_frames::<_, Vec<u8>>(&codec, &sequence)?,
+            vec![1, 2, 3]
+        );
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[cfg_attr(feature = "json", case(crate::codec::Json))]
+    #[cfg_attr(feature = "msgpack", case(crate::codec::MessagePack))]
+    #[cfg_attr(feature = "bincode", case(crate::codec::Bincode))]
+    fn frames_with_wrong_frame_count<C: Codec>(#[case] codec: C) -> Result<(), CodecError> {
+        let mut frames = encode_frames(&codec, &reading())?;
+        frames.push_back(Message::from(codec.encode("trailing")?));
+        assert!(matches!(
+            decode_frames::<_, Reading>(&codec, &frames),
+            Err(CodecError::Decode(_))
+        ));
+
+        frames.pop_back();
+        frames.pop_back();
+        assert!(matches!(
+            decode_frames::<_, Reading>(&codec, &frames),
+            Err(CodecError::Decode(_))
+        ));
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[cfg_attr(feature = "json", case(crate::codec::Json))]
+    #[cfg_attr(feature = "msgpack", case(crate::codec::MessagePack))]
+    #[cfg_attr(feature = "bincode", case(crate::codec::Bincode))]
+    fn frames_of_unsupported_types<C: Codec>(#[case] codec: C) {
+        assert!(matches!(
+            encode_frames(&codec, &42_u32),
+            Err(CodecError::Encode(_))
+        ));
+        assert!(matches!(
+            decode_frames::<_, u32>(&codec, &MultipartMessage::new()),
+            Err(CodecError::Decode(_))
+        ));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn typed_messages_over_sockets() -> Result<(), CodecError> {
+        use crate::{
+            codec::Json,
+            context::Context,
+            socket::{
+                MultipartReceiver, MultipartSender, PairSocket, Receiver, RecvFlags, SendFlags,
+                Sender,
+            },
+        };
+
+        let context = Context::new()?;
+
+        let receiver = PairSocket::from_context(&context)?;
+        receiver.bind("inproc://codec-typed-messages")?;
+
+        let sender = PairSocket::from_context(&context)?;
+        sender.connect("inproc://codec-typed-messages")?;
+
+        sender.send_typed(Json, &reading(), SendFlags::empty())?;
+        assert_eq!(
+            receiver.recv_typed::<_, Reading, _>(Json, RecvFlags::empty())?,
+            reading()
+        );
+
+        sender.send_multipart_typed(Json, &reading(), SendFlags::empty())?;
+        assert_eq!(
+            receiver.recv_multipart_typed::<_, Reading, _>(Json, RecvFlags::empty())?,
+            reading()
+        );
+
+        sender.send_msg("no json", SendFlags::empty())?;
+        assert!(matches!(
+            receiver.recv_typed::<_, Reading, _>(Json, RecvFlags::empty()),
+            Err(CodecError::Decode(_))
+        ));
+
+        Ok(())
+    }
+}
@@ -436,7 +436,7 @@ mod builder {
         name = "ContextBuilder",
         public,
         build_fn(skip, error = "ZmqError"),
-        derive(PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)
+        derive(PartialEq, Eq, Hash, Clone, serde::Serialize, serde::Deserialize)
     )]
     #[builder_struct_attr(doc = "Builder for [`Context`].\n\n")]
     #[allow(dead_code)]
@@ -0,0 +1,495 @@
+//! 0MQ endpoints
+//!
+//! An [`Endpoint`] describes the transport and address a socket binds or connects to, and can be
+//! parsed from and displayed as the usual 0MQ endpoint string, i.e. `tcp://127.0.0.1:5555`.
+//!
+//! ```
+//! # use arzmq::endpoint::{Endpoint, Port};
+//! #
+//! let endpoint: Endpoint = "tcp://127.0.0.1:*".parse().unwrap();
+//! assert_eq!(
+//!     endpoint,
+//!     Endpoint::Tcp {
+//!         source: None,
+//!         host: "127.0.0.1".to_string(),
+//!         port: Port::Wildcard,
+//!     }
+//! );
+//! assert_eq!(endpoint.to_string(), "tcp://127.0.0.1:*");
+//! ```
+
+use core::{fmt, str::FromStr};
+
+use crate::{ZmqError, ZmqResult};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Port of an [`Endpoint`]
+pub enum Port {
+    /// Wildcard port `*`, for binding to a system-assigned ephemeral port
+    Wildcard,
+    /// Fixed port number
+    Fixed(u16),
+}
+
+impl fmt::Display for Port {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Wildcard => write!(f, "*"),
+            Self::Fixed(port) => write!(f, "{port}"),
+        }
+    }
+}
+
+impl FromStr for Port {
+    type Err = ZmqError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "*" => Ok(Self::Wildcard),
+            port => Ok(Self::Fixed(port.parse()?)),
+        }
+    }
+}
+
+impl From<u16> for Port {
+    fn from(value: u16) -> Self {
+        Self::Fixed(value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "builder",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "String", into = "String")
+)]
+/// # 0MQ endpoint
+///
+/// Typed representation of the endpoints accepted by [`bind()`] and [`connect()`]. String
+/// endpoints are parsed and validated with [`FromStr`] resp. [`TryFrom`], and [`Display`]
+/// renders the endpoint in the format expected by 0MQ.
+///
+/// [`bind()`]: crate::socket::Socket::bind
+/// [`connect()`]: crate::socket::Socket::connect
+/// [`Display`]: fmt::Display
+pub enum Endpoint {
+    /// unicast transport using TCP, i.e. `tcp://127.0.0.1:5555`, or with a source address
+    /// `tcp://192.168.1.17:5555;192.168.1.1:5555`
+    Tcp {
+        /// Source address for outgoing connections
+        source: Option<String>,
+        /// Interface, IP address, or DNS name
+        host: String,
+        /// Port
+        port: Port,
+    },
+    /// local inter-process communication transport, i.e. `ipc:///tmp/feeds/0`
+    Ipc {
+        /// File system path, abstract namespace name starting with `@`, or `*` for a randomly
+        /// assigned path
+        path: String,
+    },
+    /// local in-process (inter-thread) communication transport, i.e. `inproc://my-endpoint`
+    Inproc {
+        /// Unique name of the endpoint
+        name: String,
+    },
+    /// reliable multicast transport using PGM, i.e. `pgm://eth0;239.192.1.1:5555`
+    Pgm {
+        /// Interface to use
+        interface: String,
+        /// Multicast group address
+        multicast_group: String,
+        /// Port
+        port: u16,
+    },
+    /// reliable multicast transport using PGM encapsulated in UDP, i.e.
+    /// `epgm://eth0;239.192.1.1:5555`
+    Epgm {
+        /// Interface to use
+        interface: String,
+        /// Multicast group address
+        multicast_group: String,
+        /// Port
+        port: u16,
+    },
+    /// NACK-Oriented Reliable Multicast transport, i.e. `norm://2,eth0;224.1.2.3:5556`
+    Norm {
+        /// Optional node id and interface, as well as the unicast or multicast address
+        address: String,
+        /// Port
+        port: u16,
+    },
+    /// unreliable unicast and multicast transport using UDP, i.e. `udp://127.0.0.1:5555`, or with
+    /// an interface `udp://eth0;239.0.0.1:5555`
+    Udp {
+        /// Interface or source address
+        source: Option<String>,
+        /// Interface, IP address, DNS name, or multicast group
+        host: String,
+        /// Port
+        port: Port,
+    },
+    /// WebSocket transport, i.e. `ws://127.0.0.1:5555/path`
+    Ws {
+        /// Interface, IP address, or DNS name
+        host: String,
+        /// Port
+        port: Port,
+        /// Resource path including the leading `/`, or empty
+        path: String,
+    },
+    /// WebSocket transport over TLS, i.e. `wss://example.com:443/path`
+    Wss {
+        /// Interface, IP address, or DNS name
+        host: String,
+        /// Port
+        port: Port,
+        /// Resource path including the leading `/`, or empty
+        path: String,
+    },
+    /// virtual machine communications interface transport, i.e. `vmci://2:5555`
+    Vmci {
+        /// Context id, `@` for the local context id, or `*` for any context id
+        cid: String,
+        /// Port
+        port: Port,
+    },
+    /// unicast transport using TIPC, i.e. `tipc://<1.2.3:4>`
+    Tipc {
+        /// Port name, port name range, or port id
+        address: String,
+    },
+}
+
+impl Endpoint {
+    /// Transport name of the endpoint, i.e. `tcp`
+    pub fn transport(&self) -> &'static str {
+        match self {
+            Self::Tcp { .. } => "tcp",
+            Self::Ipc { .. } => "ipc",
+            Self::Inproc { .. } => "inproc",
+            Self::Pgm { .. } => "pgm",
+            Self::Epgm { .. } => "epgm",
+            Self::Norm { .. } => "norm",
+            Self::Udp { .. } => "udp",
+            Self::Ws { .. } => "ws",
+            Self::Wss { .. } => "wss",
+            Self::Vmci { .. } => "vmci",
+            Self::Tipc { .. } => "tipc",
+        }
+    }
+
+    /// Fixed port number of the endpoint. Returns `None` for transports without ports, and for
+    /// wildcard ports. For endpoints retrieved through [`last_endpoint()`] this is the port
+    /// resolved by 0MQ for a wildcard bind.
+    ///
+    /// [`last_endpoint()`]: crate::socket::Socket::last_endpoint
+    pub fn port(&self) -> Option<u16> {
+        match self {
+            Self::Tcp { port, .. }
+            | Self::Udp { port, .. }
+            | Self::Ws { port, .. }
+            | Self::Wss { port, .. }
+            | Self::Vmci { port, .. } => match port {
+                Port::Fixed(port) => Some(*port),
+                Port::Wildcard => None,
+            },
+            Self::Pgm { port, .. } | Self::Epgm { port, .. } | Self::Norm { port, .. } => {
+                Some(*port)
+            }
+            Self::Ipc { .. } | Self::Inproc { .. } | Self::Tipc { .. } => None,
+        }
+    }
+}
+
+fn non_empty(value: &str) -> ZmqResult<String> {
+    if value.is_empty() {
+        return Err(ZmqError::InvalidArgument);
+    }
+
+    Ok(value.to_string())
+}
+
+fn split_port(address: &str) -> ZmqResult<(String, &str)> {
+    let Some((host, port)) = address.rsplit_once(':') else {
+        return Err(ZmqError::InvalidArgument);
+    };
+
+    Ok((non_empty(host)?, port))
+}
+
+fn split_source(address: &str) -> ZmqResult<(Option<String>, &str)> {
+    match address.split_once(';') {
+        None => Ok((None, address)),
+        Some((source, destination)) => Ok((Some(non_empty(source)?), destination)),
+    }
+}
+
+impl FromStr for Endpoint {
+    type Err = ZmqError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let Some((transport, address)) = value.split_once("://") else {
+            return Err(ZmqError::InvalidArgument);
+        };
+
+        match transport {
+            "tcp" | "udp" => {
+                let (source, destination) = split_source(address)?;
+                let (host, port) = split_port(destination)?;
+                let port = port.parse()?;
+
+                if transport == "tcp" {
+                    Ok(Self::Tcp { source, host, port })
+                } else {
+                    Ok(Self::Udp { source, host, port })
+                }
+            }
+            "ipc" => Ok(Self::Ipc {
+                path: non_empty(address)?,
+            }),
+            "inproc" => Ok(Self::Inproc {
+                name: non_empty(address)?,
+            }),
+            "pgm" | "epgm" => {
+                let Some((interface, destination)) = address.split_once(';') else {
+                    return Err(ZmqError::InvalidArgument);
+                };
+                let interface = non_empty(interface)?;
+                let (multicast_group, port) = split_port(destination)?;
+                let port = port.parse()?;
+
+                if transport == "pgm" {
+                    Ok(Self::Pgm {
+                        interface,
+                        multicast_group,
+                        port,
+                    })
+                } else {
+                    Ok(Self::Epgm {
+                        interface,
+                        multicast_group,
+                        port,
+                    })
+                }
+            }
+            "norm" => {
+                let (address, port) = split_port(address)?;
+                Ok(Self::Norm {
+                    address,
+                    port: port.parse()?,
+                })
+            }
+            "ws" | "wss" => {
+                let (authority, path) = address
+                    .find('/')
+                    .map_or((address, ""), |index| address.split_at(index));
+                let (host, port) = split_port(authority)?;
+                let port = port.parse()?;
+                let path = path.to_string();
+
+                if transport == "ws" {
+                    Ok(Self::Ws { host, port, path })
+                } else {
+                    Ok(Self::Wss { host, port, path })
+                }
+            }
+            "vmci" => {
+                let (cid, port) = split_port(address)?;
+                Ok(Self::Vmci {
+                    cid,
+                    port: port.parse()?,
+                })
+            }
+            "tipc" => Ok(Self::Tipc {
+                address: non_empty(address)?,
+            }),
+            _ => Err(ZmqError::ProtocolNotSupported),
+        }
+    }
+}
+
+impl fmt::Display for Endpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}://", self.transport())?;
+
+        match self {
+            Self::Tcp { source, host, port } | Self::Udp { source, host, port } => {
+                if let Some(source) = source {
+                    write!(f, "{source};")?;
+                }
+                write!(f, "{host}:{port}")
+            }
+            Self::Ipc { path } => write!(f, "{path}"),
+            Self::Inproc { name } => write!(f, "{name}"),
+            Self::Pgm {
+                interface,
+                multicast_group,
+                port,
+            }
+            | Self::Epgm {
+                interface,
+                multicast_group,
+                port,
+            } => write!(f, "{interface};{multicast_group}:{port}"),
+            Self::Norm { address, port } => write!(f, "{address}:{port}"),
+            Self::Ws { host, port, path } | Self::Wss { host, port, path } => {
+                write!(f, "{host}:{port}{path}")
+            }
+            Self::Vmci { cid, port } => write!(f, "{cid}:{port}"),
+            Self::Tipc { address } => write!(f, "{address}"),
+        }
+    }
+}
+
+impl TryFrom<&str> for Endpoint {
+    type Error = ZmqError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<String> for Endpoint {
+    type Error = ZmqError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<&String> for Endpoint {
+    type Error = ZmqError;
+
+    fn try_from(value: &String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<&Endpoint> for Endpoint {
+    fn from(value: &Endpoint) -> Self {
+        value.clone()
+    }
+}
+
+impl From<Endpoint> for String {
+    fn from(value: Endpoint) -> Self {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod endpoint_tests {
+    use rstest::*;
+
+    use super::{Endpoint, Port};
+    use crate::prelude::ZmqError;
+
+    #[rstest]
+    #[case(
+        "tcp://127.0.0.1:5555",
+        Endpoint::Tcp { source: None, host: "127.0.0.1".to_string(), port: Port::Fixed(5555) }
+    )]
+    #[case(
+        "tcp://*:*",
+        Endpoint::Tcp { source: None, host: "*".to_string(), port: Port::Wildcard }
+    )]
+    #[case(
+        "tcp://[::1]:5555",
+        Endpoint::Tcp { source: None, host: "[::1]".to_string(), port: Port::Fixed(5555) }
+    )]
+    #[case(
+        "tcp://192.168.1.17:5555;192.168.1.1:5556",
+        Endpoint::Tcp {
+            source: Some("192.168.1.17:5555".to_string()),
+            host: "192.168.1.1".to_string(),
+            port: Port::Fixed(5556),
+        }
+    )]
+    #[case("ipc:///tmp/feeds/0", Endpoint::Ipc { path: "/tmp/feeds/0".to_string() })]
+    #[case("ipc://@abstract", Endpoint::Ipc { path: "@abstract".to_string() })]
+    #[case("inproc://my-endpoint", Endpoint::Inproc { name: "my-endpoint".to_string() })]
+    #[case(
+        "pgm://eth0;239.192.1.1:5555",
+        Endpoint::Pgm {
+            interface: "eth0".to_string(),
+            multicast_group: "239.192.1.1".to_string(),
+            port: 5555,
+        }
+    )]
+    #[case(
+        "epgm://192.168.1.1;239.192.1.1:5555",
+        Endpoint::Epgm {
+            interface: "192.168.1.1".to_string(),
+            multicast_group: "239.192.1.1".to_string(),
+            port: 5555,
+        }
+    )]
+    #[case(
+        "norm://2,eth0;224.1.2.3:5556",
+        Endpoint::Norm { address: "2,eth0;224.1.2.3".to_string(), port: 5556 }
+    )]
+    #[case(
+        "udp://eth0;239.0.0.1:5555",
+        Endpoint::Udp {
+            source: Some("eth0".to_string()),
+            host: "239.0.0.1".to_string(),
+            port: Port::Fixed(5555),
+        }
+    )]
+    #[case(
+        "ws://127.0.0.1:8080/feed",
+        Endpoint::Ws { host: "127.0.0.1".to_string(), port: Port::Fixed(8080), path: "/feed".to_string() }
+    )]
+    #[case(
+        "ws://localhost:*",
+        Endpoint::Ws { host: "localhost".to_string(), port: Port::Wildcard, path: "".to_string() }
+    )]
+    #[case(
+        "wss://example.com:443/feed",
+        Endpoint::Wss { host: "example.com".to_string(), port: Port::Fixed(443), path: "/feed".to_string() }
+    )]
+    #[case(
+        "wss://localhost:*",
+        Endpoint::Wss { host: "localhost".to_string(), port: Port::Wildcard, path: "".to_string() }
+    )]
+    #[case("vmci://@:5555", Endpoint::Vmci { cid: "@".to_string(), port: Port::Fixed(5555) })]
+    #[case("tipc://<1.2.3:4>", Endpoint::Tipc { address: "<1.2.3:4>".to_string() })]
+    fn parses_and_displays_endpoint(#[case] raw_endpoint: &str, #[case] expected: Endpoint) {
+        let endpoint: Endpoint = raw_endpoint.parse().unwrap();
+
+        assert_eq!(endpoint, expected);
+        assert_eq!(endpoint.to_string(), raw_endpoint);
+    }
+
+    #[rstest]
+    #[case("127.0.0.1:5555", ZmqError::InvalidArgument)]
+    #[case("tcp://127.0.0.1", ZmqError::InvalidArgument)]
+    #[case("tcp://:5555", ZmqError::InvalidArgument)]
+    #[case("tcp://127.0.0.1:65536", ZmqError::InvalidArgument)]
+    #[case("tcp://;127.0.0.1:5555", ZmqError::InvalidArgument)]
+    #[case("ipc://", ZmqError::InvalidArgument)]
+    #[case("inproc://", ZmqError::InvalidArgument)]
+    #[case("pgm://239.192.1.1:5555", ZmqError::InvalidArgument)]
+    #[case("epgm://eth0;239.192.1.1:*", ZmqError::InvalidArgument)]
+    #[case("wss://:443/feed", ZmqError::InvalidArgument)]
+    #[case("http://127.0.0.1:80", ZmqError::ProtocolNotSupported)]
+    fn parse_fails_for_invalid_endpoint(#[case] raw_endpoint: &str, #[case] expected: ZmqError) {
+        let result = raw_endpoint.parse::<Endpoint>();
+
+        assert!(result.is_err_and(|err| err == expected));
+    }
+
+    #[rstest]
+    #[case("tcp://127.0.0.1:5555", Some(5555))]
+    #[case("tcp://127.0.0.1:*", None)]
+    #[case("pgm://eth0;239.192.1.1:5555", Some(5555))]
+    #[case("wss://example.com:443/feed", Some(443))]
+    #[case("inproc://my-endpoint", None)]
+    fn port_returns_fixed_port(#[case] raw_endpoint: &str, #[case] expected: Option<u16>) {
+        let endpoint: Endpoint = raw_endpoint.parse().unwrap();
+
+        assert_eq!(endpoint.port(), expected);
+    }
+}
@@ -1,5 +1,5 @@
 use alloc::ffi::{IntoStringError, NulError};
-use core::{ffi::FromBytesUntilNulError, num::ParseIntError};
+use core::{convert::Infallible, ffi::FromBytesUntilNulError, num::ParseIntError};
 
 use thiserror::Error;
 
@@ -102,6 +102,9 @@ pub enum ZmqError {
     /// ENOMEM
     #[error("Insufficient memory")]
     InsufficientMemory,
+    /// no reply received within the configured timeout, after all retries
+    #[error("No reply after {0} attempts")]
+    RetriesExhausted(u32),
     #[error("other")]
     Other(i32),
 }
@@ -163,12 +166,25 @@ impl From<NulError> for ZmqError {
     }
 }
 
+impl From<Infallible> for ZmqError {
+    fn from(value: Infallible) -> Self {
+        match value {}
+    }
+}
+
 impl From<ParseIntError> for ZmqError {
     fn from(_err: ParseIntError) -> Self {
         Self::InvalidArgument
     }
 }
 
+#[cfg(feature = "futures")]
+impl From<std::io::Error> for ZmqError {
+    fn from(err: std::io::Error) -> Self {
+        err.raw_os_error().map_or(Self::Other(-1), ZmqError::from)
+    }
+}
+
 /// 0MQ specific result type
 pub type ZmqResult<T, E = ZmqError> = Result<T, E>;
 
@@ -254,4 +270,19 @@ mod error_tests {
             ZmqError::InvalidArgument
         );
     }
+
+    #[cfg(feature = "futures")]
+    #[test]
+    fn from_io_error() {
+        assert_eq!(
+            ZmqError::from(std::io::Error::from_raw_os_error(
+                zmq_sys_crate::errno::EINTR
+            )),
+            ZmqError::Interrupted
+        );
+        assert_eq!(
+            ZmqError::from(std::io::Error::other("other")),
+            ZmqError::Other(-1)
+        );
+    }
 }
@@ -1,10 +1,12 @@
 use alloc::ffi::CString;
+#[cfg(feature = "bytes")]
+use alloc::sync::Arc;
 #[cfg(nightly)]
 use core::hint::cold_path;
 #[rustversion::since(1.87)]
 use core::str;
 use core::{
-    ffi::{CStr, c_long, c_void},
+    ffi::{CStr, c_int, c_long, c_void},
     fmt::Formatter,
     ops::Deref,
     ptr, slice,
@@ -373,6 +375,17 @@ impl RawSocket {
         Ok(buffer)
     }
 
+    pub(crate) fn get_sockopt_binary(&self, option: i32) -> ZmqResult<Vec<u8>> {
+        let mut buffer = vec![0; MAX_OPTION_STR_LEN];
+        let mut buffer_len = buffer.len();
+
+        self.get_sockopt(option, buffer.as_mut_ptr() as *mut c_void, &mut buffer_len)?;
+
+        buffer.truncate(buffer_len);
+
+        Ok(buffer)
+    }
+
     pub(crate) fn get_sockopt_string(&self, option: i32) -> ZmqResult<String> {
         let value = self.get_sockopt_bytes(option)?;
 
@@ -475,6 +488,59 @@ impl RawSocket {
         Ok(())
     }
 
+    #[cfg(feature = "draft-api")]
+    pub(crate) fn monitor_versioned(
+        &self,
+        endpoint: &str,
+        events: u64,
+        event_version: i32,
+        socket_type: i32,
+    ) -> ZmqResult<()> {
+        let c_endpoint = CString::from_str(endpoint)?;
+
+        let socket_guard = self.socket.lock();
+        if unsafe {
+            zmq_sys_crate::zmq_socket_monitor_versioned(
+                *socket_guard,
+                c_endpoint.as_ptr(),
+                events,
+                event_version,
+                socket_type,
+            )
+        } == -1
+        {
+            #[cfg(nightly)]
+            cold_path();
+            match unsafe { zmq_sys_crate::zmq_errno() } {
+                errno @ (zmq_sys_crate::errno::ETERM
+                | zmq_sys_crate::errno::EPROTONOSUPPORT
+                | zmq_sys_crate::errno::EINVAL) => {
+                    return Err(ZmqError::from(errno));
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "draft-api")]
+    pub(crate) fn monitor_pipes_stats(&self) -> ZmqResult<()> {
+        let socket_guard = self.socket.lock();
+        if unsafe { zmq_sys_crate::zmq_socket_monitor_pipes_stats(*socket_guard) } == -1 {
+            #[cfg(nightly)]
+            cold_path();
+            match unsafe { zmq_sys_crate::zmq_errno() } {
+                errno @ (zmq_sys_crate::errno::EINVAL | zmq_sys_crate::errno::EAGAIN) => {
+                    return Err(ZmqError::from(errno));
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        Ok(())
+    }
+
     pub(crate) fn send(&self, zmq_msg: &mut RawMessage, flags: i32) -> ZmqResult<()> {
         let socket_guard = self.socket.lock();
 
@@ -627,6 +693,205 @@ impl Drop for RawSocket {
     }
 }
 
+#[cfg(all(feature = "futures", feature = "draft-api"))]
+pub(crate) struct RawPoller {
+    poller: FairMutex<*mut c_void>,
+}
+
+#[cfg(all(feature = "futures", feature = "draft-api"))]
+impl RawPoller {
+    pub(crate) fn new() -> ZmqResult<Self> {
+        let poller_ptr = unsafe { zmq_sys_crate::zmq_poller_new() };
+        if poller_ptr.is_null() {
+            #[cfg(nightly)]
+            cold_path();
+            match unsafe { zmq_sys_crate::zmq_errno() } {
+                errno @ zmq_sys_crate::errno::ENOMEM => return Err(ZmqError::from(errno)),
+                _ => unreachable!(),
+            }
+        }
+
+        Ok(Self {
+            poller: FairMutex::new(poller_ptr),
+        })
+    }
+
+    pub(crate) fn add(&self, socket: &RawSocket, events: PollEvents) -> ZmqResult<()> {
+        let poller_guard = self.poller.lock();
+        let socket_guard = socket.socket.lock();
+        if unsafe {
+            zmq_sys_crate::zmq_poller_add(
+                *poller_guard,
+                *socket_guard,
+                ptr::null_mut(),
+                events.bits(),
+            )
+        } == -1
+        {
+            #[cfg(nightly)]
+            cold_path();
+            match unsafe { zmq_sys_crate::zmq_errno() } {
+                errno @ (zmq_sys_crate::errno::EINVAL
+                | zmq_sys_crate::errno::ENOTSOCK
+                | zmq_sys_crate::errno::ENOMEM
+                | zmq_sys_crate::errno::EMFILE) => return Err(ZmqError::from(errno)),
+                _ => unreachable!(),
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn fd(&self) -> ZmqResult<zmq_sys_crate::zmq_fd_t> {
+        let mut fd = zmq_sys_crate::zmq_fd_t::default();
+
+        let poller_guard = self.poller.lock();
+        if unsafe { zmq_sys_crate::zmq_poller_fd(*poller_guard, &mut fd) } == -1 {
+            #[cfg(nightly)]
+            cold_path();
+            match unsafe { zmq_sys_crate::zmq_errno() } {
+                errno @ (zmq_sys_crate::errno::EINVAL | zmq_sys_crate::errno::EFAULT) => {
+                    return Err(ZmqError::from(errno));
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        Ok(fd)
+    }
+
+    pub(crate) fn wait(&self, timeout_ms: i64) -> ZmqResult<PollEvents> {
+        let mut event = zmq_sys_crate::zmq_poller_event_t::default();
+
+        let poller_guard = self.poller.lock();
+        if unsafe {
+            zmq_sys_crate::zmq_poller_wait(*poller_guard, &mut event, timeout_ms as c_long)
+        } == -1
+        {
+            match unsafe { zmq_sys_crate::zmq_errno() } {
+                zmq_sys_crate::errno::EAGAIN => return Ok(PollEvents::empty()),
+                errno @ (zmq_sys_crate::errno::ENOMEM
+                | zmq_sys_crate::errno::ETERM
+                | zmq_sys_crate::errno::EFAULT
+                | zmq_sys_crate::errno::EINTR) => return Err(ZmqError::from(errno)),
+                _ => unreachable!(),
+            }
+        }
+
+        Ok(PollEvents::from_bits_truncate(event.events))
+    }
+}
+
+#[cfg(all(feature = "futures", feature = "draft-api"))]
+impl Drop for RawPoller {
+    fn drop(&mut self) {
+        let mut poller_guard = self.poller.lock();
+        if unsafe { zmq_sys_crate::zmq_poller_destroy(&mut *poller_guard) } == -1 {
+            match unsafe { zmq_sys_crate::zmq_errno() } {
+                zmq_sys_crate::errno::EFAULT => (),
+                _ => unreachable!(),
+            }
+        }
+    }
+}
+
+pub(crate) struct RawTimers {
+    timers: FairMutex<*mut c_void>,
+    fired: Box<FairMutex<Vec<i32>>>,
+}
+
+unsafe extern "C" fn raw_timer_fired(timer_id: c_int, arg: *mut c_void) {
+    let fired = unsafe { &*(arg as *const FairMutex<Vec<i32>>) };
+    fired.lock().push(timer_id);
+}
+
+impl RawTimers {
+    pub(crate) fn new() -> ZmqResult<Self> {
+        let timers_ptr = unsafe { zmq_sys_crate::zmq_timers_new() };
+        if timers_ptr.is_null() {
+            #[cfg(nightly)]
+            cold_path();
+            match unsafe { zmq_sys_crate::zmq_errno() } {
+                errno @ zmq_sys_crate::errno::ENOMEM => return Err(ZmqError::from(errno)),
+                _ => unreachable!(),
+            }
+        }
+
+        Ok(Self {
+            timers: FairMutex::new(timers_ptr),
+            fired: Box::default(),
+        })
+    }
+
+    pub(crate) fn add(&self, interval: usize) -> ZmqResult<i32> {
+        let fired_ptr = &*self.fired as *const FairMutex<Vec<i32>> as *mut c_void;
+
+        let timers_guard = self.timers.lock();
+        match unsafe {
+            zmq_sys_crate::zmq_timers_add(*timers_guard, interval, Some(raw_timer_fired), fired_ptr)
+        } {
+            -1 => {
+                #[cfg(nightly)]
+                cold_path();
+                match unsafe { zmq_sys_crate::zmq_errno() } {
+                    errno @ (zmq_sys_crate::errno::EFAULT | zmq_sys_crate::errno::EINVAL) => {
+                        Err(ZmqError::from(errno))
+                    }
+                    _ => unreachable!(),
+                }
+            }
+            timer_id => Ok(timer_id),
+        }
+    }
+
+    pub(crate) fn cancel(&self, timer_id: i32) -> ZmqResult<()> {
+        let timers_guard = self.timers.lock();
+        if unsafe { zmq_sys_crate::zmq_timers_cancel(*timers_guard, timer_id) } == -1 {
+            #[cfg(nightly)]
+            cold_path();
+            match unsafe { zmq_sys_crate::zmq_errno() } {
+                errno @ (zmq_sys_crate::errno::EFAULT | zmq_sys_crate::errno::EINVAL) => {
+                    return Err(ZmqError::from(errno));
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn timeout(&self) -> i64 {
+        let timers_guard = self.timers.lock();
+        unsafe { zmq_sys_crate::zmq_timers_timeout(*timers_guard) as i64 }
+    }
+
+    pub(crate) fn execute(&self) -> ZmqResult<Vec<i32>> {
+        let timers_guard = self.timers.lock();
+        if unsafe { zmq_sys_crate::zmq_timers_execute(*timers_guard) } == -1 {
+            #[cfg(nightly)]
+            cold_path();
+            match unsafe { zmq_sys_crate::zmq_errno() } {
+                errno @ zmq_sys_crate::errno::EFAULT => return Err(ZmqError::from(errno)),
+                _ => unreachable!(),
+            }
+        }
+
+        Ok(core::mem::take(&mut *self.fired.lock()))
+    }
+}
+
+impl Drop for RawTimers {
+    fn drop(&mut self) {
+        let mut timers_guard = self.timers.lock();
+        if unsafe { zmq_sys_crate::zmq_timers_destroy(&mut *timers_guard) } == -1 {
+            match unsafe { zmq_sys_crate::zmq_errno() } {
+                zmq_sys_crate::errno::EFAULT => (),
+                _ => unreachable!(),
+            }
+        }
+    }
+}
+
 pub(crate) struct RawMessage {
     message: zmq_sys_crate::zmq_msg_t,
 }
@@ -720,6 +985,21 @@ impl RawMessage {
             .ok()
             .filter(|group| !group.is_empty())
     }
+
+    pub(crate) fn gets(&self, property: &str) -> Option<String> {
+        let c_property = CString::from_str(property).ok()?;
+
+        let msg_property =
+            unsafe { zmq_sys_crate::zmq_msg_gets(&self.message, c_property.as_ptr()) };
+
+        if msg_property.is_null() {
+            return None;
+        }
+        unsafe { CStr::from_ptr(msg_property) }
+            .to_owned()
+            .into_string()
+            .ok()
+    }
 }
 
 impl Default for RawMessage {
@@ -907,6 +1187,53 @@ impl<'a> From<&'a [u8]> for RawMessage {
     }
 }
 
+#[cfg(feature = "bytes")]
+unsafe extern "C" fn drop_zmq_msg_owner<T>(_data: *mut c_void, hint: *mut c_void) {
+    let _ = unsafe { Box::from_raw(hint as *mut T) };
+}
+
+#[cfg(feature = "bytes")]
+impl RawMessage {
+    fn from_owner<T>(owner: T) -> Self
+    where
+        T: AsRef<[u8]> + Send + 'static,
+    {
+        let size = owner.as_ref().len();
+        if size == 0 {
+            return Self::new();
+        }
+
+        let owner = Box::into_raw(Box::new(owner));
+        let data = unsafe { (*owner).as_ref().as_ptr() };
+
+        let mut message = zmq_sys_crate::zmq_msg_t::default();
+        unsafe {
+            zmq_sys_crate::zmq_msg_init_data(
+                &mut message,
+                data as *mut c_void,
+                size,
+                Some(drop_zmq_msg_owner::<T>),
+                owner as *mut c_void,
+            )
+        };
+        Self { message }
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl From<bytes::Bytes> for RawMessage {
+    fn from(value: bytes::Bytes) -> Self {
+        Self::from_owner(value)
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl From<Arc<[u8]>> for RawMessage {
+    fn from(value: Arc<[u8]>) -> Self {
+        Self::from_owner(value)
+    }
+}
+
 impl<'a> From<&'a str> for RawMessage {
     fn from(value: &'a str) -> Self {
         RawMessage::from(value.as_bytes())
@@ -940,3 +1267,53 @@ impl RawPollItem {
         }
     }
 }
+
+#[derive(DebugDeriveMore, Default)]
+#[debug("RawPollItems {{ ... }}")]
+pub(crate) struct RawPollItems {
+    items: Vec<zmq_sys_crate::zmq_pollitem_t>,
+}
+
+impl RawPollItems {
+    pub(crate) fn push_socket(&mut self, socket: &RawSocket, events: PollEvents) {
+        let socket_guard = socket.socket.lock();
+        self.items.push(zmq_sys_crate::zmq_pollitem_t {
+            socket: *socket_guard,
+            fd: Default::default(),
+            events: events.bits(),
+            revents: 0,
+        });
+    }
+
+    pub(crate) fn push_fd(&mut self, fd: zmq_sys_crate::RawFd, events: PollEvents) {
+        self.items.push(zmq_sys_crate::zmq_pollitem_t {
+            socket: ptr::null_mut(),
+            fd: fd as _,
+            events: events.bits(),
+            revents: 0,
+        });
+    }
+
+    pub(crate) fn remove(&mut self, index: usize) {
+        self.items.remove(index);
+    }
+
+    pub(crate) fn revents(&self, index: usize) -> PollEvents {
+        PollEvents::from_bits_truncate(self.items[index].revents)
+    }
+
+    pub(crate) fn poll(&mut self, timeout_ms: i64) -> ZmqResult<i32> {
+        match unsafe {
+            zmq_sys_crate::zmq_poll(
+                self.items.as_mut_ptr(),
+                self.items.len() as i32,
+                timeout_ms as c_long,
+            )
+        } {
+            // besides ETERM, EFAULT and EINTR, closed sockets and thread-safe sockets polled
+            // through `zmq_poller` report ENOTSOCK or EINVAL, and allocations may fail with ENOMEM
+            -1 => Err(ZmqError::from(unsafe { zmq_sys_crate::zmq_errno() })),
+            num_events => Ok(num_events),
+        }
+    }
+}
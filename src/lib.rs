@@ -17,14 +17,23 @@
 extern crate alloc;
 extern crate core;
 
+pub mod actor;
 pub mod auth;
+#[cfg(feature = "codec")]
+pub mod codec;
 pub mod context;
+pub mod endpoint;
 #[doc(hidden)]
 pub mod error;
 mod ffi;
 pub mod message;
+pub mod patterns;
+pub mod poller;
+pub mod reactor;
 pub mod security;
 pub mod socket;
+#[cfg(feature = "topology")]
+pub mod topology;
 
 use alloc::ffi::CString;
 #[cfg(nightly)]
@@ -38,17 +47,22 @@ use derive_more::Display;
 pub use error::{ZmqError, ZmqResult};
 
 pub mod prelude {
+    #[cfg(feature = "codec")]
+    pub use crate::codec::{Codec, CodecError};
     #[cfg(feature = "builder")]
     pub use crate::context::ContextBuilder;
+    #[cfg(feature = "futures")]
+    pub use crate::socket::MultipartStream;
     #[cfg(all(feature = "draft-api", feature = "builder"))]
     pub use crate::socket::{
-        ChannelBuilder, ClientBuilder, DishBuilder, GatherBuilder, PeerBuilder, RadioBuilder,
-        ScatterBuilder, ServerBuilder,
+        ChannelBuilder, ClientBuilder, DatagramBuilder, DishBuilder, GatherBuilder, PeerBuilder,
+        RadioBuilder, ScatterBuilder, ServerBuilder,
     };
     #[cfg(feature = "draft-api")]
     pub use crate::socket::{
-        ChannelSocket, ClientSocket, DishSocket, GatherSocket, PeerSocket, RadioSocket,
-        ScatterSocket, ServerSocket,
+        ChannelSocket, ClientSocket, DatagramSocket, DishSocket, GatherSocket, MonitorEventV2,
+        MonitorSocketEventV2, MonitorSocketV2, PeerSocket, RadioSocket, ScatterSocket,
+        ServerSocket,
     };
     #[cfg(feature = "builder")]
     pub use crate::socket::{
@@ -59,12 +73,15 @@ pub mod prelude {
     pub use crate::{
         ZmqError, ZmqResult,
         context::{Context, ContextOption},
-        message::{Message, MultipartMessage},
+        endpoint::{Endpoint, Port},
+        message::{Envelope, Message, MultipartMessage, RoutingId},
+        poller::{Poller, PollerEvent},
         socket::{
             DealerSocket, MonitorFlags, MonitorReceiver, MonitorSocket, MonitorSocketEvent,
-            MultipartReceiver, MultipartSender, PairSocket, PublishSocket, PullSocket, PushSocket,
-            Receiver, RecvFlags, ReplySocket, RequestSocket, RouterSocket, SendFlags, Sender,
-            Socket, SocketOption, StreamSocket, SubscribeSocket, XPublishSocket, XSubscribeSocket,
+            MultipartReceiver, MultipartSender, PairSocket, PollEvents, PublishSocket, PullSocket,
+            PushSocket, Receiver, RecvFlags, ReplySocket, RequestSocket, RouterSocket, SendFlags,
+            Sender, Socket, SocketOption, StreamSocket, SubscribeSocket, SubscriptionEvent,
+            Subscriptions, XPublishSocket, XSubscribeSocket,
         },
     };
 }
@@ -247,14 +264,180 @@ where
     unreachable!()
 }
 
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Hash)]
+/// Commands for controlling a proxy started with [`proxy_steerable()`]
+///
+/// [`proxy_steerable()`]: proxy_steerable
+pub enum ProxyCommand {
+    /// suspend the proxy's activities
+    #[display("PAUSE")]
+    Pause,
+    /// resume the proxy's activities after a [`Pause`](ProxyCommand::Pause)
+    #[display("RESUME")]
+    Resume,
+    /// terminate the proxy, and return from [`proxy_steerable()`]
+    #[display("TERMINATE")]
+    Terminate,
+    /// request the proxy's [`ProxyStatistics`]
+    #[display("STATISTICS")]
+    Statistics,
+}
+
+impl From<ProxyCommand> for message::Message {
+    fn from(value: ProxyCommand) -> Self {
+        message::Message::from(value.to_string().as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+/// Message and byte counts of one side of a steerable proxy
+pub struct ProxySocketStatistics {
+    /// number of messages received on this socket
+    pub messages_received: u64,
+    /// number of bytes received on this socket
+    pub bytes_received: u64,
+    /// number of messages sent on this socket
+    pub messages_sent: u64,
+    /// number of bytes sent on this socket
+    pub bytes_sent: u64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+/// Statistics reply of a steerable proxy to the [`Statistics`] command
+///
+/// [`Statistics`]: ProxyCommand::Statistics
+pub struct ProxyStatistics {
+    /// statistics of the frontend socket
+    pub frontend: ProxySocketStatistics,
+    /// statistics of the backend socket
+    pub backend: ProxySocketStatistics,
+}
+
+impl TryFrom<message::MultipartMessage> for ProxyStatistics {
+    type Error = ZmqError;
+
+    fn try_from(value: message::MultipartMessage) -> Result<Self, Self::Error> {
+        if value.len() != 8 {
+            return Err(ZmqError::InvalidArgument);
+        }
+
+        let counters = value
+            .iter()
+            .map(|part| {
+                part.bytes()
+                    .try_into()
+                    .map(u64::from_ne_bytes)
+                    .map_err(|_err| ZmqError::InvalidArgument)
+            })
+            .collect::<ZmqResult<Vec<_>>>()?;
+
+        Ok(Self {
+            frontend: ProxySocketStatistics {
+                messages_received: counters[0],
+                bytes_received: counters[1],
+                messages_sent: counters[2],
+                bytes_sent: counters[3],
+            },
+            backend: ProxySocketStatistics {
+                messages_received: counters[4],
+                bytes_received: counters[5],
+                messages_sent: counters[6],
+                bytes_sent: counters[7],
+            },
+        })
+    }
+}
+
+/// # Start built-in 0MQ proxy with control flow
+///
+/// The [`proxy_steerable()`] function starts the built-in 0MQ proxy in the current application
+/// thread, just like [`proxy()`]. In addition to that, the proxy can be controlled through the
+/// `control` socket by sending [`ProxyCommand`]s to it:
+///
+/// * [`Pause`] suspends the proxy's activities, so that messages are queued on the frontend and
+///   backend sockets.
+/// * [`Resume`] continues the proxy's activities.
+/// * [`Terminate`] stops the proxy, and [`proxy_steerable()`] returns successfully.
+/// * [`Statistics`] makes the proxy reply with a multipart message on the `control` socket, that
+///   can be converted into [`ProxyStatistics`].
+///
+/// The `control` socket should be a [`Pair`], or [`Reply`] socket, if [`Statistics`] are
+/// requested, and a [`Subscribe`] socket otherwise.
+///
+/// [`proxy_steerable()`]: #method.proxy_steerable
+/// [`proxy()`]: proxy
+/// [`Pause`]: ProxyCommand::Pause
+/// [`Resume`]: ProxyCommand::Resume
+/// [`Terminate`]: ProxyCommand::Terminate
+/// [`Statistics`]: ProxyCommand::Statistics
+/// [`Pair`]: socket::PairSocket
+/// [`Reply`]: socket::ReplySocket
+/// [`Subscribe`]: socket::SubscribeSocket
+pub fn proxy_steerable<T, U, V, W>(
+    frontend: &Socket<T>,
+    backend: &Socket<U>,
+    capture: Option<&Socket<V>>,
+    control: &Socket<W>,
+) -> ZmqResult<()>
+where
+    T: sealed::SocketType,
+    U: sealed::SocketType,
+    V: sealed::SocketType,
+    W: sealed::SocketType,
+{
+    let frontend_guard = frontend.socket.socket.lock();
+    let backend_guard = backend.socket.socket.lock();
+    let control_guard = control.socket.socket.lock();
+    let return_code = match capture {
+        None => unsafe {
+            zmq_sys_crate::zmq_proxy_steerable(
+                *frontend_guard,
+                *backend_guard,
+                ptr::null_mut(),
+                *control_guard,
+            )
+        },
+        Some(capture) => {
+            let capture_guard = capture.socket.socket.lock();
+            unsafe {
+                zmq_sys_crate::zmq_proxy_steerable(
+                    *frontend_guard,
+                    *backend_guard,
+                    *capture_guard,
+                    *control_guard,
+                )
+            }
+        }
+    };
+
+    if return_code == -1 {
+        #[cfg(nightly)]
+        cold_path();
+        match unsafe { zmq_sys_crate::zmq_errno() } {
+            errno @ (zmq_sys_crate::errno::ETERM
+            | zmq_sys_crate::errno::EINTR
+            | zmq_sys_crate::errno::EFAULT) => {
+                return Err(ZmqError::from(errno));
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod proxy_tests {
     use std::thread;
 
-    use super::{ZmqError, proxy};
+    use rstest::*;
+
+    use super::{
+        ProxyCommand, ProxySocketStatistics, ProxyStatistics, ZmqError, proxy, proxy_steerable,
+    };
     use crate::prelude::{
-        Context, DealerSocket, MultipartReceiver, PairSocket, RecvFlags, RouterSocket, SendFlags,
-        Sender, ZmqResult,
+        Context, DealerSocket, Message, MultipartMessage, MultipartReceiver, PairSocket, Receiver,
+        RecvFlags, RouterSocket, SendFlags, Sender, ZmqResult,
     };
 
     #[test]
@@ -392,4 +575,144 @@ mod proxy_tests {
 
         Ok(())
     }
+
+    #[rstest]
+    #[case(ProxyCommand::Pause, "PAUSE")]
+    #[case(ProxyCommand::Resume, "RESUME")]
+    #[case(ProxyCommand::Terminate, "TERMINATE")]
+    #[case(ProxyCommand::Statistics, "STATISTICS")]
+    fn proxy_command_into_message(#[case] command: ProxyCommand, #[case] expected: &str) {
+        assert_eq!(Message::from(command).to_string(), expected);
+    }
+
+    #[test]
+    fn proxy_statistics_from_multipart_message() -> ZmqResult<()> {
+        let multipart: MultipartMessage = (1u64..=8)
+            .map(|counter| Message::from(counter.to_ne_bytes().to_vec()))
+            .collect::<Vec<_>>()
+            .into();
+
+        assert_eq!(
+            ProxyStatistics::try_from(multipart)?,
+            ProxyStatistics {
+                frontend: ProxySocketStatistics {
+                    messages_received: 1,
+                    bytes_received: 2,
+                    messages_sent: 3,
+                    bytes_sent: 4,
+                },
+                backend: ProxySocketStatistics {
+                    messages_received: 5,
+                    bytes_received: 6,
+                    messages_sent: 7,
+                    bytes_sent: 8,
+                },
+            }
+        );
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(vec![])]
+    #[case(vec![Message::from(0u64.to_ne_bytes().to_vec()); 7])]
+    #[case(vec![Message::from("counter"); 8])]
+    fn proxy_statistics_from_invalid_multipart_message(#[case] parts: Vec<Message>) {
+        assert!(
+            ProxyStatistics::try_from(MultipartMessage::from(parts))
+                .is_err_and(|err| err == ZmqError::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn proxy_steerable_returns_on_terminate() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let frontend_pair = PairSocket::from_context(&context)?;
+        let backend_pair = PairSocket::from_context(&context)?;
+
+        let control_pair = PairSocket::from_context(&context)?;
+        control_pair.bind("inproc://proxy-steerable-terminate")?;
+
+        let controller = PairSocket::from_context(&context)?;
+        controller.connect("inproc://proxy-steerable-terminate")?;
+
+        let handle = thread::spawn(move || {
+            proxy_steerable(
+                &frontend_pair,
+                &backend_pair,
+                None::<&PairSocket>,
+                &control_pair,
+            )
+        });
+
+        controller.send_msg(ProxyCommand::Terminate, SendFlags::empty())?;
+
+        assert!(handle.join().is_ok_and(|result| result.is_ok()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn proxy_steerable_replies_with_statistics() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let frontend_pair = PairSocket::from_context(&context)?;
+        frontend_pair.bind("inproc://proxy-steerable-frontend")?;
+
+        let external_pair = PairSocket::from_context(&context)?;
+        external_pair.connect("inproc://proxy-steerable-frontend")?;
+
+        let backend_pair = PairSocket::from_context(&context)?;
+        backend_pair.bind("inproc://proxy-steerable-backend")?;
+
+        let receiving_pair = PairSocket::from_context(&context)?;
+        receiving_pair.connect("inproc://proxy-steerable-backend")?;
+
+        let control_pair = PairSocket::from_context(&context)?;
+        control_pair.bind("inproc://proxy-steerable-control")?;
+
+        let controller = PairSocket::from_context(&context)?;
+        controller.connect("inproc://proxy-steerable-control")?;
+
+        let handle = thread::spawn(move || {
+            proxy_steerable(
+                &frontend_pair,
+                &backend_pair,
+                None::<&PairSocket>,
+                &control_pair,
+            )
+        });
+
+        external_pair.send_msg("proxied msg", SendFlags::empty())?;
+        assert_eq!(
+            receiving_pair.recv_msg(RecvFlags::empty())?.to_string(),
+            "proxied msg"
+        );
+
+        controller.send_msg(ProxyCommand::Statistics, SendFlags::empty())?;
+        let statistics = ProxyStatistics::try_from(controller.recv_multipart(RecvFlags::empty())?)?;
+
+        assert_eq!(
+            statistics,
+            ProxyStatistics {
+                frontend: ProxySocketStatistics {
+                    messages_received: 1,
+                    bytes_received: 11,
+                    ..Default::default()
+                },
+                backend: ProxySocketStatistics {
+                    messages_sent: 1,
+                    bytes_sent: 11,
+                    ..Default::default()
+                },
+            }
+        );
+
+        controller.send_msg(ProxyCommand::Terminate, SendFlags::empty())?;
+
+        assert!(handle.join().is_ok_and(|result| result.is_ok()));
+
+        Ok(())
+    }
 }
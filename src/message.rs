@@ -4,13 +4,13 @@ use alloc::collections::{
     VecDeque,
     vec_deque::{Drain, IntoIter, Iter, IterMut},
 };
-use core::ops::RangeBounds;
+use core::ops::{Deref, RangeBounds};
 
 use derive_more::{Debug as DebugDeriveMore, Display as DisplayDeriveMore};
-use parking_lot::FairMutex;
+use parking_lot::{FairMutex, FairMutexGuard};
 
 use crate::{
-    ZmqResult,
+    ZmqError, ZmqResult,
     ffi::RawMessage,
     sealed,
     socket::{MultipartSender, Socket},
@@ -21,7 +21,7 @@ use crate::{
 #[display("{}", inner.lock())]
 /// 0MQ single-part message
 pub struct Message {
-    inner: FairMutex<RawMessage>,
+    pub(crate) inner: FairMutex<RawMessage>,
 }
 
 unsafe impl Send for Message {}
@@ -49,6 +49,23 @@ impl Message {
         (*msg_guard).as_ref().to_vec()
     }
 
+    /// returns a view on the message underlying bytes without copying them. The message stays
+    /// locked until the returned [`MessageSlice`] is dropped.
+    pub fn as_slice(&self) -> MessageSlice<'_> {
+        MessageSlice {
+            msg_guard: self.inner.lock(),
+        }
+    }
+
+    /// consumes the message, and returns its underlying bytes without copying them. The message
+    /// is released once the last reference to the returned [`Bytes`] is dropped.
+    ///
+    /// [`Bytes`]: bytes::Bytes
+    #[cfg(feature = "bytes")]
+    pub fn into_bytes(self) -> bytes::Bytes {
+        bytes::Bytes::from_owner(self.inner.into_inner())
+    }
+
     /// returns the message length
     pub fn len(&self) -> usize {
         let msg_guard = self.inner.lock();
@@ -104,6 +121,34 @@ impl Message {
         let msg_guard = self.inner.lock();
         msg_guard.group()
     }
+
+    /// Retrieves a metadata property of a received message, i.e. `Socket-Type`, `Routing-Id`,
+    /// `User-Id`, `Peer-Address`, or any application metadata set by the peer through
+    /// [`set_metadata()`]. Returns `None` if the property is not available on this message.
+    ///
+    /// [`set_metadata()`]: crate::socket::Socket::set_metadata
+    pub fn property<V: AsRef<str>>(&self, property: V) -> Option<String> {
+        let msg_guard = self.inner.lock();
+        msg_guard.gets(property.as_ref())
+    }
+
+    /// Retrieves the socket type of the peer that sent this message.
+    pub fn peer_socket_type(&self) -> Option<String> {
+        self.property("Socket-Type")
+    }
+
+    /// Retrieves the IP address of the peer that sent this message.
+    pub fn peer_address(&self) -> Option<String> {
+        self.property("Peer-Address")
+    }
+
+    /// Retrieves the user id of the peer that sent this message, as provided by the
+    /// [`ZapHandler`] during authentication.
+    ///
+    /// [`ZapHandler`]: crate::auth::ZapHandler
+    pub fn user_id(&self) -> Option<String> {
+        self.property("User-Id")
+    }
 }
 
 impl Default for Message {
@@ -121,6 +166,39 @@ impl Clone for Message {
     }
 }
 
+#[derive(DebugDeriveMore)]
+#[debug("{:?}", msg_guard.deref())]
+/// # View on the bytes of a [`Message`]
+///
+/// Returned by [`as_slice()`]. The message is locked for as long as the view is alive, so the
+/// viewed bytes can't be changed by other threads in the meantime. Drop the view before calling
+/// any other method on the same message, as these would wait for the lock otherwise.
+///
+/// [`as_slice()`]: Message::as_slice
+pub struct MessageSlice<'a> {
+    msg_guard: FairMutexGuard<'a, RawMessage>,
+}
+
+impl Deref for MessageSlice<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.msg_guard.deref()
+    }
+}
+
+impl AsRef<[u8]> for MessageSlice<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.deref()
+    }
+}
+
+impl<T: AsRef<[u8]> + ?Sized> PartialEq<T> for MessageSlice<'_> {
+    fn eq(&self, other: &T) -> bool {
+        self.deref() == other.as_ref()
+    }
+}
+
 impl<T: Into<RawMessage>> From<T> for Message {
     fn from(value: T) -> Self {
         let raw_msg = value.into();
@@ -146,8 +224,9 @@ where
 #[cfg(test)]
 mod message_tests {
     use super::Message;
-    #[cfg(feature = "draft-api")]
-    use crate::prelude::ZmqResult;
+    use crate::prelude::{
+        Context, DealerSocket, Receiver, RecvFlags, SendFlags, Sender, ZmqResult,
+    };
 
     #[test]
     fn with_size_creates_message_with_correct_size() {
@@ -161,6 +240,74 @@ mod message_tests {
         assert_eq!(msg.bytes(), "asdf".as_bytes());
     }
 
+    #[test]
+    fn as_slice_returns_correct_bytes() {
+        let msg: Message = "asdf".into();
+        assert_eq!(msg.as_slice(), b"asdf");
+        assert_eq!(&msg.as_slice()[1..3], b"sd");
+    }
+
+    #[test]
+    fn as_slice_for_empty_message() {
+        let msg = Message::new();
+        assert!(msg.as_slice().is_empty());
+    }
+
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn from_bytes_does_not_copy_payload() {
+        let payload = bytes::Bytes::from(vec![0x42; 1024]);
+
+        let msg = Message::from(payload.clone());
+        assert_eq!(msg.as_slice().as_ptr(), payload.as_ptr());
+        assert_eq!(msg.as_slice(), payload.as_ref());
+    }
+
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn from_shared_buffer_releases_buffer_on_drop() {
+        use alloc::sync::Arc;
+
+        let payload: Arc<[u8]> = Arc::from(vec![0x42; 1024]);
+
+        let msg = Message::from(Arc::clone(&payload));
+        assert_eq!(Arc::strong_count(&payload), 2);
+        assert_eq!(msg.as_slice().as_ptr(), payload.as_ptr());
+
+        drop(msg);
+        assert_eq!(Arc::strong_count(&payload), 1);
+    }
+
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn into_bytes_does_not_copy_payload() {
+        let msg: Message = vec![0x42; 1024].into();
+        let payload_ptr = msg.as_slice().as_ptr();
+
+        let payload = msg.into_bytes();
+        assert_eq!(payload.as_ptr(), payload_ptr);
+        assert_eq!(payload.as_ref(), &[0x42; 1024]);
+    }
+
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn bytes_payload_survives_send_and_receive() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let receiver = DealerSocket::from_context(&context)?;
+        receiver.bind("inproc://message-bytes-payload")?;
+
+        let sender = DealerSocket::from_context(&context)?;
+        sender.connect("inproc://message-bytes-payload")?;
+
+        sender.send_msg(bytes::Bytes::from(vec![0x42; 1024]), SendFlags::empty())?;
+
+        let payload = receiver.recv_msg(RecvFlags::empty())?.into_bytes();
+        assert_eq!(payload.as_ref(), &[0x42; 1024]);
+
+        Ok(())
+    }
+
     #[test]
     fn is_empty_for_empty_message() {
         let msg = Message::new();
@@ -233,6 +380,59 @@ mod message_tests {
 
         Ok(())
     }
+
+    #[test]
+    fn property_defaults_to_none() {
+        let msg = Message::new();
+        assert_eq!(msg.property("Socket-Type"), None);
+        assert_eq!(msg.peer_address(), None);
+        assert_eq!(msg.user_id(), None);
+    }
+
+    /// Binds a server dealer, and creates a client dealer that still needs to connect to it, so
+    /// that client options can be set beforehand.
+    fn bound_dealer_and_client(context: &Context) -> ZmqResult<(DealerSocket, DealerSocket)> {
+        let server = DealerSocket::from_context(context)?;
+        server.bind("tcp://127.0.0.1:*")?;
+
+        let client = DealerSocket::from_context(context)?;
+
+        Ok((server, client))
+    }
+
+    #[test]
+    fn received_message_has_peer_properties() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let (server, client) = bound_dealer_and_client(&context)?;
+        client.connect(server.last_endpoint()?.unwrap())?;
+
+        client.send_msg("asdf", SendFlags::empty())?;
+        let msg = server.recv_msg(RecvFlags::empty())?;
+
+        assert_eq!(msg.peer_socket_type(), Some("DEALER".to_string()));
+        assert_eq!(msg.peer_address(), Some("127.0.0.1".to_string()));
+        assert_eq!(msg.property("X-Unknown"), None);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "draft-api")]
+    #[test]
+    fn received_message_has_application_metadata() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let (server, client) = bound_dealer_and_client(&context)?;
+        client.set_metadata("X-Application", "arzmq")?;
+        client.connect(server.last_endpoint()?.unwrap())?;
+
+        client.send_msg("asdf", SendFlags::empty())?;
+        let msg = server.recv_msg(RecvFlags::empty())?;
+
+        assert_eq!(msg.property("X-Application"), Some("arzmq".to_string()));
+
+        Ok(())
+    }
 }
 
 /// convenicen trait for sendable messages, including single- and multipart ones.
@@ -241,7 +441,7 @@ pub trait Sendable<S: sealed::SocketType + sealed::SenderFlag> {
     fn send(self, socket: &Socket<S>, flags: i32) -> ZmqResult<()>;
 }
 
-#[derive(Default, DebugDeriveMore, DisplayDeriveMore)]
+#[derive(Default, Clone, DebugDeriveMore, DisplayDeriveMore)]
 #[debug("MultipartMessage {{ {inner:?} }}")]
 #[display("MultipartMessage {{ {inner:?} }}")]
 /// 0MQ multipart message
@@ -313,6 +513,32 @@ impl MultipartMessage {
     {
         self.inner.drain(range)
     }
+
+    /// # Split off the envelope
+    ///
+    /// Splits this multipart message at the first empty delimiter part. All parts before the
+    /// delimiter make up the routing id stack of the returned [`Envelope`], and all parts after
+    /// the delimiter the returned body. Without a delimiter, the envelope is empty, and the whole
+    /// multipart message is returned as body. Only messages of [`Request`] peers are guaranteed to
+    /// have no empty parts before the delimiter.
+    ///
+    /// [`Request`]: crate::socket::RequestSocket
+    pub fn split_envelope(mut self) -> (Envelope, MultipartMessage) {
+        let Some(delimiter) = self.inner.iter().position(Message::is_empty) else {
+            return (Envelope::new(), self);
+        };
+
+        let routing_ids = self.inner.drain(..delimiter).map(RoutingId::from).collect();
+        self.inner.pop_front();
+
+        (
+            Envelope {
+                routing_ids,
+                delimited: true,
+            },
+            self,
+        )
+    }
 }
 
 impl From<Message> for MultipartMessage {
@@ -366,6 +592,173 @@ where
     }
 }
 
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+/// # Envelope of a multipart message
+///
+/// The stack of routing ids in front of the body of a multipart message, as added by
+/// [`Router`] sockets, optionally followed by the empty delimiter part that [`Request`] and
+/// [`Reply`] sockets require. The routing ids are ordered as the parts of the message, i.e. the
+/// routing id of the last hop comes first.
+///
+/// [`Router`]: crate::socket::RouterSocket
+/// [`Request`]: crate::socket::RequestSocket
+/// [`Reply`]: crate::socket::ReplySocket
+pub struct Envelope {
+    routing_ids: Vec<RoutingId>,
+    delimited: bool,
+}
+
+impl Envelope {
+    /// creates an empty envelope without delimiter
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// returns the routing id stack, with the routing id of the last hop first
+    pub fn routing_ids(&self) -> &[RoutingId] {
+        &self.routing_ids
+    }
+
+    /// returns the routing id of the last hop, if any
+    pub fn routing_id(&self) -> Option<&RoutingId> {
+        self.routing_ids.first()
+    }
+
+    /// pushes a routing id of a new last hop on the routing id stack
+    pub fn push_routing_id<V>(&mut self, routing_id: V)
+    where
+        V: Into<RoutingId>,
+    {
+        self.routing_ids.insert(0, routing_id.into());
+    }
+
+    /// removes the routing id of the last hop from the routing id stack, and returns it
+    pub fn pop_routing_id(&mut self) -> Option<RoutingId> {
+        (!self.routing_ids.is_empty()).then(|| self.routing_ids.remove(0))
+    }
+
+    /// returns whether the envelope ends with an empty delimiter part
+    pub fn is_delimited(&self) -> bool {
+        self.delimited
+    }
+
+    /// sets whether the envelope ends with an empty delimiter part
+    pub fn set_delimited(&mut self, value: bool) {
+        self.delimited = value;
+    }
+
+    /// returns the number of routing ids in the envelope
+    pub fn len(&self) -> usize {
+        self.routing_ids.len()
+    }
+
+    /// returns whether the envelope has no routing ids
+    pub fn is_empty(&self) -> bool {
+        self.routing_ids.is_empty()
+    }
+
+    /// # Wrap a body into this envelope
+    ///
+    /// Returns a multipart message with the routing id stack, the empty delimiter part if the
+    /// envelope is delimited, and the parts of the body.
+    pub fn wrap<M>(&self, body: M) -> MultipartMessage
+    where
+        M: Into<MultipartMessage>,
+    {
+        let mut multipart = body.into();
+        if self.delimited {
+            multipart.push_front(Message::new());
+        }
+        self.routing_ids
+            .iter()
+            .rev()
+            .for_each(|routing_id| multipart.push_front(Message::from(routing_id)));
+
+        multipart
+    }
+}
+
+impl<V: Into<RoutingId>> From<V> for Envelope {
+    fn from(routing_id: V) -> Self {
+        Self {
+            routing_ids: vec![routing_id.into()],
+            delimited: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod envelope_tests {
+    use rstest::*;
+
+    use super::{Envelope, Message, MultipartMessage, RoutingId};
+
+    fn frames(multipart: &MultipartMessage) -> Vec<Vec<u8>> {
+        multipart.iter().map(Message::bytes).collect()
+    }
+
+    #[rstest]
+    #[case(vec!["hop2".into(), "hop1".into(), vec![].into(), "body".into()], &["hop2", "hop1"], true, 1)]
+    #[case(vec![vec![].into(), "body".into(), "more".into()], &[], true, 2)]
+    #[case(vec!["body".into(), "more".into()], &[], false, 2)]
+    #[case(vec!["hop".into(), vec![].into()], &["hop"], true, 0)]
+    fn split_envelope_splits_at_delimiter(
+        #[case] parts: Vec<Message>,
+        #[case] routing_ids: &[&str],
+        #[case] delimited: bool,
+        #[case] body_len: usize,
+    ) {
+        let (envelope, body) = MultipartMessage::from(parts).split_envelope();
+
+        let expected: Vec<_> = routing_ids.iter().copied().map(RoutingId::from).collect();
+        assert_eq!(envelope.routing_ids(), expected.as_slice());
+        assert_eq!(envelope.is_delimited(), delimited);
+        assert_eq!(body.len(), body_len);
+    }
+
+    #[test]
+    fn wrap_restores_split_message() {
+        let multipart: MultipartMessage =
+            vec!["hop2".into(), "hop1".into(), vec![].into(), "body".into()].into();
+        let expected = frames(&multipart);
+
+        let (envelope, body) = multipart.split_envelope();
+        assert_eq!(frames(&envelope.wrap(body)), expected);
+    }
+
+    #[test]
+    fn wrap_without_delimiter() {
+        let envelope = Envelope::from("peer");
+        assert_eq!(envelope.len(), 1);
+        assert!(!envelope.is_delimited());
+
+        let multipart = envelope.wrap(Message::from("body"));
+        assert_eq!(frames(&multipart), vec![b"peer".to_vec(), b"body".to_vec()]);
+    }
+
+    #[test]
+    fn routing_id_stack() {
+        let mut envelope = Envelope::new();
+        assert!(envelope.is_empty());
+        assert!(envelope.routing_id().is_none());
+
+        envelope.push_routing_id("hop1");
+        envelope.push_routing_id("hop2");
+        envelope.set_delimited(true);
+        assert_eq!(envelope.routing_id(), Some(&RoutingId::from("hop2")));
+
+        let multipart = envelope.wrap(Message::from("body"));
+        assert_eq!(
+            frames(&multipart),
+            vec![b"hop2".to_vec(), b"hop1".to_vec(), vec![], b"body".to_vec()]
+        );
+
+        assert_eq!(envelope.pop_routing_id(), Some(RoutingId::from("hop2")));
+        assert_eq!(envelope.pop_routing_id(), Some(RoutingId::from("hop1")));
+        assert_eq!(envelope.pop_routing_id(), None);
+    }
+}
+
 #[cfg(test)]
 mod multipart_message_tests {
     use super::{Message, MultipartMessage};
@@ -622,3 +1015,281 @@ mod multipart_message_tests {
         );
     }
 }
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "builder", derive(serde::Serialize))]
+/// # Binary routing id of a 0MQ socket
+///
+/// Routing ids identify peers on [`Router`] and [`Stream`] sockets. They are arbitrary binary data
+/// of at most 255 bytes, and prepended to every message received on these sockets. Routing ids
+/// generated by `libzmq` itself start with a zero byte, which is why routing ids provided by the
+/// application must not start with a zero byte.
+///
+/// Routing ids are serialized as a sequence of bytes. For compatibility with configurations
+/// written when routing ids were plain strings, deserializing also accepts a string, which is
+/// taken as its UTF-8 bytes.
+///
+/// [`Router`]: crate::socket::RouterSocket
+/// [`Stream`]: crate::socket::StreamSocket
+pub struct RoutingId(Vec<u8>);
+
+impl RoutingId {
+    /// Maximum length of a routing id in bytes
+    pub const MAX_LEN: usize = u8::MAX as usize;
+
+    /// Creates a routing id from the provided `value`, and validates that it is usable as
+    /// application-provided routing id, i.e. between 1 and 255 bytes long, and not starting with a
+    /// zero byte.
+    pub fn new<V: Into<Vec<u8>>>(value: V) -> ZmqResult<Self> {
+        let routing_id = Self(value.into());
+        if !routing_id.is_valid() {
+            return Err(ZmqError::InvalidArgument);
+        }
+
+        Ok(routing_id)
+    }
+
+    /// Whether this routing id can be assigned to a socket, i.e. is between 1 and 255 bytes long,
+    /// and does not start with a zero byte.
+    pub fn is_valid(&self) -> bool {
+        self.0.first().is_some_and(|first| *first != 0) && self.0.len() <= Self::MAX_LEN
+    }
+
+    /// returns the underlying bytes of the routing id
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// returns the length of the routing id in bytes
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// returns whether the routing id is empty
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// consumes the routing id and returns the underlying bytes
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+impl core::fmt::Display for RoutingId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+impl AsRef<[u8]> for RoutingId {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl From<Vec<u8>> for RoutingId {
+    fn from(value: Vec<u8>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&[u8]> for RoutingId {
+    fn from(value: &[u8]) -> Self {
+        Self(value.to_vec())
+    }
+}
+
+impl<const N: usize> From<&[u8; N]> for RoutingId {
+    fn from(value: &[u8; N]) -> Self {
+        Self(value.to_vec())
+    }
+}
+
+impl From<&str> for RoutingId {
+    fn from(value: &str) -> Self {
+        Self(value.as_bytes().to_vec())
+    }
+}
+
+impl From<String> for RoutingId {
+    fn from(value: String) -> Self {
+        Self(value.into_bytes())
+    }
+}
+
+impl From<&String> for RoutingId {
+    fn from(value: &String) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+impl From<&RoutingId> for RoutingId {
+    fn from(value: &RoutingId) -> Self {
+        value.clone()
+    }
+}
+
+impl From<&Message> for RoutingId {
+    fn from(value: &Message) -> Self {
+        Self(value.bytes())
+    }
+}
+
+impl From<Message> for RoutingId {
+    fn from(value: Message) -> Self {
+        Self::from(&value)
+    }
+}
+
+#[cfg(feature = "builder")]
+impl<'de> serde::Deserialize<'de> for RoutingId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_newtype_struct("RoutingId", RoutingIdVisitor)
+    }
+}
+
+#[cfg(feature = "builder")]
+struct RoutingIdVisitor;
+
+#[cfg(feature = "builder")]
+impl<'de> serde::de::Visitor<'de> for RoutingIdVisitor {
+    type Value = RoutingId;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("a routing id as string or sequence of bytes")
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(self)
+        } else {
+            // binary formats encode strings and byte sequences alike
+            serde::Deserialize::deserialize(deserializer).map(RoutingId)
+        }
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(RoutingId::from(value))
+    }
+
+    fn visit_string<E>(self, value: String) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(RoutingId::from(value))
+    }
+
+    fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(RoutingId::from(value))
+    }
+
+    fn visit_byte_buf<E>(self, value: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(RoutingId::from(value))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(byte) = seq.next_element::<u8>()? {
+            bytes.push(byte);
+        }
+        Ok(RoutingId(bytes))
+    }
+}
+
+impl From<RoutingId> for RawMessage {
+    fn from(value: RoutingId) -> Self {
+        RawMessage::from(value.0)
+    }
+}
+
+impl From<&RoutingId> for RawMessage {
+    fn from(value: &RoutingId) -> Self {
+        RawMessage::from(value.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod routing_id_tests {
+    use rstest::*;
+
+    use super::{Message, RoutingId};
+    use crate::{ZmqError, prelude::ZmqResult};
+
+    #[rstest]
+    #[case(RoutingId::from("asdf"), true)]
+    #[case(RoutingId::from(&[0x01, 0x00, 0x02]), true)]
+    #[case(RoutingId::from(vec![0x42; RoutingId::MAX_LEN]), true)]
+    #[case(RoutingId::from(vec![0x42; RoutingId::MAX_LEN + 1]), false)]
+    #[case(RoutingId::from(&[0x00, 0x6b, 0x8b, 0x45, 0x67]), false)]
+    #[case(RoutingId::default(), false)]
+    fn routing_id_is_valid(#[case] routing_id: RoutingId, #[case] expected: bool) {
+        assert_eq!(routing_id.is_valid(), expected);
+    }
+
+    #[test]
+    fn new_validates_routing_id() -> ZmqResult<()> {
+        assert_eq!(RoutingId::new("asdf")?.as_bytes(), b"asdf");
+        assert!(
+            RoutingId::new(vec![0x00, 0x01]).is_err_and(|err| err == ZmqError::InvalidArgument)
+        );
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(RoutingId::from("asdf"), "61736466")]
+    #[case(RoutingId::from(&[0x00, 0x6b, 0x8b, 0x45, 0x67]), "006b8b4567")]
+    #[case(RoutingId::default(), "")]
+    fn routing_id_displays_as_hex(#[case] routing_id: RoutingId, #[case] expected: &str) {
+        assert_eq!(routing_id.to_string(), expected);
+    }
+
+    #[test]
+    fn routing_id_converts_from_and_into_message() {
+        let routing_id = RoutingId::from(&[0x00, 0x6b, 0x8b, 0x45, 0x67]);
+
+        let message = Message::from(routing_id.clone());
+        assert_eq!(message.bytes(), routing_id.as_bytes());
+
+        assert_eq!(RoutingId::from(message), routing_id);
+    }
+
+    #[cfg(feature = "json")]
+    #[rstest]
+    #[case(r#""asdf""#, RoutingId::from("asdf"))]
+    #[case("[97,115,100,102]", RoutingId::from("asdf"))]
+    #[case("[1,0,2]", RoutingId::from(&[0x01, 0x00, 0x02]))]
+    fn routing_id_deserializes_from_string_and_bytes(
+        #[case] json: &str,
+        #[case] expected: RoutingId,
+    ) {
+        let routing_id: RoutingId = serde_json::from_str(json).unwrap();
+        assert_eq!(routing_id, expected);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn routing_id_serializes_as_bytes() {
+        let json = serde_json::to_string(&RoutingId::from(&[0x01, 0x00, 0x02])).unwrap();
+        assert_eq!(json, "[1,0,2]");
+    }
+}
@@ -0,0 +1,429 @@
+use std::{collections::HashMap, time::Instant};
+
+use super::{SNAPSHOT_END, SNAPSHOT_REQUEST, StateValue, Update, decode_sequence};
+use crate::{
+    ZmqError, ZmqResult,
+    context::Context,
+    endpoint::Endpoint,
+    message::{Message, MultipartMessage},
+    patterns::interval_duration,
+    socket::{
+        DealerSocket, MultipartReceiver, MultipartSender, PollEvents, RecvFlags, SendFlags,
+        SubscribeSocket,
+    },
+};
+
+struct ClientEntry<V> {
+    value: V,
+    expiry: Option<Instant>,
+}
+
+impl<V> ClientEntry<V> {
+    fn is_alive(&self, now: Instant) -> bool {
+        self.expiry.is_none_or(|expiry| expiry > now)
+    }
+}
+
+/// # Clone client
+///
+/// Mirrors the map of a [`Server`]. The client subscribes to the updates of the server, fetches a
+/// snapshot of the map, and then applies the updates received through [`update()`] in sequence
+/// order. When an update is missing, the client fetches a fresh snapshot.
+///
+/// Keys with a time to live are hidden once it passed, even before the removal published by the
+/// server arrives.
+///
+/// [`Server`]: super::Server
+/// [`update()`]: #method.update
+pub struct Client<V> {
+    context: Context,
+    snapshot: Endpoint,
+    updates: SubscribeSocket,
+    map: HashMap<String, ClientEntry<V>>,
+    sequence: u64,
+    timeout: i64,
+}
+
+impl<V: StateValue> Client<V> {
+    /// Default time in milliseconds to wait for a snapshot
+    pub const DEFAULT_TIMEOUT: i64 = 2_500;
+
+    /// Creates a new client, subscribes to the updates published at the provided updates
+    /// endpoint, and fetches a snapshot from the provided snapshot endpoint.
+    pub fn new<S, U>(context: &Context, snapshot: S, updates: U) -> ZmqResult<Self>
+    where
+        S: TryInto<Endpoint>,
+        ZmqError: From<S::Error>,
+        U: TryInto<Endpoint>,
+        ZmqError: From<U::Error>,
+    {
+        let subscriber = SubscribeSocket::from_context(context)?;
+        subscriber.set_linger(0)?;
+        subscriber.subscribe("")?;
+        subscriber.connect(updates)?;
+
+        let mut client = Self {
+            context: context.clone(),
+            snapshot: snapshot.try_into()?,
+            updates: subscriber,
+            map: HashMap::new(),
+            sequence: 0,
+            timeout: Self::DEFAULT_TIMEOUT,
+        };
+        client.resync()?;
+
+        Ok(client)
+    }
+
+    /// # Set the snapshot timeout
+    ///
+    /// Sets the time in milliseconds to wait for a snapshot. A value of `-1` waits indefinitely.
+    pub fn set_timeout(&mut self, value: i64) {
+        self.timeout = value;
+    }
+
+    /// # Retrieve the snapshot timeout
+    ///
+    /// Retrieves the time in milliseconds to wait for a snapshot.
+    pub fn timeout(&self) -> i64 {
+        self.timeout
+    }
+
+    /// The sequence number of the last applied update.
+    pub fn sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    /// # Fetch a fresh snapshot
+    ///
+    /// Replaces the mirrored map with a fresh snapshot of the server. Fails with [`Again`] if the
+    /// snapshot did not arrive within [`timeout()`] milliseconds.
+    ///
+    /// [`Again`]: ZmqError::Again
+    /// [`timeout()`]: #method.timeout
+    pub fn resync(&mut self) -> ZmqResult<()> {
+        let socket = DealerSocket::from_context(&self.context)?;
+        socket.set_linger(0)?;
+        socket.connect(&self.snapshot)?;
+        socket.send_multipart(Message::from(SNAPSHOT_REQUEST), SendFlags::empty())?;
+
+        let deadline =
+            (self.timeout >= 0).then(|| Instant::now() + interval_duration(self.timeout));
+        let mut map = HashMap::new();
+        loop {
+            let timeout = deadline.map_or(-1, |deadline| {
+                deadline
+                    .saturating_duration_since(Instant::now())
+                    .as_millis() as i64
+            });
+            if !socket
+                .poll(PollEvents::POLL_IN, timeout)?
+                .contains(PollEvents::POLL_IN)
+            {
+                return Err(ZmqError::Again);
+            }
+
+            let multipart = socket.recv_multipart(RecvFlags::empty())?;
+            if let Some(sequence) = snapshot_end(&multipart) {
+                self.map = map;
+                self.sequence = sequence;
+                return Ok(());
+            }
+
+            if let Ok(update) = Update::try_from(multipart)
+                && let Ok((key, Some(entry))) = decode_entry(update)
+            {
+                map.insert(key, entry);
+            }
+        }
+    }
+
+    /// # Apply pending updates
+    ///
+    /// Waits at most `timeout` milliseconds for the first update, and applies all pending updates
+    /// in sequence order. Updates already covered by the snapshot are skipped, and a gap in the
+    /// sequence numbers triggers a [`resync()`]. Updates with a value that can't be decoded are
+    /// skipped as well, leaving their key untouched. Returns the number of applied updates.
+    ///
+    /// [`resync()`]: #method.resync
+    pub fn update(&mut self, timeout: i64) -> ZmqResult<usize> {
+        let mut applied = 0;
+        let mut timeout = timeout;
+        while self
+            .updates
+            .poll(PollEvents::POLL_IN, timeout)?
+            .contains(PollEvents::POLL_IN)
+        {
+            timeout = 0;
+
+            let multipart = self.updates.recv_multipart(RecvFlags::empty())?;
+            let Ok(update) = Update::try_from(multipart) else {
+                continue;
+            };
+
+            if update.sequence <= self.sequence {
+                continue;
+            }
+
+            if update.sequence > self.sequence + 1 {
+                self.resync()?;
+                continue;
+            }
+
+            self.sequence = update.sequence;
+            match decode_entry(update) {
+                Ok((key, Some(entry))) => {
+                    self.map.insert(key, entry);
+                }
+                Ok((key, None)) => {
+                    self.map.remove(&key);
+                }
+                Err(_) => continue,
+            }
+            applied += 1;
+        }
+
+        let now = Instant::now();
+        self.map.retain(|_key, entry| entry.is_alive(now));
+
+        Ok(applied)
+    }
+
+    /// The value of the key, if set and not expired.
+    pub fn get(&self, key: &str) -> Option<&V> {
+        let now = Instant::now();
+        self.map
+            .get(key)
+            .filter(|entry| entry.is_alive(now))
+            .map(|entry| &entry.value)
+    }
+
+    /// Whether the key is set and not expired.
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// The number of set keys, including expired keys not yet removed by [`update()`].
+    ///
+    /// [`update()`]: #method.update
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Whether no key is set.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Iterates over all set keys, that are not expired, and their values.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &V)> {
+        let now = Instant::now();
+        self.map
+            .iter()
+            .filter(move |(_key, entry)| entry.is_alive(now))
+            .map(|(key, entry)| (key.as_str(), &entry.value))
+    }
+}
+
+fn snapshot_end(multipart: &MultipartMessage) -> Option<u64> {
+    if multipart.len() != 2
+        || multipart
+            .get(0)
+            .is_none_or(|marker| marker.as_slice() != SNAPSHOT_END.as_bytes())
+    {
+        return None;
+    }
+
+    multipart
+        .get(1)
+        .and_then(|sequence| decode_sequence(sequence).ok())
+}
+
+/// Decodes the entry of an update, which is `None` for removals. Fails if the value can't be
+/// decoded.
+fn decode_entry<V: StateValue>(update: Update) -> ZmqResult<(String, Option<ClientEntry<V>>)> {
+    let expiry = update
+        .ttl
+        .map(|ttl| Instant::now() + interval_duration(ttl));
+    let entry = update
+        .value
+        .map(|value| V::from_message(&value))
+        .transpose()?
+        .map(|value| ClientEntry { value, expiry });
+
+    Ok((update.key, entry))
+}
+
+#[cfg(test)]
+mod client_tests {
+    use core::time::Duration;
+    use std::thread;
+
+    use super::Client;
+    use crate::{
+        ZmqError, ZmqResult,
+        context::Context,
+        message::{Message, MultipartMessage},
+        patterns::clone::{SNAPSHOT_END, SNAPSHOT_REQUEST, Update, encode_sequence},
+        socket::{
+            MultipartReceiver, MultipartSender, PublishSocket, RecvFlags, RouterSocket, SendFlags,
+        },
+    };
+
+    fn update(key: &str, sequence: u64, value: Option<&str>) -> MultipartMessage {
+        Update {
+            key: key.to_string(),
+            sequence,
+            ttl: None,
+            value: value.map(Message::from),
+        }
+        .into_multipart()
+    }
+
+    fn answer_snapshot(
+        snapshot: &RouterSocket,
+        entries: &[(&str, u64, &str)],
+        sequence: u64,
+    ) -> ZmqResult<()> {
+        let request = snapshot.recv_multipart(RecvFlags::empty())?;
+        assert_eq!(
+            request.get(1).unwrap().as_slice(),
+            SNAPSHOT_REQUEST.as_bytes()
+        );
+        let identity = request.get(0).unwrap();
+
+        for (key, entry_sequence, value) in entries {
+            let mut multipart = update(key, *entry_sequence, Some(value));
+            multipart.push_front(identity.clone());
+            snapshot.send_multipart(multipart, SendFlags::empty())?;
+        }
+
+        let end: MultipartMessage = vec![
+            identity.clone(),
+            Message::from(SNAPSHOT_END),
+            encode_sequence(sequence),
+        ]
+        .into();
+        snapshot.send_multipart(end, SendFlags::empty())
+    }
+
+    #[test]
+    fn update_applies_updates_in_order_and_resyncs_on_gaps() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let snapshot = RouterSocket::from_context(&context)?;
+        snapshot.bind("inproc://clone-client-snapshot")?;
+        let publisher = PublishSocket::from_context(&context)?;
+        publisher.bind("inproc://clone-client-updates")?;
+
+        let client_context = context.clone();
+        let client = thread::spawn(move || {
+            Client::<String>::new(
+                &client_context,
+                "inproc://clone-client-snapshot",
+                "inproc://clone-client-updates",
+            )
+        });
+        answer_snapshot(&snapshot, &[("first", 1, "1")], 1)?;
+        let mut client = client.join().unwrap()?;
+        assert_eq!(client.sequence(), 1);
+        assert_eq!(client.timeout(), Client::<String>::DEFAULT_TIMEOUT);
+
+        // give the publisher time to process the subscription of the client
+        thread::sleep(Duration::from_millis(50));
+
+        // already covered by the snapshot
+        publisher.send_multipart(update("first", 1, Some("stale")), SendFlags::empty())?;
+        publisher.send_multipart(update("second", 2, Some("2")), SendFlags::empty())?;
+        publisher.send_multipart(update("first", 3, None), SendFlags::empty())?;
+
+        assert_eq!(client.update(1_000)?, 2);
+        assert_eq!(client.sequence(), 3);
+        assert_eq!(client.get("first"), None);
+        assert_eq!(client.get("second"), Some(&"2".to_string()));
+
+        // sequence number 4 is missing
+        publisher.send_multipart(update("third", 5, Some("3")), SendFlags::empty())?;
+
+        let client = thread::spawn(move || -> ZmqResult<Client<String>> {
+            client.update(1_000)?;
+            Ok(client)
+        });
+        answer_snapshot(
+            &snapshot,
+            &[("second", 2, "2"), ("third", 5, "3"), ("fourth", 4, "4")],
+            5,
+        )?;
+
+        let client = client.join().unwrap()?;
+        assert_eq!(client.sequence(), 5);
+        assert_eq!(client.len(), 3);
+        assert_eq!(client.get("fourth"), Some(&"4".to_string()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn update_skips_undecodable_values() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let snapshot = RouterSocket::from_context(&context)?;
+        snapshot.bind("inproc://clone-client-undecodable-snapshot")?;
+        let publisher = PublishSocket::from_context(&context)?;
+        publisher.bind("inproc://clone-client-undecodable-updates")?;
+
+        let client_context = context.clone();
+        let client = thread::spawn(move || {
+            Client::<String>::new(
+                &client_context,
+                "inproc://clone-client-undecodable-snapshot",
+                "inproc://clone-client-undecodable-updates",
+            )
+        });
+        answer_snapshot(&snapshot, &[("key", 1, "1")], 1)?;
+        let mut client = client.join().unwrap()?;
+
+        // give the publisher time to process the subscription of the client
+        thread::sleep(Duration::from_millis(50));
+
+        let invalid_utf8 = Update {
+            key: "key".to_string(),
+            sequence: 2,
+            ttl: None,
+            value: Some(Message::from(vec![0xff, 0xfe])),
+        }
+        .into_multipart();
+        publisher.send_multipart(invalid_utf8, SendFlags::empty())?;
+
+        assert_eq!(client.update(1_000)?, 0);
+        assert_eq!(client.sequence(), 2);
+        assert_eq!(client.get("key"), Some(&"1".to_string()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn resync_times_out_without_server() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let snapshot = RouterSocket::from_context(&context)?;
+        snapshot.bind("inproc://clone-client-timeout-snapshot")?;
+
+        let client_context = context.clone();
+        let client = thread::spawn(move || {
+            Client::<String>::new(
+                &client_context,
+                "inproc://clone-client-timeout-snapshot",
+                "inproc://clone-client-timeout-updates",
+            )
+        });
+        answer_snapshot(&snapshot, &[], 0)?;
+        let mut client = client.join().unwrap()?;
+
+        client.set_timeout(10);
+        assert_eq!(client.timeout(), 10);
+        assert!(client.resync().is_err_and(|err| err == ZmqError::Again));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,337 @@
+//! # Reliable state replication ("Clone")
+//!
+//! Replication of a key/value map from a single [`Server`] to any number of [`Client`]s, following
+//! the Clone pattern from the 0MQ guide. The server publishes every change of its map as an update
+//! with a sequence number on a [`PublishSocket`], and serves snapshots of the whole map on a
+//! [`RouterSocket`].
+//!
+//! A client subscribes to the updates first, and then fetches a snapshot, so that late joiners do
+//! not miss any state. Updates already covered by the snapshot are skipped, and the remaining ones
+//! are applied in sequence order. When the client detects a gap in the sequence numbers, it fetches
+//! a fresh snapshot.
+//!
+//! Keys can carry a time to live, after which the server removes them, and publishes the removal.
+//!
+//! ```
+//! # use arzmq::{
+//! #     patterns::clone::{Client, Server},
+//! #     prelude::{Context, ZmqResult},
+//! # };
+//! #
+//! fn main() -> ZmqResult<()> {
+//!     let context = Context::new()?;
+//!
+//!     let mut server = Server::<String>::new(&context)?;
+//!     server.bind_snapshot("inproc://clone-snapshot")?;
+//!     server.bind_publisher("inproc://clone-updates")?;
+//!     server.set("greeting", "Hello".to_string())?;
+//!
+//!     let client_context = context.clone();
+//!     let client = std::thread::spawn(move || {
+//!         Client::<String>::new(
+//!             &client_context,
+//!             "inproc://clone-snapshot",
+//!             "inproc://clone-updates",
+//!         )
+//!     });
+//!     server.serve(1_000)?;
+//!
+//!     let mut client = client.join().unwrap()?;
+//!     assert_eq!(client.get("greeting"), Some(&"Hello".to_string()));
+//! #   std::thread::sleep(std::time::Duration::from_millis(50));
+//!
+//!     server.set("greeting", "World".to_string())?;
+//!     client.update(1_000)?;
+//!     assert_eq!(client.get("greeting"), Some(&"World".to_string()));
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! [`PublishSocket`]: crate::socket::PublishSocket
+//! [`RouterSocket`]: crate::socket::RouterSocket
+mod client;
+mod server;
+
+pub use client::Client;
+pub use server::Server;
+
+use crate::{
+    ZmqError, ZmqResult,
+    message::{Message, MultipartMessage},
+};
+
+/// Snapshot request of a client
+const SNAPSHOT_REQUEST: &str = "ICANHAZ?";
+/// Marker for the end of a snapshot
+const SNAPSHOT_END: &str = "KTHXBAI";
+
+/// # Values of a replicated map
+///
+/// Converts the values of a replicated map from and to message frames.
+pub trait StateValue: Sized {
+    /// Encodes the value into a message frame.
+    fn to_message(&self) -> Message;
+
+    /// Decodes the value from a message frame.
+    fn from_message(message: &Message) -> ZmqResult<Self>;
+}
+
+impl StateValue for Vec<u8> {
+    fn to_message(&self) -> Message {
+        Message::from(self.as_slice())
+    }
+
+    fn from_message(message: &Message) -> ZmqResult<Self> {
+        Ok(message.bytes())
+    }
+}
+
+impl StateValue for Message {
+    fn to_message(&self) -> Message {
+        self.clone()
+    }
+
+    fn from_message(message: &Message) -> ZmqResult<Self> {
+        Ok(message.clone())
+    }
+}
+
+impl StateValue for String {
+    fn to_message(&self) -> Message {
+        Message::from(self)
+    }
+
+    fn from_message(message: &Message) -> ZmqResult<Self> {
+        String::from_utf8(message.bytes()).map_err(|_err| ZmqError::InvalidArgument)
+    }
+}
+
+/// Single change of a replicated map, or entry of a snapshot
+///
+/// Encoded as the frames `[key, sequence, ttl, value]`, with the sequence number as 8 bytes in
+/// network byte order, the time to live in milliseconds as 8 bytes in network byte order or an
+/// empty frame, and without a value frame for removals.
+struct Update {
+    key: String,
+    sequence: u64,
+    ttl: Option<i64>,
+    value: Option<Message>,
+}
+
+impl Update {
+    fn into_multipart(self) -> MultipartMessage {
+        let ttl = self
+            .ttl
+            .map_or_else(Message::new, |ttl| Message::from(&ttl.to_be_bytes()[..]));
+
+        let mut multipart: MultipartMessage = vec![
+            Message::from(&self.key),
+            encode_sequence(self.sequence),
+            ttl,
+        ]
+        .into();
+        if let Some(value) = self.value {
+            multipart.push_back(value);
+        }
+
+        multipart
+    }
+}
+
+impl TryFrom<MultipartMessage> for Update {
+    type Error = ZmqError;
+
+    fn try_from(mut value: MultipartMessage) -> Result<Self, Self::Error> {
+        if !(3..=4).contains(&value.len()) {
+            return Err(ZmqError::InvalidArgument);
+        }
+
+        let key = value
+            .pop_front()
+            .map(|key| String::from_message(&key))
+            .ok_or(ZmqError::InvalidArgument)??;
+        let sequence = value
+            .pop_front()
+            .ok_or(ZmqError::InvalidArgument)
+            .and_then(|sequence| decode_sequence(&sequence))?;
+        let ttl = match value.pop_front() {
+            Some(ttl) if ttl.is_empty() => None,
+            Some(ttl) => Some(i64::from_be_bytes(
+                ttl.as_slice()[..]
+                    .try_into()
+                    .map_err(|_err| ZmqError::InvalidArgument)?,
+            )),
+            None => return Err(ZmqError::InvalidArgument),
+        };
+        let value = value.pop_front();
+
+        Ok(Self {
+            key,
+            sequence,
+            ttl,
+            value,
+        })
+    }
+}
+
+fn encode_sequence(sequence: u64) -> Message {
+    Message::from(&sequence.to_be_bytes()[..])
+}
+
+fn decode_sequence(message: &Message) -> ZmqResult<u64> {
+    message.as_slice()[..]
+        .try_into()
+        .map(u64::from_be_bytes)
+        .map_err(|_err| ZmqError::InvalidArgument)
+}
+
+#[cfg(test)]
+mod clone_tests {
+    use core::time::Duration;
+    use std::thread;
+
+    use rstest::*;
+
+    use super::{Client, Server, StateValue, Update};
+    use crate::{
+        ZmqError, ZmqResult,
+        context::Context,
+        message::{Message, MultipartMessage},
+    };
+
+    #[rstest]
+    #[case(Some(1_000), Some("value"))]
+    #[case(None, Some("value"))]
+    #[case(None, None)]
+    fn update_round_trips(#[case] ttl: Option<i64>, #[case] value: Option<&str>) -> ZmqResult<()> {
+        let update = Update {
+            key: "key".to_string(),
+            sequence: 42,
+            ttl,
+            value: value.map(Message::from),
+        };
+
+        let decoded = Update::try_from(update.into_multipart())?;
+        assert_eq!(decoded.key, "key");
+        assert_eq!(decoded.sequence, 42);
+        assert_eq!(decoded.ttl, ttl);
+        assert_eq!(
+            decoded.value.map(|value| value.to_string()).as_deref(),
+            value
+        );
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(vec![Message::from("key"), Message::from("1")])]
+    #[case(vec![Message::from("key"), Message::from("1"), Message::new()])]
+    #[case(vec![Message::from("key"), Message::from(&1_u64.to_be_bytes()[..]), Message::from("1")])]
+    #[case(vec![Message::from(&[0xff][..]), Message::from(&1_u64.to_be_bytes()[..]), Message::new()])]
+    fn update_try_from_invalid_multipart(#[case] frames: Vec<Message>) {
+        let result = Update::try_from(MultipartMessage::from(frames));
+        assert!(result.is_err_and(|err| err == ZmqError::InvalidArgument));
+    }
+
+    #[test]
+    fn values_round_trip() -> ZmqResult<()> {
+        let bytes = vec![0x00, 0xff];
+        assert_eq!(Vec::<u8>::from_message(&bytes.to_message())?, bytes);
+
+        let string = "value".to_string();
+        assert_eq!(String::from_message(&string.to_message())?, string);
+
+        let result = String::from_message(&Message::from(&[0xff][..]));
+        assert!(result.is_err_and(|err| err == ZmqError::InvalidArgument));
+
+        Ok(())
+    }
+
+    fn connect_client(
+        context: &Context,
+        server: &mut Server<String>,
+        name: &str,
+    ) -> ZmqResult<Client<String>> {
+        let client_context = context.clone();
+        let snapshot = format!("inproc://{name}-snapshot");
+        let updates = format!("inproc://{name}-updates");
+        let client = thread::spawn(move || Client::new(&client_context, snapshot, updates));
+
+        server.serve(1_000)?;
+        let client = client.join().unwrap()?;
+
+        // give the publisher time to process the subscription of the client
+        thread::sleep(Duration::from_millis(50));
+
+        Ok(client)
+    }
+
+    #[test]
+    fn client_replicates_server_state() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let mut server = Server::<String>::new(&context)?;
+        server.bind_snapshot("inproc://clone-replicates-snapshot")?;
+        server.bind_publisher("inproc://clone-replicates-updates")?;
+        server.set("first", "1".to_string())?;
+        server.set("second", "2".to_string())?;
+
+        let mut client = connect_client(&context, &mut server, "clone-replicates")?;
+        assert_eq!(client.sequence(), 2);
+        assert_eq!(client.len(), 2);
+        assert_eq!(client.get("first"), Some(&"1".to_string()));
+        assert_eq!(client.get("second"), Some(&"2".to_string()));
+
+        server.set("first", "one".to_string())?;
+        assert_eq!(server.remove("second")?, Some("2".to_string()));
+        server.set("third", "3".to_string())?;
+
+        assert_eq!(client.update(1_000)?, 3);
+        assert_eq!(client.sequence(), server.sequence());
+
+        let mut entries: Vec<_> = client
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.clone()))
+            .collect();
+        entries.sort();
+        assert_eq!(
+            entries,
+            vec![
+                ("first".to_string(), "one".to_string()),
+                ("third".to_string(), "3".to_string())
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn keys_expire_after_ttl() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let mut server = Server::<String>::new(&context)?;
+        server.bind_snapshot("inproc://clone-ttl-snapshot")?;
+        server.bind_publisher("inproc://clone-ttl-updates")?;
+
+        let mut client = connect_client(&context, &mut server, "clone-ttl")?;
+
+        server.set_with_ttl("volatile", "gone soon".to_string(), 10)?;
+        server.set("stable", "here to stay".to_string())?;
+        assert_eq!(client.update(1_000)?, 2);
+        assert!(client.contains_key("volatile"));
+
+        thread::sleep(Duration::from_millis(20));
+        assert!(!client.contains_key("volatile"));
+
+        server.serve(0)?;
+        assert!(!server.contains_key("volatile"));
+        assert!(server.contains_key("stable"));
+
+        assert_eq!(client.update(1_000)?, 1);
+        assert_eq!(client.sequence(), 3);
+        assert_eq!(client.len(), 1);
+
+        Ok(())
+    }
+}
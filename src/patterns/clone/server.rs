@@ -0,0 +1,357 @@
+use std::{collections::HashMap, time::Instant};
+
+use super::{SNAPSHOT_END, SNAPSHOT_REQUEST, StateValue, Update, encode_sequence};
+use crate::{
+    ZmqError, ZmqResult,
+    context::Context,
+    endpoint::Endpoint,
+    message::{Message, MultipartMessage},
+    patterns::interval_duration,
+    socket::{
+        MultipartReceiver, MultipartSender, PollEvents, PublishSocket, RecvFlags, RouterSocket,
+        SendFlags,
+    },
+};
+
+struct ServerEntry<V> {
+    value: V,
+    sequence: u64,
+    expiry: Option<Instant>,
+}
+
+/// # Clone server
+///
+/// Owns the replicated map. Every change is published as a sequenced update to the subscribed
+/// [`Client`]s, and [`serve()`] answers their snapshot requests, and removes expired keys.
+///
+/// [`Client`]: super::Client
+/// [`serve()`]: #method.serve
+pub struct Server<V> {
+    snapshot: RouterSocket,
+    publisher: PublishSocket,
+    map: HashMap<String, ServerEntry<V>>,
+    sequence: u64,
+}
+
+impl<V: StateValue> Server<V> {
+    /// Creates a new server with an empty map, whose sockets still need to be bound.
+    pub fn new(context: &Context) -> ZmqResult<Self> {
+        let snapshot = RouterSocket::from_context(context)?;
+        snapshot.set_linger(0)?;
+
+        let publisher = PublishSocket::from_context(context)?;
+        publisher.set_linger(0)?;
+
+        Ok(Self {
+            snapshot,
+            publisher,
+            map: HashMap::new(),
+            sequence: 0,
+        })
+    }
+
+    /// Binds the snapshot socket, where clients request snapshots from, to the provided endpoint.
+    pub fn bind_snapshot<E>(&self, endpoint: E) -> ZmqResult<()>
+    where
+        E: TryInto<Endpoint>,
+        ZmqError: From<E::Error>,
+    {
+        self.snapshot.bind(endpoint)
+    }
+
+    /// Binds the publisher, where clients subscribe to updates, to the provided endpoint.
+    pub fn bind_publisher<E>(&self, endpoint: E) -> ZmqResult<()>
+    where
+        E: TryInto<Endpoint>,
+        ZmqError: From<E::Error>,
+    {
+        self.publisher.bind(endpoint)
+    }
+
+    /// The last endpoint the snapshot socket was bound to, if any.
+    pub fn snapshot_endpoint(&self) -> ZmqResult<Option<Endpoint>> {
+        self.snapshot.last_endpoint()
+    }
+
+    /// The last endpoint the publisher was bound to, if any.
+    pub fn publisher_endpoint(&self) -> ZmqResult<Option<Endpoint>> {
+        self.publisher.last_endpoint()
+    }
+
+    /// The sequence number of the last published update.
+    pub fn sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    /// # Set a key
+    ///
+    /// Sets the key to the provided value without a time to live, and publishes the update.
+    /// Returns the sequence number of the update.
+    pub fn set<K>(&mut self, key: K, value: V) -> ZmqResult<u64>
+    where
+        K: Into<String>,
+    {
+        self.insert(key.into(), value, None)
+    }
+
+    /// # Set a key with a time to live
+    ///
+    /// Sets the key to the provided value, and publishes the update. The key is removed after
+    /// `ttl` milliseconds. Returns the sequence number of the update.
+    pub fn set_with_ttl<K>(&mut self, key: K, value: V, ttl: i64) -> ZmqResult<u64>
+    where
+        K: Into<String>,
+    {
+        self.insert(key.into(), value, Some(ttl))
+    }
+
+    fn insert(&mut self, key: String, value: V, ttl: Option<i64>) -> ZmqResult<u64> {
+        self.sequence += 1;
+
+        let update = Update {
+            key: key.clone(),
+            sequence: self.sequence,
+            ttl,
+            value: Some(value.to_message()),
+        };
+        self.publisher
+            .send_multipart(update.into_multipart(), SendFlags::empty())?;
+
+        self.map.insert(
+            key,
+            ServerEntry {
+                value,
+                sequence: self.sequence,
+                expiry: ttl.map(|ttl| Instant::now() + interval_duration(ttl)),
+            },
+        );
+
+        Ok(self.sequence)
+    }
+
+    /// # Remove a key
+    ///
+    /// Removes the key, and publishes the removal. Returns the removed value, if the key was set.
+    pub fn remove(&mut self, key: &str) -> ZmqResult<Option<V>> {
+        let Some(entry) = self.map.remove(key) else {
+            return Ok(None);
+        };
+
+        self.publish_removal(key)?;
+
+        Ok(Some(entry.value))
+    }
+
+    fn publish_removal(&mut self, key: &str) -> ZmqResult<()> {
+        self.sequence += 1;
+
+        let update = Update {
+            key: key.to_string(),
+            sequence: self.sequence,
+            ttl: None,
+            value: None,
+        };
+        self.publisher
+            .send_multipart(update.into_multipart(), SendFlags::empty())
+    }
+
+    /// The value of the key, if set.
+    pub fn get(&self, key: &str) -> Option<&V> {
+        self.map.get(key).map(|entry| &entry.value)
+    }
+
+    /// Whether the key is set.
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.map.contains_key(key)
+    }
+
+    /// The number of set keys.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Whether no key is set.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Iterates over all set keys and their values.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &V)> {
+        self.map
+            .iter()
+            .map(|(key, entry)| (key.as_str(), &entry.value))
+    }
+
+    /// # Serve snapshots and expire keys
+    ///
+    /// Waits at most `timeout` milliseconds for snapshot requests of clients, and answers all
+    /// pending requests. Afterwards, all keys whose time to live passed are removed, and their
+    /// removals are published. Has to be called regularly.
+    pub fn serve(&mut self, timeout: i64) -> ZmqResult<()> {
+        let mut timeout = timeout;
+        while self
+            .snapshot
+            .poll(PollEvents::POLL_IN, timeout)?
+            .contains(PollEvents::POLL_IN)
+        {
+            let request = self.snapshot.recv_multipart(RecvFlags::empty())?;
+            self.send_snapshot(request)?;
+            timeout = 0;
+        }
+
+        self.expire()
+    }
+
+    fn send_snapshot(&self, mut request: MultipartMessage) -> ZmqResult<()> {
+        let Some(identity) = request.pop_front() else {
+            return Ok(());
+        };
+        if request.len() != 1
+            || request
+                .get(0)
+                .is_none_or(|command| command.as_slice() != SNAPSHOT_REQUEST.as_bytes())
+        {
+            return Ok(());
+        }
+
+        let now = Instant::now();
+        for (key, entry) in &self.map {
+            let update = Update {
+                key: key.clone(),
+                sequence: entry.sequence,
+                ttl: entry
+                    .expiry
+                    .map(|expiry| expiry.saturating_duration_since(now).as_millis() as i64),
+                value: Some(entry.value.to_message()),
+            };
+
+            let mut multipart = update.into_multipart();
+            multipart.push_front(identity.clone());
+            self.snapshot
+                .send_multipart(multipart, SendFlags::empty())?;
+        }
+
+        let end: MultipartMessage = vec![
+            identity,
+            Message::from(SNAPSHOT_END),
+            encode_sequence(self.sequence),
+        ]
+        .into();
+        self.snapshot.send_multipart(end, SendFlags::empty())
+    }
+
+    fn expire(&mut self) -> ZmqResult<()> {
+        let now = Instant::now();
+        let mut expired: Vec<_> = self
+            .map
+            .iter()
+            .filter(|(_key, entry)| entry.expiry.is_some_and(|expiry| expiry <= now))
+            .map(|(key, entry)| (entry.sequence, key.clone()))
+            .collect();
+        expired.sort();
+
+        expired.into_iter().try_for_each(|(_sequence, key)| {
+            self.map.remove(&key);
+            self.publish_removal(&key)
+        })
+    }
+}
+
+#[cfg(test)]
+mod server_tests {
+    use super::Server;
+    use crate::{
+        ZmqResult,
+        context::Context,
+        message::{Message, MultipartMessage},
+        patterns::clone::{SNAPSHOT_END, SNAPSHOT_REQUEST, Update, decode_sequence},
+        socket::{
+            DealerSocket, MultipartReceiver, MultipartSender, RecvFlags, SendFlags, SubscribeSocket,
+        },
+    };
+
+    #[test]
+    fn map_api_sets_and_removes_keys() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let mut server = Server::<String>::new(&context)?;
+        assert!(server.is_empty());
+
+        assert_eq!(server.set("key", "value".to_string())?, 1);
+        assert_eq!(server.set_with_ttl("other", "value".to_string(), 1_000)?, 2);
+        assert_eq!(server.len(), 2);
+        assert_eq!(server.get("key"), Some(&"value".to_string()));
+
+        assert_eq!(server.remove("key")?, Some("value".to_string()));
+        assert_eq!(server.remove("key")?, None);
+        assert!(!server.contains_key("key"));
+        assert_eq!(server.sequence(), 3);
+        assert_eq!(server.iter().count(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_publishes_updates() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let mut server = Server::<String>::new(&context)?;
+        server.bind_publisher("inproc://clone-server-publish")?;
+
+        let subscriber = SubscribeSocket::from_context(&context)?;
+        subscriber.connect(server.publisher_endpoint()?.unwrap())?;
+        subscriber.subscribe("")?;
+
+        server.set("key", "value".to_string())?;
+        server.remove("key")?;
+
+        let update = Update::try_from(subscriber.recv_multipart(RecvFlags::empty())?)?;
+        assert_eq!(update.key, "key");
+        assert_eq!(update.sequence, 1);
+        assert_eq!(update.value.unwrap().to_string(), "value");
+
+        let removal = Update::try_from(subscriber.recv_multipart(RecvFlags::empty())?)?;
+        assert_eq!(removal.sequence, 2);
+        assert!(removal.value.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn serve_sends_snapshots() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let mut server = Server::<String>::new(&context)?;
+        server.bind_snapshot("inproc://clone-server-snapshot")?;
+        server.set("key", "value".to_string())?;
+        server.set_with_ttl("volatile", "value".to_string(), 60_000)?;
+
+        let client = DealerSocket::from_context(&context)?;
+        client.connect(server.snapshot_endpoint()?.unwrap())?;
+        client.send_multipart(Message::from(SNAPSHOT_REQUEST), SendFlags::empty())?;
+        // ignored invalid request
+        let invalid: MultipartMessage = vec![Message::from("hello"), Message::new()].into();
+        client.send_multipart(invalid, SendFlags::empty())?;
+
+        server.serve(1_000)?;
+
+        let mut entries = vec![];
+        loop {
+            let multipart = client.recv_multipart(RecvFlags::empty())?;
+            if multipart.get(0).unwrap().as_slice() == SNAPSHOT_END.as_bytes() {
+                assert_eq!(decode_sequence(multipart.get(1).unwrap())?, 2);
+                break;
+            }
+            entries.push(Update::try_from(multipart)?);
+        }
+
+        entries.sort_by_key(|entry| entry.sequence);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].key, "key");
+        assert!(entries[0].ttl.is_none());
+        assert_eq!(entries[1].key, "volatile");
+        assert!(entries[1].ttl.is_some_and(|ttl| ttl > 0 && ttl <= 60_000));
+
+        Ok(())
+    }
+}
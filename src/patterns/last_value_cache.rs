@@ -0,0 +1,708 @@
+use alloc::sync::Arc;
+use std::{
+    collections::BTreeMap,
+    sync::atomic::{AtomicU64, Ordering},
+    time::Instant,
+};
+
+use derive_more::Debug as DebugDeriveMore;
+#[cfg(feature = "futures")]
+use futures::future::{self, Either};
+
+use super::interval_duration;
+use crate::{
+    ZmqError, ZmqResult,
+    actor::Actor,
+    context::Context,
+    endpoint::Endpoint,
+    message::{Message, MultipartMessage},
+    poller::Poller,
+    socket::{
+        MultipartReceiver, MultipartSender, PairSocket, PollEvents, Receiver, RecvFlags, SendFlags,
+        SubscriptionEvent, XPublishSocket, XSubscribeSocket,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CacheItem {
+    Frontend,
+    Backend,
+    Control,
+}
+
+struct CachedValue {
+    message: MultipartMessage,
+    updated_at: Instant,
+}
+
+#[derive(Debug, Default)]
+/// # Hit and miss counters of a [`LastValueCache`]
+///
+/// A subscription counts as a hit when there was a cached value to replay for its topic, and as a
+/// miss otherwise.
+pub struct LastValueCacheStats {
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl LastValueCacheStats {
+    /// The number of subscriptions that had a cached value replayed.
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// The number of subscriptions without a cached value.
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+}
+
+/// # Proxy caching the last value of each topic ("Last Value Cache")
+///
+/// A [`LastValueCache`] sits between publishers and subscribers. Its [`XSubscribe`] frontend
+/// receives the messages of the publishers, and forwards them to the subscribers connected to its
+/// [`XPublish`] backend. The last message of every topic, as given by the first frame of the
+/// message, is kept in the cache, and replayed as soon as a subscriber subscribes to exactly that
+/// topic, so that late-joining subscribers don't have to wait for the next update.
+///
+/// The backend handles subscriptions manually. With the `draft-api` feature enabled, cached values
+/// are replayed to the new subscriber only, by turning on [`set_manual_last_value()`] for the
+/// replay alone. Otherwise all subscribers of the topic receive the replayed value once more.
+///
+/// The cache can be bounded to [`capacity()`] topics, evicting the least recently updated topic,
+/// and cached values expire after [`ttl()`] milliseconds.
+///
+/// The cache either [`run()`]s on the current thread until its [`Context`] is terminated, is
+/// [`start()`]ed on a dedicated thread, that runs until the returned [`LastValueCacheHandle`] is
+/// stopped or dropped, or runs as an async task through [`run_async()`].
+///
+/// ```
+/// # use arzmq::{
+/// #     patterns::LastValueCache,
+/// #     prelude::{
+/// #         Context, Message, MultipartReceiver, MultipartSender, PollEvents, PublishSocket,
+/// #         RecvFlags, SendFlags, SubscribeSocket, ZmqResult,
+/// #     },
+/// # };
+/// #
+/// fn main() -> ZmqResult<()> {
+///     let context = Context::new()?;
+///
+///     let publish = PublishSocket::from_context(&context)?;
+///     publish.bind("inproc://last-value-cache-publisher")?;
+///
+///     let cache = LastValueCache::new(&context)?;
+///     cache.connect_frontend("inproc://last-value-cache-publisher")?;
+///     cache.bind_backend("inproc://last-value-cache-subscribers")?;
+///     let cache = cache.start()?;
+///
+///     let subscribe = SubscribeSocket::from_context(&context)?;
+///     subscribe.connect("inproc://last-value-cache-subscribers")?;
+///     subscribe.subscribe("weather")?;
+///
+///     // publish until the subscription went all the way through the cache
+///     while !subscribe
+///         .poll(PollEvents::POLL_IN, 10)?
+///         .contains(PollEvents::POLL_IN)
+///     {
+///         let update: Vec<Message> = vec!["weather".into(), "sunny".into()];
+///         publish.send_multipart(update, SendFlags::empty())?;
+///     }
+///     subscribe.recv_multipart(RecvFlags::empty())?;
+///
+///     let late_subscribe = SubscribeSocket::from_context(&context)?;
+///     late_subscribe.connect("inproc://last-value-cache-subscribers")?;
+///     late_subscribe.subscribe("weather")?;
+///
+///     let update = late_subscribe.recv_multipart(RecvFlags::empty())?;
+///     assert_eq!(update.get(1).unwrap().to_string(), "sunny");
+///     assert_eq!(cache.stats().hits(), 1);
+///
+///     cache.stop()
+/// }
+/// ```
+///
+/// [`XSubscribe`]: XSubscribeSocket
+/// [`XPublish`]: XPublishSocket
+/// [`set_manual_last_value()`]: XPublishSocket::set_manual_last_value
+/// [`capacity()`]: #method.capacity
+/// [`ttl()`]: #method.ttl
+/// [`run()`]: #method.run
+/// [`start()`]: #method.start
+/// [`run_async()`]: #method.run_async
+pub struct LastValueCache {
+    context: Context,
+    frontend: XSubscribeSocket,
+    backend: XPublishSocket,
+    cache: BTreeMap<Vec<u8>, CachedValue>,
+    capacity: Option<usize>,
+    ttl: i64,
+    stats: Arc<LastValueCacheStats>,
+}
+
+impl LastValueCache {
+    /// Default time in milliseconds after which cached values expire, never expiring them
+    pub const DEFAULT_TTL: i64 = -1;
+
+    /// Creates a new cache without a capacity bound, whose frontend and backend still need to be
+    /// connected and bound.
+    pub fn new(context: &Context) -> ZmqResult<Self> {
+        let frontend = XSubscribeSocket::from_context(context)?;
+        frontend.set_linger(0)?;
+
+        let backend = XPublishSocket::from_context(context)?;
+        backend.set_linger(0)?;
+        backend.set_manual(true)?;
+
+        Ok(Self {
+            context: context.clone(),
+            frontend,
+            backend,
+            cache: BTreeMap::new(),
+            capacity: None,
+            ttl: Self::DEFAULT_TTL,
+            stats: Arc::default(),
+        })
+    }
+
+    /// Connects the frontend to a publisher at the provided endpoint.
+    pub fn connect_frontend<E>(&self, endpoint: E) -> ZmqResult<()>
+    where
+        E: TryInto<Endpoint>,
+        ZmqError: From<E::Error>,
+    {
+        self.frontend.connect(endpoint)
+    }
+
+    /// Binds the frontend, where publishers connect to, to the provided endpoint.
+    pub fn bind_frontend<E>(&self, endpoint: E) -> ZmqResult<()>
+    where
+        E: TryInto<Endpoint>,
+        ZmqError: From<E::Error>,
+    {
+        self.frontend.bind(endpoint)
+    }
+
+    /// Binds the backend, where subscribers connect to, to the provided endpoint.
+    pub fn bind_backend<E>(&self, endpoint: E) -> ZmqResult<()>
+    where
+        E: TryInto<Endpoint>,
+        ZmqError: From<E::Error>,
+    {
+        self.backend.bind(endpoint)
+    }
+
+    /// The last endpoint the backend was bound to, if any.
+    pub fn backend_endpoint(&self) -> ZmqResult<Option<Endpoint>> {
+        self.backend.last_endpoint()
+    }
+
+    /// # Set the capacity
+    ///
+    /// Sets the maximum number of topics kept in the cache. When a new topic arrives at a full
+    /// cache, the least recently updated topic is evicted. `None` keeps all topics.
+    pub fn set_capacity(&mut self, value: Option<usize>) {
+        self.capacity = value;
+    }
+
+    /// # Retrieve the capacity
+    ///
+    /// Retrieves the maximum number of topics kept in the cache.
+    pub fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+
+    /// # Set the time to live
+    ///
+    /// Sets the time in milliseconds after which cached values are no longer replayed. A value of
+    /// `-1` keeps cached values until they are replaced by the next update of their topic.
+    pub fn set_ttl(&mut self, value: i64) {
+        self.ttl = value;
+    }
+
+    /// # Retrieve the time to live
+    ///
+    /// Retrieves the time in milliseconds after which cached values are no longer replayed.
+    pub fn ttl(&self) -> i64 {
+        self.ttl
+    }
+
+    /// The hit and miss counters of the cache.
+    pub fn stats(&self) -> Arc<LastValueCacheStats> {
+        Arc::clone(&self.stats)
+    }
+
+    /// Runs the cache on the current thread, until its [`Context`] is terminated.
+    pub fn run(mut self) -> ZmqResult<()> {
+        match self.run_until(None) {
+            Err(ZmqError::ContextTerminated) => Ok(()),
+            result => result,
+        }
+    }
+
+    /// Starts the cache on a dedicated thread.
+    pub fn start(mut self) -> ZmqResult<LastValueCacheHandle> {
+        let context = self.context.clone();
+        let stats = self.stats();
+        let actor = Actor::new(&context, move |pipe| self.run_until(Some(&pipe)))?;
+
+        Ok(LastValueCacheHandle { actor, stats })
+    }
+
+    /// Runs the cache as an async task, until its [`Context`] is terminated.
+    #[cfg(feature = "futures")]
+    pub async fn run_async(mut self) -> ZmqResult<()> {
+        loop {
+            let received = match future::select(
+                self.frontend.recv_multipart_async(),
+                self.backend.recv_msg_async(),
+            )
+            .await
+            {
+                Either::Left((multipart, _)) => multipart.map(Either::Left),
+                Either::Right((msg, _)) => msg.map(Either::Right),
+            };
+
+            // sending on XPublish and XSubscribe sockets never blocks, so the handlers are shared
+            // with the blocking loop
+            let result = match received {
+                Ok(Either::Left(multipart)) => self.handle_frontend(multipart),
+                Ok(Either::Right(msg)) => self.handle_backend(&msg),
+                Err(err) => Err(err),
+            };
+            match result {
+                Err(ZmqError::ContextTerminated) => return Ok(()),
+                Err(err) => return Err(err),
+                Ok(()) => (),
+            }
+        }
+    }
+
+    fn run_until(&mut self, control: Option<&PairSocket>) -> ZmqResult<()> {
+        loop {
+            let mut poller = Poller::new();
+            poller.add_socket(&self.frontend, PollEvents::POLL_IN, CacheItem::Frontend);
+            poller.add_socket(&self.backend, PollEvents::POLL_IN, CacheItem::Backend);
+            if let Some(control) = control {
+                poller.add_socket(control, PollEvents::POLL_IN, CacheItem::Control);
+            }
+
+            for event in poller.wait(-1)? {
+                match event.into_token() {
+                    CacheItem::Control => return Ok(()),
+                    CacheItem::Frontend => {
+                        let multipart = self.frontend.recv_multipart(RecvFlags::empty())?;
+                        self.handle_frontend(multipart)?;
+                    }
+                    CacheItem::Backend => {
+                        let msg = self.backend.recv_msg(RecvFlags::empty())?;
+                        self.handle_backend(&msg)?;
+                    }
+                }
+            }
+        }
+    }
+
+    fn handle_frontend(&mut self, multipart: MultipartMessage) -> ZmqResult<()> {
+        if let Some(topic) = multipart.get(0).map(Message::bytes) {
+            self.store(topic, multipart.clone());
+        }
+
+        self.backend.send_multipart(multipart, SendFlags::empty())
+    }
+
+    fn handle_backend(&mut self, msg: &Message) -> ZmqResult<()> {
+        // anything else than subscription messages from subscribers is dropped
+        let Ok(event) = SubscriptionEvent::try_from(msg) else {
+            return Ok(());
+        };
+
+        match &event {
+            SubscriptionEvent::Subscribe(topic) => {
+                self.backend.subscribe(topic)?;
+                self.replay(topic)?;
+            }
+            SubscriptionEvent::Unsubscribe(topic) => self.backend.unsubscribe(topic)?,
+        }
+
+        self.frontend.send_subscription(event, SendFlags::empty())
+    }
+
+    fn replay(&mut self, topic: &[u8]) -> ZmqResult<()> {
+        match self.lookup(topic) {
+            Some(multipart) => {
+                self.stats.hits.fetch_add(1, Ordering::Relaxed);
+                self.send_to_last_subscriber(multipart)
+            }
+            None => {
+                self.stats.misses.fetch_add(1, Ordering::Relaxed);
+                Ok(())
+            }
+        }
+    }
+
+    #[cfg(feature = "draft-api")]
+    fn send_to_last_subscriber(&self, multipart: MultipartMessage) -> ZmqResult<()> {
+        // while turned on, every send following a (un)subscription only reaches the subscriber
+        // it came from, so it must not stay on for regular updates
+        self.backend.set_manual_last_value(true)?;
+        let result = self.backend.send_multipart(multipart, SendFlags::empty());
+
+        // turning off `ZMQ_XPUB_MANUAL_LAST_VALUE` turns off the manual subscriptions as well
+        self.backend.set_manual_last_value(false)?;
+        self.backend.set_manual(true)?;
+
+        result
+    }
+
+    #[cfg(not(feature = "draft-api"))]
+    fn send_to_last_subscriber(&self, multipart: MultipartMessage) -> ZmqResult<()> {
+        self.backend.send_multipart(multipart, SendFlags::empty())
+    }
+
+    fn is_expired(&self, value: &CachedValue) -> bool {
+        self.ttl >= 0 && value.updated_at.elapsed() >= interval_duration(self.ttl)
+    }
+
+    fn lookup(&mut self, topic: &[u8]) -> Option<MultipartMessage> {
+        let value = self.cache.get(topic)?;
+        if self.is_expired(value) {
+            self.cache.remove(topic);
+            return None;
+        }
+
+        Some(value.message.clone())
+    }
+
+    fn store(&mut self, topic: Vec<u8>, message: MultipartMessage) {
+        if let Some(capacity) = self.capacity
+            && !self.cache.contains_key(&topic)
+        {
+            if capacity == 0 {
+                return;
+            }
+            while self.cache.len() >= capacity {
+                self.evict();
+            }
+        }
+
+        self.cache.insert(
+            topic,
+            CachedValue {
+                message,
+                updated_at: Instant::now(),
+            },
+        );
+    }
+
+    fn evict(&mut self) {
+        let oldest = self
+            .cache
+            .iter()
+            .min_by_key(|(_, value)| value.updated_at)
+            .map(|(topic, _)| topic.clone());
+
+        if let Some(topic) = oldest {
+            self.cache.remove(&topic);
+        }
+    }
+}
+
+#[derive(DebugDeriveMore)]
+#[debug("LastValueCacheHandle {{ ... }}")]
+/// # Handle of a started [`LastValueCache`]
+///
+/// Stops the cache thread when [`stop()`]ped or dropped.
+///
+/// [`stop()`]: LastValueCacheHandle::stop
+pub struct LastValueCacheHandle {
+    actor: Actor,
+    stats: Arc<LastValueCacheStats>,
+}
+
+impl LastValueCacheHandle {
+    /// The hit and miss counters of the cache.
+    pub fn stats(&self) -> Arc<LastValueCacheStats> {
+        Arc::clone(&self.stats)
+    }
+
+    /// Stops the cache, and waits for its thread to finish. If the cache thread panicked, the
+    /// panic is resumed on the calling thread.
+    pub fn stop(self) -> ZmqResult<()> {
+        self.actor.stop()
+    }
+}
+
+#[cfg(test)]
+mod last_value_cache_tests {
+    use std::thread;
+
+    use super::LastValueCache;
+    use crate::{
+        ZmqResult,
+        context::Context,
+        message::{Message, MultipartMessage},
+        socket::{
+            MultipartReceiver, MultipartSender, PollEvents, PublishSocket, RecvFlags, SendFlags,
+            SubscribeSocket,
+        },
+    };
+
+    fn update(topic: &str, value: &str) -> MultipartMessage {
+        vec![Message::from(topic), Message::from(value)].into()
+    }
+
+    fn publish_until_received(
+        publish: &PublishSocket,
+        subscribe: &SubscribeSocket,
+        update: MultipartMessage,
+    ) -> ZmqResult<MultipartMessage> {
+        while !subscribe
+            .poll(PollEvents::POLL_IN, 10)?
+            .contains(PollEvents::POLL_IN)
+        {
+            publish.send_multipart(update.clone(), SendFlags::empty())?;
+        }
+
+        subscribe.recv_multipart(RecvFlags::empty())
+    }
+
+    #[test]
+    fn new_uses_defaults() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let cache = LastValueCache::new(&context)?;
+
+        assert_eq!(cache.capacity(), None);
+        assert_eq!(cache.ttl(), LastValueCache::DEFAULT_TTL);
+        assert_eq!(cache.stats().hits(), 0);
+        assert_eq!(cache.stats().misses(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn setters_set_values() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let mut cache = LastValueCache::new(&context)?;
+        cache.set_capacity(Some(42));
+        cache.set_ttl(100);
+
+        assert_eq!(cache.capacity(), Some(42));
+        assert_eq!(cache.ttl(), 100);
+
+        Ok(())
+    }
+
+    #[test]
+    fn store_evicts_least_recently_updated_topic() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let mut cache = LastValueCache::new(&context)?;
+        cache.set_capacity(Some(2));
+
+        cache.store(b"topic1".to_vec(), update("topic1", "1"));
+        cache.store(b"topic2".to_vec(), update("topic2", "1"));
+        cache.store(b"topic1".to_vec(), update("topic1", "2"));
+        cache.store(b"topic3".to_vec(), update("topic3", "1"));
+
+        assert!(cache.lookup(b"topic2").is_none());
+        assert_eq!(
+            cache.lookup(b"topic1").unwrap().get(1).unwrap().to_string(),
+            "2"
+        );
+        assert!(cache.lookup(b"topic3").is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn store_without_capacity_keeps_nothing() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let mut cache = LastValueCache::new(&context)?;
+        cache.set_capacity(Some(0));
+
+        cache.store(b"topic".to_vec(), update("topic", "1"));
+
+        assert!(cache.lookup(b"topic").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn lookup_skips_expired_values() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let mut cache = LastValueCache::new(&context)?;
+        cache.set_ttl(10);
+
+        cache.store(b"topic".to_vec(), update("topic", "1"));
+        assert!(cache.lookup(b"topic").is_some());
+
+        thread::sleep(core::time::Duration::from_millis(20));
+        assert!(cache.lookup(b"topic").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn cache_replays_last_value_to_late_subscriber() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let publish = PublishSocket::from_context(&context)?;
+        publish.bind("inproc://last-value-cache-replay-publisher")?;
+
+        let cache = LastValueCache::new(&context)?;
+        cache.connect_frontend("inproc://last-value-cache-replay-publisher")?;
+        cache.bind_backend("inproc://last-value-cache-replay-subscribers")?;
+        let cache = cache.start()?;
+
+        let subscribe = SubscribeSocket::from_context(&context)?;
+        subscribe.connect("inproc://last-value-cache-replay-subscribers")?;
+        subscribe.subscribe("topic")?;
+
+        publish_until_received(&publish, &subscribe, update("topic", "1"))?;
+
+        let late_subscribe = SubscribeSocket::from_context(&context)?;
+        late_subscribe.connect("inproc://last-value-cache-replay-subscribers")?;
+        late_subscribe.subscribe("topic")?;
+
+        let replayed = late_subscribe.recv_multipart(RecvFlags::empty())?;
+        assert_eq!(replayed.get(0).unwrap().to_string(), "topic");
+        assert_eq!(replayed.get(1).unwrap().to_string(), "1");
+
+        assert_eq!(cache.stats().hits(), 1);
+        assert_eq!(cache.stats().misses(), 1);
+
+        cache.stop()
+    }
+
+    #[cfg(feature = "draft-api")]
+    #[test]
+    fn cache_forwards_updates_to_all_subscribers_after_miss() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let publish = PublishSocket::from_context(&context)?;
+        publish.bind("inproc://last-value-cache-miss-publisher")?;
+
+        let cache = LastValueCache::new(&context)?;
+        cache.connect_frontend("inproc://last-value-cache-miss-publisher")?;
+        cache.bind_backend("inproc://last-value-cache-miss-subscribers")?;
+        let cache = cache.start()?;
+
+        let subscribe = SubscribeSocket::from_context(&context)?;
+        subscribe.connect("inproc://last-value-cache-miss-subscribers")?;
+        subscribe.subscribe("topic")?;
+        publish_until_received(&publish, &subscribe, update("topic", "1"))?;
+
+        let other_subscribe = SubscribeSocket::from_context(&context)?;
+        other_subscribe.connect("inproc://last-value-cache-miss-subscribers")?;
+        other_subscribe.subscribe("other")?;
+        while cache.stats().misses() < 2 {
+            thread::sleep(core::time::Duration::from_millis(1));
+        }
+
+        // the subscription to `other` was a miss, the next update still reaches everyone
+        publish.send_multipart(update("topic", "2"), SendFlags::empty())?;
+        loop {
+            let received = subscribe.recv_multipart(RecvFlags::empty())?;
+            if received.get(1).unwrap().to_string() == "2" {
+                break;
+            }
+        }
+
+        cache.stop()
+    }
+
+    #[test]
+    fn cache_forwards_updates_to_remaining_subscribers_after_unsubscribe() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let publish = PublishSocket::from_context(&context)?;
+        publish.bind("inproc://last-value-cache-unsubscribe-publisher")?;
+
+        let cache = LastValueCache::new(&context)?;
+        cache.connect_frontend("inproc://last-value-cache-unsubscribe-publisher")?;
+        cache.bind_backend("inproc://last-value-cache-unsubscribe-subscribers")?;
+        let cache = cache.start()?;
+
+        let subscribe = SubscribeSocket::from_context(&context)?;
+        subscribe.connect("inproc://last-value-cache-unsubscribe-subscribers")?;
+        subscribe.subscribe("topic")?;
+        publish_until_received(&publish, &subscribe, update("topic", "1"))?;
+
+        // the late subscriber gets the cached value replayed, and then leaves again
+        let late_subscribe = SubscribeSocket::from_context(&context)?;
+        late_subscribe.connect("inproc://last-value-cache-unsubscribe-subscribers")?;
+        late_subscribe.subscribe("topic")?;
+        late_subscribe.recv_multipart(RecvFlags::empty())?;
+        late_subscribe.unsubscribe("topic")?;
+        thread::sleep(core::time::Duration::from_millis(100));
+
+        // drain the updates sent while waiting for the first subscription
+        while subscribe
+            .poll(PollEvents::POLL_IN, 0)?
+            .contains(PollEvents::POLL_IN)
+        {
+            subscribe.recv_multipart(RecvFlags::empty())?;
+        }
+
+        publish.send_multipart(update("topic", "2"), SendFlags::empty())?;
+        assert!(
+            subscribe
+                .poll(PollEvents::POLL_IN, 1_000)?
+                .contains(PollEvents::POLL_IN)
+        );
+        let received = subscribe.recv_multipart(RecvFlags::empty())?;
+        assert_eq!(received.get(1).unwrap().to_string(), "2");
+
+        cache.stop()
+    }
+
+    #[test]
+    fn run_returns_when_context_terminated() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let cache = LastValueCache::new(&context)?;
+        cache.bind_backend("inproc://last-value-cache-run")?;
+
+        let handle = thread::spawn(move || cache.run());
+        context.shutdown()?;
+
+        handle.join().unwrap()
+    }
+
+    #[cfg(feature = "futures")]
+    #[test]
+    fn run_async_replays_last_value() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let publish = PublishSocket::from_context(&context)?;
+        publish.bind("inproc://last-value-cache-async-publisher")?;
+
+        let cache = LastValueCache::new(&context)?;
+        cache.connect_frontend("inproc://last-value-cache-async-publisher")?;
+        cache.bind_backend("inproc://last-value-cache-async-subscribers")?;
+        let stats = cache.stats();
+        let handle = thread::spawn(move || futures::executor::block_on(cache.run_async()));
+
+        let subscribe = SubscribeSocket::from_context(&context)?;
+        subscribe.connect("inproc://last-value-cache-async-subscribers")?;
+        subscribe.subscribe("topic")?;
+        publish_until_received(&publish, &subscribe, update("topic", "1"))?;
+
+        let late_subscribe = SubscribeSocket::from_context(&context)?;
+        late_subscribe.connect("inproc://last-value-cache-async-subscribers")?;
+        late_subscribe.subscribe("topic")?;
+
+        let replayed = late_subscribe.recv_multipart(RecvFlags::empty())?;
+        assert_eq!(replayed.get(1).unwrap().to_string(), "1");
+        assert_eq!(stats.hits(), 1);
+
+        context.shutdown()?;
+
+        handle.join().unwrap()
+    }
+}
@@ -0,0 +1,468 @@
+use core::time::Duration;
+use std::thread;
+
+#[cfg(feature = "futures")]
+use async_io::Timer;
+#[cfg(feature = "futures")]
+use futures::future::{self, Either};
+
+use crate::{
+    ZmqError, ZmqResult,
+    context::Context,
+    endpoint::Endpoint,
+    message::MultipartMessage,
+    socket::{MultipartReceiver, MultipartSender, PollEvents, RecvFlags, RequestSocket, SendFlags},
+};
+
+/// # Request/reply client with timeouts and retries ("Lazy Pirate")
+///
+/// A [`ReliableRequestClient`] wraps a [`RequestSocket`] connected to a single endpoint. Each
+/// request waits at most [`timeout()`] milliseconds for its reply. When no reply arrives in time,
+/// the socket is closed and replaced with a freshly connected one, so that it never gets stuck in
+/// the strict send/receive alternation of the [`Request`] socket type, and the request is sent
+/// again after a backoff delay.
+///
+/// The backoff delay starts at [`backoff()`] milliseconds and doubles with every retry, up to
+/// [`backoff_max()`] milliseconds. Once all [`retries()`] are used up without a reply, the request
+/// fails with [`RetriesExhausted`].
+///
+/// ```
+/// # use arzmq::{
+/// #     patterns::ReliableRequestClient,
+/// #     prelude::{
+/// #         Context, Message, MultipartReceiver, MultipartSender, ReplySocket, RecvFlags, SendFlags,
+/// #         ZmqResult,
+/// #     },
+/// # };
+/// #
+/// fn main() -> ZmqResult<()> {
+///     let context = Context::new()?;
+///
+///     let server = ReplySocket::from_context(&context)?;
+///     server.bind("inproc://lazy-pirate-example")?;
+///
+///     std::thread::spawn(move || {
+///         let request = server.recv_multipart(RecvFlags::empty()).unwrap();
+///         server.send_multipart(request, SendFlags::empty()).unwrap();
+///     });
+///
+///     let mut client = ReliableRequestClient::new(&context, "inproc://lazy-pirate-example")?;
+///     client.set_timeout(1_000);
+///     client.set_retries(2);
+///
+///     let reply = client.request(Message::from("Hello"))?;
+///     assert_eq!(reply.get(0).unwrap().to_string(), "Hello");
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`Request`]: RequestSocket
+/// [`timeout()`]: #method.timeout
+/// [`backoff()`]: #method.backoff
+/// [`backoff_max()`]: #method.backoff_max
+/// [`retries()`]: #method.retries
+/// [`RetriesExhausted`]: ZmqError::RetriesExhausted
+pub struct ReliableRequestClient {
+    context: Context,
+    endpoint: Endpoint,
+    socket: RequestSocket,
+    timeout: i64,
+    retries: u32,
+    backoff: i64,
+    backoff_max: i64,
+}
+
+impl ReliableRequestClient {
+    /// Default time in milliseconds to wait for a reply
+    pub const DEFAULT_TIMEOUT: i64 = 2_500;
+    /// Default number of retries after the first attempt
+    pub const DEFAULT_RETRIES: u32 = 3;
+    /// Default delay in milliseconds before the first retry
+    pub const DEFAULT_BACKOFF: i64 = 100;
+    /// Default upper bound in milliseconds for the delay between retries
+    pub const DEFAULT_BACKOFF_MAX: i64 = 5_000;
+
+    /// Creates a new client connected to the provided endpoint, using the default timeout,
+    /// retries and backoff.
+    pub fn new<E>(context: &Context, endpoint: E) -> ZmqResult<Self>
+    where
+        E: TryInto<Endpoint>,
+        ZmqError: From<E::Error>,
+    {
+        let endpoint = endpoint.try_into()?;
+        let socket = Self::connect_socket(context, &endpoint)?;
+
+        Ok(Self {
+            context: context.clone(),
+            endpoint,
+            socket,
+            timeout: Self::DEFAULT_TIMEOUT,
+            retries: Self::DEFAULT_RETRIES,
+            backoff: Self::DEFAULT_BACKOFF,
+            backoff_max: Self::DEFAULT_BACKOFF_MAX,
+        })
+    }
+
+    fn connect_socket(context: &Context, endpoint: &Endpoint) -> ZmqResult<RequestSocket> {
+        let socket = RequestSocket::from_context(context)?;
+        socket.set_linger(0)?;
+        socket.connect(endpoint)?;
+
+        Ok(socket)
+    }
+
+    fn reconnect(&mut self) -> ZmqResult<()> {
+        self.socket = Self::connect_socket(&self.context, &self.endpoint)?;
+
+        Ok(())
+    }
+
+    fn backoff_delay(&self, retry: u32) -> Duration {
+        let delay = self
+            .backoff
+            .saturating_mul(2_i64.saturating_pow(retry.saturating_sub(1)))
+            .min(self.backoff_max);
+
+        Duration::from_millis(u64::try_from(delay).unwrap_or_default())
+    }
+
+    /// The endpoint the client is connected to.
+    pub fn endpoint(&self) -> &Endpoint {
+        &self.endpoint
+    }
+
+    /// # Set the reply timeout
+    ///
+    /// Sets the time in milliseconds to wait for the reply of each attempt of a request. A value
+    /// of `-1` waits indefinitely, effectively disabling retries.
+    pub fn set_timeout(&mut self, value: i64) {
+        self.timeout = value;
+    }
+
+    /// # Retrieve the reply timeout
+    ///
+    /// Retrieves the time in milliseconds to wait for the reply of each attempt of a request.
+    pub fn timeout(&self) -> i64 {
+        self.timeout
+    }
+
+    /// # Set the number of retries
+    ///
+    /// Sets how many times a request is sent again after the first attempt timed out.
+    pub fn set_retries(&mut self, value: u32) {
+        self.retries = value;
+    }
+
+    /// # Retrieve the number of retries
+    ///
+    /// Retrieves how many times a request is sent again after the first attempt timed out.
+    pub fn retries(&self) -> u32 {
+        self.retries
+    }
+
+    /// # Set the initial backoff delay
+    ///
+    /// Sets the delay in milliseconds before the first retry of a request. The delay doubles with
+    /// each further retry, up to [`backoff_max()`].
+    ///
+    /// [`backoff_max()`]: #method.backoff_max
+    pub fn set_backoff(&mut self, value: i64) {
+        self.backoff = value;
+    }
+
+    /// # Retrieve the initial backoff delay
+    ///
+    /// Retrieves the delay in milliseconds before the first retry of a request.
+    pub fn backoff(&self) -> i64 {
+        self.backoff
+    }
+
+    /// # Set the maximum backoff delay
+    ///
+    /// Sets the upper bound in milliseconds for the delay between two retries of a request.
+    pub fn set_backoff_max(&mut self, value: i64) {
+        self.backoff_max = value;
+    }
+
+    /// # Retrieve the maximum backoff delay
+    ///
+    /// Retrieves the upper bound in milliseconds for the delay between two retries of a request.
+    pub fn backoff_max(&self) -> i64 {
+        self.backoff_max
+    }
+
+    /// # Send a request and wait for its reply
+    ///
+    /// Sends the request and blocks the current thread until the reply arrives. Attempts that
+    /// time out are retried on a new socket until the reply arrives, or the retries are exhausted
+    /// and [`RetriesExhausted`] is returned.
+    ///
+    /// [`RetriesExhausted`]: ZmqError::RetriesExhausted
+    pub fn request<M>(&mut self, request: M) -> ZmqResult<MultipartMessage>
+    where
+        M: Into<MultipartMessage>,
+    {
+        let request = request.into();
+        let attempts = self.retries.saturating_add(1);
+
+        for attempt in 1..=attempts {
+            self.socket
+                .send_multipart(request.clone(), SendFlags::empty())?;
+
+            if self
+                .socket
+                .poll(PollEvents::POLL_IN, self.timeout)?
+                .contains(PollEvents::POLL_IN)
+            {
+                return self.socket.recv_multipart(RecvFlags::empty());
+            }
+
+            self.reconnect()?;
+            if attempt < attempts {
+                thread::sleep(self.backoff_delay(attempt));
+            }
+        }
+
+        Err(ZmqError::RetriesExhausted(attempts))
+    }
+
+    /// # Send a request and wait for its reply asynchronously
+    ///
+    /// Sends the request without blocking the current thread. The returned future resolves with
+    /// the reply, or with [`RetriesExhausted`] once all attempts timed out. Attempts that time out
+    /// are retried on a new socket.
+    ///
+    /// [`RetriesExhausted`]: ZmqError::RetriesExhausted
+    #[cfg(feature = "futures")]
+    pub async fn request_async<M>(&mut self, request: M) -> ZmqResult<MultipartMessage>
+    where
+        M: Into<MultipartMessage>,
+    {
+        let request = request.into();
+        let attempts = self.retries.saturating_add(1);
+
+        for attempt in 1..=attempts {
+            self.socket
+                .send_multipart_async(request.clone(), SendFlags::empty())
+                .await?;
+
+            let timer = match u64::try_from(self.timeout) {
+                Ok(timeout) => Timer::after(Duration::from_millis(timeout)),
+                Err(_) => Timer::never(),
+            };
+            match future::select(self.socket.recv_multipart_async(), timer).await {
+                Either::Left((reply, _)) => return reply,
+                Either::Right(_) => (),
+            }
+
+            self.reconnect()?;
+            if attempt < attempts {
+                Timer::after(self.backoff_delay(attempt)).await;
+            }
+        }
+
+        Err(ZmqError::RetriesExhausted(attempts))
+    }
+}
+
+#[cfg(test)]
+mod reliable_request_client_tests {
+    use std::thread;
+
+    use super::ReliableRequestClient;
+    use crate::{
+        ZmqError, ZmqResult,
+        context::Context,
+        endpoint::Endpoint,
+        message::Message,
+        socket::{
+            MultipartReceiver, MultipartSender, RecvFlags, ReplySocket, RouterSocket, SendFlags,
+        },
+    };
+
+    #[test]
+    fn new_uses_defaults() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let client = ReliableRequestClient::new(&context, "inproc://lazy-pirate-defaults")?;
+
+        assert_eq!(
+            client.endpoint(),
+            &Endpoint::Inproc {
+                name: "lazy-pirate-defaults".to_string()
+            }
+        );
+        assert_eq!(client.timeout(), ReliableRequestClient::DEFAULT_TIMEOUT);
+        assert_eq!(client.retries(), ReliableRequestClient::DEFAULT_RETRIES);
+        assert_eq!(client.backoff(), ReliableRequestClient::DEFAULT_BACKOFF);
+        assert_eq!(
+            client.backoff_max(),
+            ReliableRequestClient::DEFAULT_BACKOFF_MAX
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn new_with_invalid_endpoint() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let result = ReliableRequestClient::new(&context, "lazy-pirate");
+        assert!(result.is_err_and(|err| err == ZmqError::InvalidArgument));
+
+        Ok(())
+    }
+
+    #[test]
+    fn setters_set_values() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let mut client = ReliableRequestClient::new(&context, "inproc://lazy-pirate-setters")?;
+        client.set_timeout(42);
+        client.set_retries(7);
+        client.set_backoff(10);
+        client.set_backoff_max(30);
+
+        assert_eq!(client.timeout(), 42);
+        assert_eq!(client.retries(), 7);
+        assert_eq!(client.backoff(), 10);
+        assert_eq!(client.backoff_max(), 30);
+
+        Ok(())
+    }
+
+    #[test]
+    fn backoff_delay_doubles_up_to_max() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let mut client = ReliableRequestClient::new(&context, "inproc://lazy-pirate-backoff")?;
+        client.set_backoff(10);
+        client.set_backoff_max(30);
+
+        assert_eq!(client.backoff_delay(1).as_millis(), 10);
+        assert_eq!(client.backoff_delay(2).as_millis(), 20);
+        assert_eq!(client.backoff_delay(3).as_millis(), 30);
+        assert_eq!(client.backoff_delay(u32::MAX).as_millis(), 30);
+
+        Ok(())
+    }
+
+    #[test]
+    fn request_receives_reply() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let server = ReplySocket::from_context(&context)?;
+        server.bind("tcp://127.0.0.1:*")?;
+        let server_endpoint = server.last_endpoint()?.unwrap();
+
+        thread::spawn(move || {
+            let request = server.recv_multipart(RecvFlags::empty()).unwrap();
+            assert_eq!(request.get(0).unwrap().to_string(), "Hello");
+            server
+                .send_multipart(Message::from("World"), SendFlags::empty())
+                .unwrap();
+        });
+
+        let mut client = ReliableRequestClient::new(&context, server_endpoint)?;
+        let reply = client.request(Message::from("Hello"))?;
+
+        assert_eq!(reply.len(), 1);
+        assert_eq!(reply.get(0).unwrap().to_string(), "World");
+
+        Ok(())
+    }
+
+    #[test]
+    fn request_retries_after_timeout() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let server = RouterSocket::from_context(&context)?;
+        server.bind("inproc://lazy-pirate-retry")?;
+
+        thread::spawn(move || {
+            let _dropped = server.recv_multipart(RecvFlags::empty()).unwrap();
+
+            let mut request = server.recv_multipart(RecvFlags::empty()).unwrap();
+            assert_eq!(request.pop_back().unwrap().to_string(), "Hello");
+            request.push_back("World".into());
+            server.send_multipart(request, SendFlags::empty()).unwrap();
+        });
+
+        let mut client = ReliableRequestClient::new(&context, "inproc://lazy-pirate-retry")?;
+        client.set_timeout(100);
+        client.set_retries(3);
+        client.set_backoff(10);
+
+        let reply = client.request(Message::from("Hello"))?;
+        assert_eq!(reply.get(0).unwrap().to_string(), "World");
+
+        Ok(())
+    }
+
+    #[test]
+    fn request_fails_when_retries_exhausted() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let mut client = ReliableRequestClient::new(&context, "inproc://lazy-pirate-nobody")?;
+        client.set_timeout(20);
+        client.set_retries(2);
+        client.set_backoff(5);
+
+        let result = client.request(Message::from("Hello"));
+        assert!(result.is_err_and(|err| err == ZmqError::RetriesExhausted(3)));
+
+        let result = client.request(Message::from("Hello again"));
+        assert!(result.is_err_and(|err| err == ZmqError::RetriesExhausted(3)));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "futures")]
+    #[test]
+    fn request_async_retries_after_timeout() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let server = RouterSocket::from_context(&context)?;
+        server.bind("inproc://lazy-pirate-retry-async")?;
+
+        thread::spawn(move || {
+            let _dropped = server.recv_multipart(RecvFlags::empty()).unwrap();
+
+            let mut request = server.recv_multipart(RecvFlags::empty()).unwrap();
+            assert_eq!(request.pop_back().unwrap().to_string(), "Hello");
+            request.push_back("World".into());
+            server.send_multipart(request, SendFlags::empty()).unwrap();
+        });
+
+        let mut client = ReliableRequestClient::new(&context, "inproc://lazy-pirate-retry-async")?;
+        client.set_timeout(100);
+        client.set_retries(3);
+        client.set_backoff(10);
+
+        futures::executor::block_on(async {
+            let reply = client.request_async(Message::from("Hello")).await?;
+            assert_eq!(reply.get(0).unwrap().to_string(), "World");
+
+            Ok(())
+        })
+    }
+
+    #[cfg(feature = "futures")]
+    #[test]
+    fn request_async_fails_when_retries_exhausted() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let mut client = ReliableRequestClient::new(&context, "inproc://lazy-pirate-nobody-async")?;
+        client.set_timeout(20);
+        client.set_retries(1);
+        client.set_backoff(5);
+
+        futures::executor::block_on(async {
+            let result = client.request_async(Message::from("Hello")).await;
+            assert!(result.is_err_and(|err| err == ZmqError::RetriesExhausted(2)));
+
+            Ok(())
+        })
+    }
+}
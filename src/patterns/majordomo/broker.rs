@@ -0,0 +1,609 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    time::Instant,
+};
+
+use derive_more::Debug as DebugDeriveMore;
+
+use super::{
+    CLIENT_HEADER, ClientCommand, DEFAULT_HEARTBEAT_INTERVAL, DEFAULT_HEARTBEAT_LIVENESS,
+    MMI_PREFIX, WORKER_HEADER, WorkerCommand, interval_duration,
+};
+use crate::{
+    ZmqError, ZmqResult,
+    actor::Actor,
+    context::Context,
+    endpoint::Endpoint,
+    message::{Message, MultipartMessage, RoutingId},
+    poller::Poller,
+    socket::{
+        MultipartReceiver, MultipartSender, PairSocket, PollEvents, RecvFlags, RouterSocket,
+        SendFlags,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BrokerItem {
+    Socket,
+    Control,
+}
+
+struct BrokerWorker {
+    service: String,
+    /// `None` for workers that never expire
+    expiry: Option<Instant>,
+}
+
+#[derive(Default)]
+struct BrokerService {
+    requests: VecDeque<(RoutingId, MultipartMessage)>,
+    waiting: VecDeque<RoutingId>,
+}
+
+/// # Majordomo broker
+///
+/// Routes the requests of [`Client`]s to the [`Worker`]s of the requested service. Requests for a
+/// service without any waiting worker are queued until a worker becomes available.
+///
+/// Waiting workers receive a heartbeat every [`heartbeat_interval()`] milliseconds, and are
+/// forgotten once they did not send anything for [`heartbeat_liveness()`] intervals.
+///
+/// The broker either [`run()`]s on the current thread until its [`Context`] is terminated, or is
+/// [`start()`]ed on a dedicated thread, that runs until the returned [`BrokerHandle`] is stopped
+/// or dropped.
+///
+/// [`Client`]: super::Client
+/// [`Worker`]: super::Worker
+/// [`heartbeat_interval()`]: #method.heartbeat_interval
+/// [`heartbeat_liveness()`]: #method.heartbeat_liveness
+/// [`run()`]: #method.run
+/// [`start()`]: #method.start
+pub struct Broker {
+    context: Context,
+    socket: RouterSocket,
+    services: HashMap<String, BrokerService>,
+    workers: HashMap<RoutingId, BrokerWorker>,
+    heartbeat_interval: i64,
+    heartbeat_liveness: u32,
+}
+
+impl Broker {
+    /// Creates a new broker, which still needs to be bound to at least one endpoint.
+    pub fn new(context: &Context) -> ZmqResult<Self> {
+        let socket = RouterSocket::from_context(context)?;
+        socket.set_linger(0)?;
+
+        Ok(Self {
+            context: context.clone(),
+            socket,
+            services: HashMap::new(),
+            workers: HashMap::new(),
+            heartbeat_interval: DEFAULT_HEARTBEAT_INTERVAL,
+            heartbeat_liveness: DEFAULT_HEARTBEAT_LIVENESS,
+        })
+    }
+
+    /// Binds the broker to the provided endpoint, where both clients and workers connect to.
+    pub fn bind<E>(&self, endpoint: E) -> ZmqResult<()>
+    where
+        E: TryInto<Endpoint>,
+        ZmqError: From<E::Error>,
+    {
+        self.socket.bind(endpoint)
+    }
+
+    /// The last endpoint the broker was bound to, if any.
+    pub fn last_endpoint(&self) -> ZmqResult<Option<Endpoint>> {
+        self.socket.last_endpoint()
+    }
+
+    /// # Set the heartbeat interval
+    ///
+    /// Sets the interval in milliseconds between two heartbeats sent to waiting workers. Intervals
+    /// that are not positive are rejected with [`InvalidArgument`].
+    ///
+    /// [`InvalidArgument`]: ZmqError::InvalidArgument
+    pub fn set_heartbeat_interval(&mut self, value: i64) -> ZmqResult<()> {
+        if value <= 0 {
+            return Err(ZmqError::InvalidArgument);
+        }
+        self.heartbeat_interval = value;
+        Ok(())
+    }
+
+    /// # Retrieve the heartbeat interval
+    ///
+    /// Retrieves the interval in milliseconds between two heartbeats sent to waiting workers.
+    pub fn heartbeat_interval(&self) -> i64 {
+        self.heartbeat_interval
+    }
+
+    /// # Set the heartbeat liveness
+    ///
+    /// Sets the number of heartbeat intervals without any message from a worker, after which the
+    /// worker is considered dead.
+    pub fn set_heartbeat_liveness(&mut self, value: u32) {
+        self.heartbeat_liveness = value;
+    }
+
+    /// # Retrieve the heartbeat liveness
+    ///
+    /// Retrieves the number of heartbeat intervals without any message from a worker, after which
+    /// the worker is considered dead.
+    pub fn heartbeat_liveness(&self) -> u32 {
+        self.heartbeat_liveness
+    }
+
+    /// Runs the broker on the current thread, until its [`Context`] is terminated.
+    pub fn run(mut self) -> ZmqResult<()> {
+        match self.run_until(None) {
+            Err(ZmqError::ContextTerminated) => Ok(()),
+            result => result,
+        }
+    }
+
+    /// Starts the broker on a dedicated thread.
+    pub fn start(mut self) -> ZmqResult<BrokerHandle> {
+        let context = self.context.clone();
+        let actor = Actor::new(&context, move |pipe| self.run_until(Some(&pipe)))?;
+
+        Ok(BrokerHandle { actor })
+    }
+
+    fn run_until(&mut self, control: Option<&PairSocket>) -> ZmqResult<()> {
+        let mut poller = Poller::new();
+        poller.add_socket(&self.socket, PollEvents::POLL_IN, BrokerItem::Socket);
+        if let Some(control) = control {
+            poller.add_socket(control, PollEvents::POLL_IN, BrokerItem::Control);
+        }
+
+        let heartbeat_interval = interval_duration(self.heartbeat_interval);
+        let mut heartbeat_at = Instant::now() + heartbeat_interval;
+        loop {
+            let timeout = heartbeat_at.saturating_duration_since(Instant::now());
+            for event in poller.wait(timeout.as_millis() as i64)? {
+                match event.into_token() {
+                    BrokerItem::Control => return Ok(()),
+                    BrokerItem::Socket => {
+                        let multipart = self.socket.recv_multipart(RecvFlags::empty())?;
+                        self.handle_message(multipart)?;
+                    }
+                }
+            }
+
+            if Instant::now() >= heartbeat_at {
+                self.purge_workers();
+                self.send_heartbeats()?;
+                heartbeat_at = Instant::now() + heartbeat_interval;
+            }
+        }
+    }
+
+    fn worker_expiry(&self) -> Option<Instant> {
+        // workers with an expiry too far in the future to be represented never expire
+        interval_duration(self.heartbeat_interval)
+            .checked_mul(self.heartbeat_liveness)
+            .and_then(|liveness| Instant::now().checked_add(liveness))
+    }
+
+    fn handle_message(&mut self, mut multipart: MultipartMessage) -> ZmqResult<()> {
+        let Some(sender) = multipart.pop_front().map(RoutingId::from) else {
+            return Ok(());
+        };
+
+        match multipart.pop_front() {
+            Some(header) if header.as_slice() == CLIENT_HEADER.as_bytes() => {
+                self.handle_client(sender, multipart)
+            }
+            Some(header) if header.as_slice() == WORKER_HEADER.as_bytes() => {
+                self.handle_worker(sender, multipart)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn handle_client(
+        &mut self,
+        client: RoutingId,
+        mut multipart: MultipartMessage,
+    ) -> ZmqResult<()> {
+        let (Some(command), Some(service)) = (multipart.pop_front(), multipart.pop_front()) else {
+            return Ok(());
+        };
+        if ClientCommand::try_from(&command) != Ok(ClientCommand::Request) {
+            return Ok(());
+        }
+
+        let service = service.to_string();
+        if service.starts_with(MMI_PREFIX) {
+            return self.handle_mmi(client, &service, multipart);
+        }
+
+        self.services
+            .entry(service.clone())
+            .or_default()
+            .requests
+            .push_back((client, multipart));
+
+        self.dispatch(&service)
+    }
+
+    fn handle_mmi(
+        &self,
+        client: RoutingId,
+        service: &str,
+        body: MultipartMessage,
+    ) -> ZmqResult<()> {
+        let status = match service {
+            "mmi.service" => {
+                let requested = body.get(0).map(Message::to_string).unwrap_or_default();
+                if self
+                    .workers
+                    .values()
+                    .any(|worker| worker.service == requested)
+                {
+                    "200"
+                } else {
+                    "404"
+                }
+            }
+            _ => "501",
+        };
+
+        self.send_to_client(
+            client,
+            ClientCommand::Final,
+            service,
+            Message::from(status).into(),
+        )
+    }
+
+    fn handle_worker(
+        &mut self,
+        identity: RoutingId,
+        mut multipart: MultipartMessage,
+    ) -> ZmqResult<()> {
+        let Some(Ok(command)) = multipart.pop_front().as_ref().map(WorkerCommand::try_from) else {
+            return self.delete_worker(&identity, true);
+        };
+
+        let expiry = self.worker_expiry();
+        let service = self.workers.get_mut(&identity).map(|worker| {
+            worker.expiry = expiry;
+            worker.service.clone()
+        });
+
+        match (command, service) {
+            (WorkerCommand::Ready, None) => {
+                let service = multipart
+                    .pop_front()
+                    .map(|service| service.to_string())
+                    .unwrap_or_default();
+                if service.is_empty() || service.starts_with(MMI_PREFIX) {
+                    return self.delete_worker(&identity, true);
+                }
+
+                self.workers.insert(
+                    identity.clone(),
+                    BrokerWorker {
+                        service: service.clone(),
+                        expiry,
+                    },
+                );
+                self.worker_waiting(identity, &service)
+            }
+            (command @ (WorkerCommand::Partial | WorkerCommand::Final), Some(service)) => {
+                let Some(client) = multipart.pop_front().map(RoutingId::from) else {
+                    return self.delete_worker(&identity, true);
+                };
+                // envelope delimiter
+                multipart.pop_front();
+
+                if command == WorkerCommand::Partial {
+                    return self.send_to_client(
+                        client,
+                        ClientCommand::Partial,
+                        &service,
+                        multipart,
+                    );
+                }
+
+                self.send_to_client(client, ClientCommand::Final, &service, multipart)?;
+                self.worker_waiting(identity, &service)
+            }
+            (WorkerCommand::Heartbeat, Some(_)) => Ok(()),
+            (WorkerCommand::Disconnect, _) => self.delete_worker(&identity, false),
+            _ => self.delete_worker(&identity, true),
+        }
+    }
+
+    fn worker_waiting(&mut self, identity: RoutingId, service: &str) -> ZmqResult<()> {
+        let waiting = &mut self
+            .services
+            .entry(service.to_string())
+            .or_default()
+            .waiting;
+        if !waiting.contains(&identity) {
+            waiting.push_back(identity);
+        }
+
+        self.dispatch(service)
+    }
+
+    fn delete_worker(&mut self, identity: &RoutingId, disconnect: bool) -> ZmqResult<()> {
+        if let Some(worker) = self.workers.remove(identity)
+            && let Some(service) = self.services.get_mut(&worker.service)
+        {
+            service.waiting.retain(|waiting| waiting != identity);
+        }
+
+        if disconnect {
+            send_to_worker(
+                &self.socket,
+                identity,
+                WorkerCommand::Disconnect,
+                MultipartMessage::new(),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn dispatch(&mut self, service: &str) -> ZmqResult<()> {
+        let Self {
+            socket, services, ..
+        } = self;
+        let Some(service) = services.get_mut(service) else {
+            return Ok(());
+        };
+
+        while !service.waiting.is_empty()
+            && let Some((client, mut body)) = service.requests.pop_front()
+            && let Some(worker) = service.waiting.pop_front()
+        {
+            body.push_front(Message::new());
+            body.push_front(Message::from(client));
+            send_to_worker(socket, &worker, WorkerCommand::Request, body)?;
+        }
+
+        Ok(())
+    }
+
+    fn purge_workers(&mut self) {
+        let now = Instant::now();
+        let Self {
+            services, workers, ..
+        } = self;
+
+        services.values_mut().for_each(|service| {
+            service.waiting.retain(|identity| {
+                let alive = workers
+                    .get(identity)
+                    .is_some_and(|worker| worker.expiry.is_none_or(|expiry| expiry > now));
+                if !alive {
+                    workers.remove(identity);
+                }
+                alive
+            })
+        });
+    }
+
+    fn send_heartbeats(&self) -> ZmqResult<()> {
+        self.services
+            .values()
+            .flat_map(|service| service.waiting.iter())
+            .try_for_each(|identity| {
+                send_to_worker(
+                    &self.socket,
+                    identity,
+                    WorkerCommand::Heartbeat,
+                    MultipartMessage::new(),
+                )
+            })
+    }
+
+    fn send_to_client(
+        &self,
+        client: RoutingId,
+        command: ClientCommand,
+        service: &str,
+        mut body: MultipartMessage,
+    ) -> ZmqResult<()> {
+        body.push_front(Message::from(service));
+        body.push_front(Message::from(command));
+        body.push_front(Message::from(CLIENT_HEADER));
+        body.push_front(Message::from(client));
+
+        self.socket.send_multipart(body, SendFlags::empty())
+    }
+}
+
+fn send_to_worker(
+    socket: &RouterSocket,
+    worker: &RoutingId,
+    command: WorkerCommand,
+    mut body: MultipartMessage,
+) -> ZmqResult<()> {
+    body.push_front(Message::from(command));
+    body.push_front(Message::from(WORKER_HEADER));
+    body.push_front(Message::from(worker));
+
+    socket.send_multipart(body, SendFlags::empty())
+}
+
+#[derive(DebugDeriveMore)]
+#[debug("BrokerHandle {{ ... }}")]
+/// # Handle of a started [`Broker`]
+///
+/// Stops the broker thread when [`stop()`]ped or dropped.
+///
+/// [`stop()`]: BrokerHandle::stop
+pub struct BrokerHandle {
+    actor: Actor,
+}
+
+impl BrokerHandle {
+    /// Stops the broker, and waits for its thread to finish. If the broker thread panicked, the
+    /// panic is resumed on the calling thread.
+    pub fn stop(self) -> ZmqResult<()> {
+        self.actor.stop()
+    }
+}
+
+#[cfg(test)]
+mod broker_tests {
+    use super::Broker;
+    use crate::{
+        ZmqError, ZmqResult,
+        context::Context,
+        message::{Message, MultipartMessage},
+        patterns::majordomo::{
+            CLIENT_HEADER, ClientCommand, DEFAULT_HEARTBEAT_INTERVAL, DEFAULT_HEARTBEAT_LIVENESS,
+            WORKER_HEADER, WorkerCommand,
+        },
+        socket::{DealerSocket, MultipartReceiver, MultipartSender, RecvFlags, SendFlags},
+    };
+
+    #[test]
+    fn new_uses_defaults() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let broker = Broker::new(&context)?;
+
+        assert_eq!(broker.heartbeat_interval(), DEFAULT_HEARTBEAT_INTERVAL);
+        assert_eq!(broker.heartbeat_liveness(), DEFAULT_HEARTBEAT_LIVENESS);
+
+        Ok(())
+    }
+
+    #[test]
+    fn setters_set_values() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let mut broker = Broker::new(&context)?;
+        broker.set_heartbeat_interval(100)?;
+        broker.set_heartbeat_liveness(5);
+
+        assert_eq!(broker.heartbeat_interval(), 100);
+        assert_eq!(broker.heartbeat_liveness(), 5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_heartbeat_interval_rejects_non_positive_intervals() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let mut broker = Broker::new(&context)?;
+        for interval in [0, -1] {
+            let result = broker.set_heartbeat_interval(interval);
+            assert!(result.is_err_and(|err| err == ZmqError::InvalidArgument));
+        }
+        assert_eq!(broker.heartbeat_interval(), DEFAULT_HEARTBEAT_INTERVAL);
+
+        Ok(())
+    }
+
+    #[test]
+    fn run_returns_when_context_terminated() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let broker = Broker::new(&context)?;
+        broker.bind("inproc://majordomo-broker-run")?;
+
+        let handle = std::thread::spawn(move || broker.run());
+        context.shutdown()?;
+
+        handle.join().unwrap()
+    }
+
+    #[test]
+    fn broker_sends_heartbeats_to_waiting_workers() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let mut broker = Broker::new(&context)?;
+        broker.set_heartbeat_interval(20)?;
+        broker.bind("inproc://majordomo-broker-heartbeat")?;
+        let broker = broker.start()?;
+
+        let worker = DealerSocket::from_context(&context)?;
+        worker.connect("inproc://majordomo-broker-heartbeat")?;
+        let ready: MultipartMessage = vec![
+            Message::from(WORKER_HEADER),
+            Message::from(WorkerCommand::Ready),
+            Message::from("heartbeat"),
+        ]
+        .into();
+        worker.send_multipart(ready, SendFlags::empty())?;
+
+        let heartbeat = worker.recv_multipart(RecvFlags::empty())?;
+        assert_eq!(heartbeat.len(), 2);
+        assert_eq!(heartbeat.get(0).unwrap().to_string(), WORKER_HEADER);
+        assert_eq!(
+            WorkerCommand::try_from(heartbeat.get(1).unwrap())?,
+            WorkerCommand::Heartbeat
+        );
+
+        broker.stop()
+    }
+
+    #[test]
+    fn broker_disconnects_unknown_workers() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let broker = Broker::new(&context)?;
+        broker.bind("inproc://majordomo-broker-unknown")?;
+        let broker = broker.start()?;
+
+        let worker = DealerSocket::from_context(&context)?;
+        worker.connect("inproc://majordomo-broker-unknown")?;
+        let heartbeat: MultipartMessage = vec![
+            Message::from(WORKER_HEADER),
+            Message::from(WorkerCommand::Heartbeat),
+        ]
+        .into();
+        worker.send_multipart(heartbeat, SendFlags::empty())?;
+
+        let disconnect = worker.recv_multipart(RecvFlags::empty())?;
+        assert_eq!(
+            WorkerCommand::try_from(disconnect.get(1).unwrap())?,
+            WorkerCommand::Disconnect
+        );
+
+        broker.stop()
+    }
+
+    #[test]
+    fn broker_ignores_invalid_client_messages() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let broker = Broker::new(&context)?;
+        broker.bind("inproc://majordomo-broker-invalid")?;
+        let broker = broker.start()?;
+
+        let client = DealerSocket::from_context(&context)?;
+        client.connect("inproc://majordomo-broker-invalid")?;
+        client.send_multipart(Message::from("garbage"), SendFlags::empty())?;
+
+        let request: MultipartMessage = vec![
+            Message::from(CLIENT_HEADER),
+            Message::from(ClientCommand::Request),
+            Message::from("mmi.service"),
+            Message::from("nothing"),
+        ]
+        .into();
+        client.send_multipart(request, SendFlags::empty())?;
+
+        let reply = client.recv_multipart(RecvFlags::empty())?;
+        assert_eq!(reply.len(), 4);
+        assert_eq!(reply.get(0).unwrap().to_string(), CLIENT_HEADER);
+        assert_eq!(
+            ClientCommand::try_from(reply.get(1).unwrap())?,
+            ClientCommand::Final
+        );
+        assert_eq!(reply.get(2).unwrap().to_string(), "mmi.service");
+        assert_eq!(reply.get(3).unwrap().to_string(), "404");
+
+        broker.stop()
+    }
+}
@@ -0,0 +1,333 @@
+use std::time::Instant;
+
+use super::{CLIENT_HEADER, ClientCommand, expect_header, interval_duration};
+use crate::{
+    ZmqError, ZmqResult,
+    context::Context,
+    endpoint::Endpoint,
+    message::{Message, MultipartMessage},
+    socket::{DealerSocket, MultipartReceiver, MultipartSender, PollEvents, RecvFlags, SendFlags},
+};
+
+#[derive(Debug, Clone)]
+/// Reply to a request of a [`Client`]
+pub struct Reply {
+    service: String,
+    body: MultipartMessage,
+    is_final: bool,
+}
+
+impl Reply {
+    /// The service that sent the reply.
+    pub fn service(&self) -> &str {
+        &self.service
+    }
+
+    /// The reply body.
+    pub fn body(&self) -> &MultipartMessage {
+        &self.body
+    }
+
+    /// Consumes the reply, returning its body.
+    pub fn into_body(self) -> MultipartMessage {
+        self.body
+    }
+
+    /// Whether this is the final reply to the request, or a partial one with more to come.
+    pub fn is_final(&self) -> bool {
+        self.is_final
+    }
+}
+
+impl TryFrom<MultipartMessage> for Reply {
+    type Error = ZmqError;
+
+    fn try_from(mut value: MultipartMessage) -> Result<Self, Self::Error> {
+        expect_header(value.pop_front(), CLIENT_HEADER)?;
+
+        let is_final = match value
+            .pop_front()
+            .ok_or(ZmqError::InvalidArgument)
+            .and_then(|command| ClientCommand::try_from(&command))?
+        {
+            ClientCommand::Partial => false,
+            ClientCommand::Final => true,
+            ClientCommand::Request => return Err(ZmqError::InvalidArgument),
+        };
+        let service = value
+            .pop_front()
+            .map(|service| service.to_string())
+            .ok_or(ZmqError::InvalidArgument)?;
+
+        Ok(Self {
+            service,
+            body: value,
+            is_final,
+        })
+    }
+}
+
+/// # Majordomo client
+///
+/// Sends requests to the services behind a [`Broker`]. Each reply is awaited for at most
+/// [`timeout()`] milliseconds, after which receiving fails with [`Again`].
+///
+/// [`Broker`]: super::Broker
+/// [`timeout()`]: #method.timeout
+/// [`Again`]: ZmqError::Again
+pub struct Client {
+    context: Context,
+    broker: Endpoint,
+    socket: DealerSocket,
+    timeout: i64,
+}
+
+impl Client {
+    /// Default time in milliseconds to wait for a reply
+    pub const DEFAULT_TIMEOUT: i64 = 2_500;
+
+    /// Creates a new client connected to the broker at the provided endpoint.
+    pub fn new<E>(context: &Context, broker: E) -> ZmqResult<Self>
+    where
+        E: TryInto<Endpoint>,
+        ZmqError: From<E::Error>,
+    {
+        let broker = broker.try_into()?;
+        let socket = Self::connect_socket(context, &broker)?;
+
+        Ok(Self {
+            context: context.clone(),
+            broker,
+            socket,
+            timeout: Self::DEFAULT_TIMEOUT,
+        })
+    }
+
+    fn connect_socket(context: &Context, broker: &Endpoint) -> ZmqResult<DealerSocket> {
+        let socket = DealerSocket::from_context(context)?;
+        socket.set_linger(0)?;
+        socket.connect(broker)?;
+
+        Ok(socket)
+    }
+
+    /// # Set the reply timeout
+    ///
+    /// Sets the time in milliseconds to wait for each reply. A value of `-1` waits indefinitely.
+    pub fn set_timeout(&mut self, value: i64) {
+        self.timeout = value;
+    }
+
+    /// # Retrieve the reply timeout
+    ///
+    /// Retrieves the time in milliseconds to wait for each reply.
+    pub fn timeout(&self) -> i64 {
+        self.timeout
+    }
+
+    /// # Send a request
+    ///
+    /// Sends a request to the provided service without waiting for its replies, which are
+    /// received through [`recv()`].
+    ///
+    /// [`recv()`]: #method.recv
+    pub fn send<S, M>(&self, service: S, body: M) -> ZmqResult<()>
+    where
+        S: AsRef<str>,
+        M: Into<MultipartMessage>,
+    {
+        let mut multipart = body.into();
+        multipart.push_front(Message::from(service.as_ref()));
+        multipart.push_front(Message::from(ClientCommand::Request));
+        multipart.push_front(Message::from(CLIENT_HEADER));
+
+        self.socket.send_multipart(multipart, SendFlags::empty())
+    }
+
+    /// # Receive a reply
+    ///
+    /// Waits for the next partial or final reply to any of the sent requests. Fails with
+    /// [`Again`] if no reply arrived within [`timeout()`] milliseconds.
+    ///
+    /// [`Again`]: ZmqError::Again
+    /// [`timeout()`]: #method.timeout
+    pub fn recv(&mut self) -> ZmqResult<Reply> {
+        let deadline =
+            (self.timeout >= 0).then(|| Instant::now() + interval_duration(self.timeout));
+
+        loop {
+            let timeout = deadline.map_or(-1, |deadline| {
+                deadline
+                    .saturating_duration_since(Instant::now())
+                    .as_millis() as i64
+            });
+            if !self
+                .socket
+                .poll(PollEvents::POLL_IN, timeout)?
+                .contains(PollEvents::POLL_IN)
+            {
+                return Err(ZmqError::Again);
+            }
+
+            let multipart = self.socket.recv_multipart(RecvFlags::empty())?;
+            if let Ok(reply) = Reply::try_from(multipart) {
+                return Ok(reply);
+            }
+        }
+    }
+
+    /// # Send a request and wait for its final reply
+    ///
+    /// Sends a request to the provided service, and returns the body of its final reply. Partial
+    /// replies are skipped, use [`send()`] and [`recv()`] to process them.
+    ///
+    /// When the reply does not arrive in time, the client reconnects to the broker, so that a late
+    /// reply cannot be mistaken for the reply to the next request, and fails with [`Again`].
+    ///
+    /// [`send()`]: #method.send
+    /// [`recv()`]: #method.recv
+    /// [`Again`]: ZmqError::Again
+    pub fn request<S, M>(&mut self, service: S, body: M) -> ZmqResult<MultipartMessage>
+    where
+        S: AsRef<str>,
+        M: Into<MultipartMessage>,
+    {
+        self.send(service, body)?;
+
+        loop {
+            match self.recv() {
+                Ok(reply) if reply.is_final() => return Ok(reply.into_body()),
+                Ok(_partial) => (),
+                Err(ZmqError::Again) => {
+                    self.socket = Self::connect_socket(&self.context, &self.broker)?;
+                    return Err(ZmqError::Again);
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod client_tests {
+    use rstest::*;
+
+    use super::{Client, Reply};
+    use crate::{
+        ZmqError, ZmqResult,
+        context::Context,
+        message::{Message, MultipartMessage},
+        patterns::majordomo::{CLIENT_HEADER, ClientCommand},
+        socket::{MultipartReceiver, MultipartSender, RecvFlags, RouterSocket, SendFlags},
+    };
+
+    #[rstest]
+    #[case(ClientCommand::Partial, false)]
+    #[case(ClientCommand::Final, true)]
+    fn reply_try_from_multipart(
+        #[case] command: ClientCommand,
+        #[case] is_final: bool,
+    ) -> ZmqResult<()> {
+        let multipart: MultipartMessage = vec![
+            Message::from(CLIENT_HEADER),
+            Message::from(command),
+            Message::from("echo"),
+            Message::from("Hello"),
+        ]
+        .into();
+
+        let reply = Reply::try_from(multipart)?;
+        assert_eq!(reply.service(), "echo");
+        assert_eq!(reply.is_final(), is_final);
+        assert_eq!(reply.body().len(), 1);
+        assert_eq!(reply.into_body().get(0).unwrap().to_string(), "Hello");
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(vec![])]
+    #[case(vec![Message::from("MDPC01"), Message::from(ClientCommand::Final), Message::from("echo")])]
+    #[case(vec![Message::from(CLIENT_HEADER), Message::from(ClientCommand::Request), Message::from("echo")])]
+    #[case(vec![Message::from(CLIENT_HEADER), Message::from(ClientCommand::Final)])]
+    fn reply_try_from_invalid_multipart(#[case] frames: Vec<Message>) {
+        let result = Reply::try_from(MultipartMessage::from(frames));
+        assert!(result.is_err_and(|err| err == ZmqError::InvalidArgument));
+    }
+
+    #[test]
+    fn send_sends_request() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let broker = RouterSocket::from_context(&context)?;
+        broker.bind("inproc://majordomo-client-send")?;
+
+        let client = Client::new(&context, "inproc://majordomo-client-send")?;
+        assert_eq!(client.timeout(), Client::DEFAULT_TIMEOUT);
+        client.send("echo", Message::from("Hello"))?;
+
+        let request = broker.recv_multipart(RecvFlags::empty())?;
+        assert_eq!(request.len(), 5);
+        assert_eq!(request.get(1).unwrap().to_string(), CLIENT_HEADER);
+        assert_eq!(
+            ClientCommand::try_from(request.get(2).unwrap())?,
+            ClientCommand::Request
+        );
+        assert_eq!(request.get(3).unwrap().to_string(), "echo");
+        assert_eq!(request.get(4).unwrap().to_string(), "Hello");
+
+        Ok(())
+    }
+
+    #[test]
+    fn request_skips_partial_replies() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let broker = RouterSocket::from_context(&context)?;
+        broker.bind("inproc://majordomo-client-request")?;
+
+        std::thread::spawn(move || -> ZmqResult<()> {
+            let mut request = broker.recv_multipart(RecvFlags::empty())?;
+            let client = request.pop_front().unwrap();
+
+            for (command, body) in [
+                (ClientCommand::Partial, "partial"),
+                (ClientCommand::Final, "final"),
+            ] {
+                let reply: MultipartMessage = vec![
+                    client.clone(),
+                    Message::from(CLIENT_HEADER),
+                    Message::from(command),
+                    Message::from("echo"),
+                    Message::from(body),
+                ]
+                .into();
+                broker.send_multipart(reply, SendFlags::empty())?;
+            }
+
+            Ok(())
+        });
+
+        let mut client = Client::new(&context, "inproc://majordomo-client-request")?;
+        let reply = client.request("echo", Message::from("Hello"))?;
+
+        assert_eq!(reply.len(), 1);
+        assert_eq!(reply.get(0).unwrap().to_string(), "final");
+
+        Ok(())
+    }
+
+    #[test]
+    fn recv_times_out() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let mut client = Client::new(&context, "inproc://majordomo-client-timeout")?;
+        client.set_timeout(10);
+        assert_eq!(client.timeout(), 10);
+
+        let result = client.recv();
+        assert!(result.is_err_and(|err| err == ZmqError::Again));
+
+        Ok(())
+    }
+}
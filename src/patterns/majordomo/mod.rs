@@ -0,0 +1,371 @@
+//! # Majordomo Protocol (MDP/0.2)
+//!
+//! Service-oriented request routing following the
+//! [Majordomo Protocol](https://rfc.zeromq.org/spec/18/). A [`Broker`] binds a [`RouterSocket`]
+//! that both [`Client`]s and [`Worker`]s connect to. Workers register for a service name, and the
+//! broker routes every client request to a waiting worker of the requested service. Workers may
+//! stream any number of partial replies before the final one.
+//!
+//! The broker and its workers heartbeat each other, so that the broker forgets about dead
+//! workers, and workers reconnect when the broker goes away. The broker also answers the
+//! `mmi.service` request of the Majordomo Management Interface, which tells whether workers for a
+//! service are available.
+//!
+//! ```
+//! # use arzmq::{
+//! #     patterns::majordomo::{Broker, Client, Worker},
+//! #     prelude::{Context, Message, ZmqResult},
+//! # };
+//! #
+//! fn main() -> ZmqResult<()> {
+//!     let context = Context::new()?;
+//!
+//!     let broker = Broker::new(&context)?;
+//!     broker.bind("inproc://majordomo-example")?;
+//!     let broker = broker.start()?;
+//!
+//!     let mut worker = Worker::new(&context, "inproc://majordomo-example", "echo")?;
+//!     std::thread::spawn(move || {
+//!         let request = worker.recv_request().unwrap();
+//!         let client = request.client().clone();
+//!         worker.send_final(&client, request.into_body()).unwrap();
+//!     });
+//!
+//!     let mut client = Client::new(&context, "inproc://majordomo-example")?;
+//!     let reply = client.request("echo", Message::from("Hello"))?;
+//!     assert_eq!(reply.get(0).unwrap().to_string(), "Hello");
+//!
+//!     broker.stop()
+//! }
+//! ```
+//!
+//! [`RouterSocket`]: crate::socket::RouterSocket
+mod broker;
+mod client;
+mod worker;
+
+pub use broker::{Broker, BrokerHandle};
+pub use client::{Client, Reply};
+pub use worker::{Request, Worker};
+
+use super::interval_duration;
+use crate::{ZmqError, ZmqResult, ffi::RawMessage, message::Message};
+
+/// Protocol header of all messages between clients and the broker
+const CLIENT_HEADER: &str = "MDPC02";
+/// Protocol header of all messages between workers and the broker
+const WORKER_HEADER: &str = "MDPW02";
+/// Prefix of the service names handled by the broker itself
+const MMI_PREFIX: &str = "mmi.";
+
+/// Default interval between two heartbeats in milliseconds
+pub const DEFAULT_HEARTBEAT_INTERVAL: i64 = 2_500;
+/// Default number of missed heartbeats after which a peer is considered dead
+pub const DEFAULT_HEARTBEAT_LIVENESS: u32 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+enum ClientCommand {
+    Request = 0x01,
+    Partial = 0x02,
+    Final = 0x03,
+}
+
+impl TryFrom<&Message> for ClientCommand {
+    type Error = ZmqError;
+
+    fn try_from(value: &Message) -> Result<Self, Self::Error> {
+        match &*value.as_slice() {
+            [0x01] => Ok(Self::Request),
+            [0x02] => Ok(Self::Partial),
+            [0x03] => Ok(Self::Final),
+            _ => Err(ZmqError::InvalidArgument),
+        }
+    }
+}
+
+impl From<ClientCommand> for RawMessage {
+    fn from(value: ClientCommand) -> Self {
+        RawMessage::from(&[value as u8][..])
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+enum WorkerCommand {
+    Ready = 0x01,
+    Request = 0x02,
+    Partial = 0x03,
+    Final = 0x04,
+    Heartbeat = 0x05,
+    Disconnect = 0x06,
+}
+
+impl TryFrom<&Message> for WorkerCommand {
+    type Error = ZmqError;
+
+    fn try_from(value: &Message) -> Result<Self, Self::Error> {
+        match &*value.as_slice() {
+            [0x01] => Ok(Self::Ready),
+            [0x02] => Ok(Self::Request),
+            [0x03] => Ok(Self::Partial),
+            [0x04] => Ok(Self::Final),
+            [0x05] => Ok(Self::Heartbeat),
+            [0x06] => Ok(Self::Disconnect),
+            _ => Err(ZmqError::InvalidArgument),
+        }
+    }
+}
+
+impl From<WorkerCommand> for RawMessage {
+    fn from(value: WorkerCommand) -> Self {
+        RawMessage::from(&[value as u8][..])
+    }
+}
+
+fn expect_header(header: Option<Message>, expected: &str) -> ZmqResult<()> {
+    match header {
+        Some(header) if header.as_slice() == expected.as_bytes() => Ok(()),
+        _ => Err(ZmqError::InvalidArgument),
+    }
+}
+
+#[cfg(test)]
+mod majordomo_tests {
+    use core::time::Duration;
+    use std::thread;
+
+    use rstest::*;
+
+    use super::{Broker, Client, ClientCommand, Worker, WorkerCommand};
+    use crate::{
+        ZmqError, ZmqResult,
+        context::Context,
+        message::{Message, MultipartMessage},
+    };
+
+    #[rstest]
+    #[case(ClientCommand::Request)]
+    #[case(ClientCommand::Partial)]
+    #[case(ClientCommand::Final)]
+    fn client_command_round_trips(#[case] command: ClientCommand) -> ZmqResult<()> {
+        assert_eq!(ClientCommand::try_from(&Message::from(command))?, command);
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(WorkerCommand::Ready)]
+    #[case(WorkerCommand::Request)]
+    #[case(WorkerCommand::Partial)]
+    #[case(WorkerCommand::Final)]
+    #[case(WorkerCommand::Heartbeat)]
+    #[case(WorkerCommand::Disconnect)]
+    fn worker_command_round_trips(#[case] command: WorkerCommand) -> ZmqResult<()> {
+        assert_eq!(WorkerCommand::try_from(&Message::from(command))?, command);
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(&[])]
+    #[case(&[0x00])]
+    #[case(&[0x07])]
+    #[case(&[0x01, 0x02])]
+    fn invalid_commands(#[case] frame: &[u8]) {
+        let frame = Message::from(frame);
+
+        assert!(ClientCommand::try_from(&frame).is_err_and(|err| err == ZmqError::InvalidArgument));
+        assert!(WorkerCommand::try_from(&frame).is_err_and(|err| err == ZmqError::InvalidArgument));
+    }
+
+    fn spawn_echo_worker(context: &Context, endpoint: &str, service: &str) -> ZmqResult<()> {
+        let mut worker = Worker::new(context, endpoint, service)?;
+        worker.set_heartbeat_interval(100)?;
+
+        thread::spawn(move || {
+            while let Ok(request) = worker.recv_request() {
+                let client = request.client().clone();
+                if worker.send_final(&client, request.into_body()).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("inproc://majordomo-end-to-end")]
+    #[case("tcp://127.0.0.1:*")]
+    fn request_reply_end_to_end(#[case] endpoint: &str) -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let broker = Broker::new(&context)?;
+        broker.bind(endpoint)?;
+        let broker_endpoint = broker.last_endpoint()?.unwrap().to_string();
+        let broker = broker.start()?;
+
+        spawn_echo_worker(&context, &broker_endpoint, "echo")?;
+
+        let mut client = Client::new(&context, &broker_endpoint)?;
+        client.set_timeout(2_500);
+
+        for request in ["Hello", "World"] {
+            let body: MultipartMessage = vec![Message::from(request), Message::from("!")].into();
+            let reply = client.request("echo", body)?;
+
+            assert_eq!(reply.len(), 2);
+            assert_eq!(reply.get(0).unwrap().to_string(), request);
+            assert_eq!(reply.get(1).unwrap().to_string(), "!");
+        }
+
+        broker.stop()
+    }
+
+    #[test]
+    fn partial_replies_are_streamed() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let broker = Broker::new(&context)?;
+        broker.bind("inproc://majordomo-partial")?;
+        let broker = broker.start()?;
+
+        let mut worker = Worker::new(&context, "inproc://majordomo-partial", "count")?;
+        thread::spawn(move || {
+            let request = worker.recv_request().unwrap();
+            worker
+                .send_partial(request.client(), Message::from("1"))
+                .unwrap();
+            worker
+                .send_partial(request.client(), Message::from("2"))
+                .unwrap();
+            worker
+                .send_final(request.client(), Message::from("3"))
+                .unwrap();
+        });
+
+        let mut client = Client::new(&context, "inproc://majordomo-partial")?;
+        client.send("count", Message::from("go"))?;
+
+        for (expected, is_final) in [("1", false), ("2", false), ("3", true)] {
+            let reply = client.recv()?;
+            assert_eq!(reply.service(), "count");
+            assert_eq!(reply.is_final(), is_final);
+            assert_eq!(reply.body().get(0).unwrap().to_string(), expected);
+        }
+
+        broker.stop()
+    }
+
+    #[test]
+    fn requests_are_queued_until_a_worker_is_ready() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let broker = Broker::new(&context)?;
+        broker.bind("inproc://majordomo-queued")?;
+        let broker = broker.start()?;
+
+        let mut client = Client::new(&context, "inproc://majordomo-queued")?;
+        client.send("late", Message::from("Hello"))?;
+
+        spawn_echo_worker(&context, "inproc://majordomo-queued", "late")?;
+
+        let reply = client.recv()?;
+        assert!(reply.is_final());
+        assert_eq!(reply.body().get(0).unwrap().to_string(), "Hello");
+
+        broker.stop()
+    }
+
+    #[test]
+    fn mmi_service_reports_available_services() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let broker = Broker::new(&context)?;
+        broker.bind("inproc://majordomo-mmi")?;
+        let broker = broker.start()?;
+
+        spawn_echo_worker(&context, "inproc://majordomo-mmi", "echo")?;
+
+        let mut client = Client::new(&context, "inproc://majordomo-mmi")?;
+        // wait for the worker to be registered
+        client.request("echo", Message::from("ping"))?;
+
+        let reply = client.request("mmi.service", Message::from("echo"))?;
+        assert_eq!(reply.get(0).unwrap().to_string(), "200");
+
+        let reply = client.request("mmi.service", Message::from("unknown"))?;
+        assert_eq!(reply.get(0).unwrap().to_string(), "404");
+
+        let reply = client.request("mmi.unknown", MultipartMessage::new())?;
+        assert_eq!(reply.get(0).unwrap().to_string(), "501");
+
+        broker.stop()
+    }
+
+    #[test]
+    fn client_request_times_out_without_worker() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let broker = Broker::new(&context)?;
+        broker.bind("inproc://majordomo-timeout")?;
+        let broker = broker.start()?;
+
+        let mut client = Client::new(&context, "inproc://majordomo-timeout")?;
+        client.set_timeout(50);
+
+        let result = client.request("nobody", Message::from("Hello"));
+        assert!(result.is_err_and(|err| err == ZmqError::Again));
+
+        broker.stop()
+    }
+
+    #[test]
+    fn worker_reconnects_after_broker_restart() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let broker = Broker::new(&context)?;
+        broker.bind("tcp://127.0.0.1:*")?;
+        let broker_endpoint = broker.last_endpoint()?.unwrap().to_string();
+        let broker = broker.start()?;
+
+        let mut worker = Worker::new(&context, &broker_endpoint, "echo")?;
+        worker.set_heartbeat_interval(50)?;
+        worker.set_heartbeat_liveness(2);
+        worker.set_reconnect_interval(50);
+        thread::spawn(move || {
+            while let Ok(request) = worker.recv_request() {
+                let client = request.client().clone();
+                if worker.send_final(&client, request.into_body()).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let mut client = Client::new(&context, &broker_endpoint)?;
+        client.set_timeout(2_500);
+        client.request("echo", Message::from("Hello"))?;
+
+        broker.stop()?;
+
+        let broker = Broker::new(&context)?;
+        let mut attempts = 0;
+        while let Err(err) = broker.bind(&broker_endpoint) {
+            attempts += 1;
+            if attempts == 100 {
+                return Err(err);
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        let broker = broker.start()?;
+
+        let mut client = Client::new(&context, &broker_endpoint)?;
+        client.set_timeout(2_500);
+
+        let reply = client.request("echo", Message::from("World"))?;
+        assert_eq!(reply.get(0).unwrap().to_string(), "World");
+
+        broker.stop()
+    }
+}
@@ -0,0 +1,413 @@
+use std::{thread, time::Instant};
+
+use super::{
+    DEFAULT_HEARTBEAT_INTERVAL, DEFAULT_HEARTBEAT_LIVENESS, WORKER_HEADER, WorkerCommand,
+    expect_header, interval_duration,
+};
+use crate::{
+    ZmqError, ZmqResult,
+    context::Context,
+    endpoint::Endpoint,
+    message::{Message, MultipartMessage, RoutingId},
+    socket::{DealerSocket, MultipartReceiver, MultipartSender, PollEvents, RecvFlags, SendFlags},
+};
+
+#[derive(Debug, Clone)]
+/// Request of a client, as received by a [`Worker`]
+pub struct Request {
+    client: RoutingId,
+    body: MultipartMessage,
+}
+
+impl Request {
+    /// Address of the requesting client, to hand back with the replies.
+    pub fn client(&self) -> &RoutingId {
+        &self.client
+    }
+
+    /// The request body.
+    pub fn body(&self) -> &MultipartMessage {
+        &self.body
+    }
+
+    /// Consumes the request, returning its body.
+    pub fn into_body(self) -> MultipartMessage {
+        self.body
+    }
+}
+
+/// # Majordomo worker
+///
+/// Serves the requests for a single service, that a [`Broker`] routes to it. The worker sends a
+/// heartbeat every [`heartbeat_interval()`] milliseconds while waiting for requests. When the
+/// broker did not send anything for [`heartbeat_liveness()`] intervals, the worker waits
+/// [`reconnect_interval()`] milliseconds, and registers with the broker again on a new connection.
+///
+/// [`Broker`]: super::Broker
+/// [`heartbeat_interval()`]: #method.heartbeat_interval
+/// [`heartbeat_liveness()`]: #method.heartbeat_liveness
+/// [`reconnect_interval()`]: #method.reconnect_interval
+pub struct Worker {
+    context: Context,
+    broker: Endpoint,
+    service: String,
+    socket: DealerSocket,
+    heartbeat_interval: i64,
+    heartbeat_liveness: u32,
+    reconnect_interval: i64,
+    liveness: u32,
+    heartbeat_at: Instant,
+}
+
+impl Worker {
+    /// Default delay in milliseconds before reconnecting to the broker
+    pub const DEFAULT_RECONNECT_INTERVAL: i64 = 2_500;
+
+    /// Creates a new worker for the provided service, and registers it with the broker at the
+    /// provided endpoint.
+    pub fn new<E, S>(context: &Context, broker: E, service: S) -> ZmqResult<Self>
+    where
+        E: TryInto<Endpoint>,
+        ZmqError: From<E::Error>,
+        S: Into<String>,
+    {
+        let broker = broker.try_into()?;
+        let service = service.into();
+        let socket = Self::connect_socket(context, &broker, &service)?;
+
+        Ok(Self {
+            context: context.clone(),
+            broker,
+            service,
+            socket,
+            heartbeat_interval: DEFAULT_HEARTBEAT_INTERVAL,
+            heartbeat_liveness: DEFAULT_HEARTBEAT_LIVENESS,
+            reconnect_interval: Self::DEFAULT_RECONNECT_INTERVAL,
+            liveness: DEFAULT_HEARTBEAT_LIVENESS,
+            heartbeat_at: Instant::now() + interval_duration(DEFAULT_HEARTBEAT_INTERVAL),
+        })
+    }
+
+    fn connect_socket(
+        context: &Context,
+        broker: &Endpoint,
+        service: &str,
+    ) -> ZmqResult<DealerSocket> {
+        let socket = DealerSocket::from_context(context)?;
+        socket.set_linger(0)?;
+        socket.connect(broker)?;
+
+        send_to_broker(&socket, WorkerCommand::Ready, Message::from(service).into())?;
+
+        Ok(socket)
+    }
+
+    fn reconnect(&mut self) -> ZmqResult<()> {
+        self.socket = Self::connect_socket(&self.context, &self.broker, &self.service)?;
+        self.liveness = self.heartbeat_liveness;
+        self.heartbeat_at = Instant::now() + interval_duration(self.heartbeat_interval);
+
+        Ok(())
+    }
+
+    /// The service the worker serves.
+    pub fn service(&self) -> &str {
+        &self.service
+    }
+
+    /// # Set the heartbeat interval
+    ///
+    /// Sets the interval in milliseconds between two heartbeats sent to the broker. Intervals that
+    /// are not positive are rejected with [`InvalidArgument`].
+    ///
+    /// [`InvalidArgument`]: ZmqError::InvalidArgument
+    pub fn set_heartbeat_interval(&mut self, value: i64) -> ZmqResult<()> {
+        if value <= 0 {
+            return Err(ZmqError::InvalidArgument);
+        }
+        self.heartbeat_interval = value;
+        self.heartbeat_at = Instant::now() + interval_duration(value);
+        Ok(())
+    }
+
+    /// # Retrieve the heartbeat interval
+    ///
+    /// Retrieves the interval in milliseconds between two heartbeats sent to the broker.
+    pub fn heartbeat_interval(&self) -> i64 {
+        self.heartbeat_interval
+    }
+
+    /// # Set the heartbeat liveness
+    ///
+    /// Sets the number of heartbeat intervals without any message from the broker, after which
+    /// the worker reconnects.
+    pub fn set_heartbeat_liveness(&mut self, value: u32) {
+        self.heartbeat_liveness = value;
+        self.liveness = value;
+    }
+
+    /// # Retrieve the heartbeat liveness
+    ///
+    /// Retrieves the number of heartbeat intervals without any message from the broker, after
+    /// which the worker reconnects.
+    pub fn heartbeat_liveness(&self) -> u32 {
+        self.heartbeat_liveness
+    }
+
+    /// # Set the reconnect interval
+    ///
+    /// Sets the delay in milliseconds before reconnecting to an unresponsive broker.
+    pub fn set_reconnect_interval(&mut self, value: i64) {
+        self.reconnect_interval = value;
+    }
+
+    /// # Retrieve the reconnect interval
+    ///
+    /// Retrieves the delay in milliseconds before reconnecting to an unresponsive broker.
+    pub fn reconnect_interval(&self) -> i64 {
+        self.reconnect_interval
+    }
+
+    /// # Receive the next request
+    ///
+    /// Blocks the current thread until the broker hands over the next request, while
+    /// heartbeating, and reconnecting to the broker as necessary. Every received request has to be
+    /// answered with a [`send_final()`] reply, optionally preceded by [`send_partial()`] replies,
+    /// before the worker receives the next request.
+    ///
+    /// [`send_final()`]: #method.send_final
+    /// [`send_partial()`]: #method.send_partial
+    pub fn recv_request(&mut self) -> ZmqResult<Request> {
+        loop {
+            if self
+                .socket
+                .poll(PollEvents::POLL_IN, self.heartbeat_interval)?
+                .contains(PollEvents::POLL_IN)
+            {
+                let multipart = self.socket.recv_multipart(RecvFlags::empty())?;
+                self.liveness = self.heartbeat_liveness;
+
+                match parse_broker_message(multipart) {
+                    Ok((WorkerCommand::Request, mut body)) => {
+                        if let Some(client) = body.pop_front().map(RoutingId::from) {
+                            // envelope delimiter
+                            body.pop_front();
+                            return Ok(Request { client, body });
+                        }
+                    }
+                    Ok((WorkerCommand::Disconnect, _)) => self.reconnect()?,
+                    _ => (),
+                }
+            } else {
+                self.liveness = self.liveness.saturating_sub(1);
+                if self.liveness == 0 {
+                    thread::sleep(interval_duration(self.reconnect_interval));
+                    self.reconnect()?;
+                }
+            }
+
+            if Instant::now() >= self.heartbeat_at {
+                send_to_broker(
+                    &self.socket,
+                    WorkerCommand::Heartbeat,
+                    MultipartMessage::new(),
+                )?;
+                self.heartbeat_at = Instant::now() + interval_duration(self.heartbeat_interval);
+            }
+        }
+    }
+
+    /// # Send a partial reply
+    ///
+    /// Streams a partial reply to the client of a received request. The request remains open
+    /// until the [`send_final()`] reply.
+    ///
+    /// [`send_final()`]: #method.send_final
+    pub fn send_partial<M>(&self, client: &RoutingId, body: M) -> ZmqResult<()>
+    where
+        M: Into<MultipartMessage>,
+    {
+        self.send_reply(WorkerCommand::Partial, client, body.into())
+    }
+
+    /// # Send the final reply
+    ///
+    /// Sends the final reply to the client of a received request, which completes the request.
+    pub fn send_final<M>(&self, client: &RoutingId, body: M) -> ZmqResult<()>
+    where
+        M: Into<MultipartMessage>,
+    {
+        self.send_reply(WorkerCommand::Final, client, body.into())
+    }
+
+    fn send_reply(
+        &self,
+        command: WorkerCommand,
+        client: &RoutingId,
+        mut body: MultipartMessage,
+    ) -> ZmqResult<()> {
+        body.push_front(Message::new());
+        body.push_front(Message::from(client));
+
+        send_to_broker(&self.socket, command, body)
+    }
+}
+
+impl Drop for Worker {
+    fn drop(&mut self) {
+        let disconnect: MultipartMessage = vec![
+            Message::from(WORKER_HEADER),
+            Message::from(WorkerCommand::Disconnect),
+        ]
+        .into();
+        let _ = self.socket.send_multipart(disconnect, SendFlags::DONT_WAIT);
+    }
+}
+
+fn send_to_broker(
+    socket: &DealerSocket,
+    command: WorkerCommand,
+    mut body: MultipartMessage,
+) -> ZmqResult<()> {
+    body.push_front(Message::from(command));
+    body.push_front(Message::from(WORKER_HEADER));
+
+    socket.send_multipart(body, SendFlags::empty())
+}
+
+fn parse_broker_message(
+    mut multipart: MultipartMessage,
+) -> ZmqResult<(WorkerCommand, MultipartMessage)> {
+    expect_header(multipart.pop_front(), WORKER_HEADER)?;
+    let command = multipart
+        .pop_front()
+        .ok_or(ZmqError::InvalidArgument)
+        .and_then(|command| WorkerCommand::try_from(&command))?;
+
+    Ok((command, multipart))
+}
+
+#[cfg(test)]
+mod worker_tests {
+    use super::Worker;
+    use crate::{
+        ZmqError, ZmqResult,
+        context::Context,
+        message::{Message, MultipartMessage, RoutingId},
+        patterns::majordomo::{
+            DEFAULT_HEARTBEAT_INTERVAL, DEFAULT_HEARTBEAT_LIVENESS, WORKER_HEADER, WorkerCommand,
+        },
+        socket::{MultipartReceiver, MultipartSender, RecvFlags, RouterSocket, SendFlags},
+    };
+
+    #[test]
+    fn new_registers_with_broker() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let broker = RouterSocket::from_context(&context)?;
+        broker.bind("inproc://majordomo-worker-ready")?;
+
+        let worker = Worker::new(&context, "inproc://majordomo-worker-ready", "echo")?;
+        assert_eq!(worker.service(), "echo");
+        assert_eq!(worker.heartbeat_interval(), DEFAULT_HEARTBEAT_INTERVAL);
+        assert_eq!(worker.heartbeat_liveness(), DEFAULT_HEARTBEAT_LIVENESS);
+        assert_eq!(
+            worker.reconnect_interval(),
+            Worker::DEFAULT_RECONNECT_INTERVAL
+        );
+
+        let ready = broker.recv_multipart(RecvFlags::empty())?;
+        assert_eq!(ready.len(), 4);
+        assert_eq!(ready.get(1).unwrap().to_string(), WORKER_HEADER);
+        assert_eq!(
+            WorkerCommand::try_from(ready.get(2).unwrap())?,
+            WorkerCommand::Ready
+        );
+        assert_eq!(ready.get(3).unwrap().to_string(), "echo");
+
+        Ok(())
+    }
+
+    #[test]
+    fn recv_request_sends_heartbeats_and_replies() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let broker = RouterSocket::from_context(&context)?;
+        broker.bind("inproc://majordomo-worker-request")?;
+
+        let mut worker = Worker::new(&context, "inproc://majordomo-worker-request", "echo")?;
+        worker.set_heartbeat_interval(10)?;
+
+        let mut ready = broker.recv_multipart(RecvFlags::empty())?;
+        let identity = ready.pop_front().unwrap();
+
+        let handle = std::thread::spawn(move || -> ZmqResult<()> {
+            let request = worker.recv_request()?;
+            assert_eq!(request.client(), &RoutingId::from("client"));
+            worker.send_partial(request.client(), Message::from("partial"))?;
+            let client = request.client().clone();
+            worker.send_final(&client, request.into_body())
+        });
+
+        let heartbeat = broker.recv_multipart(RecvFlags::empty())?;
+        assert_eq!(
+            WorkerCommand::try_from(heartbeat.get(2).unwrap())?,
+            WorkerCommand::Heartbeat
+        );
+
+        let request: MultipartMessage = vec![
+            identity,
+            Message::from(WORKER_HEADER),
+            Message::from(WorkerCommand::Request),
+            Message::from("client"),
+            Message::new(),
+            Message::from("Hello"),
+        ]
+        .into();
+        broker.send_multipart(request, SendFlags::empty())?;
+
+        let mut commands = vec![];
+        while commands.last() != Some(&WorkerCommand::Final) {
+            let reply = broker.recv_multipart(RecvFlags::empty())?;
+            let command = WorkerCommand::try_from(reply.get(2).unwrap())?;
+            if command != WorkerCommand::Heartbeat {
+                assert_eq!(reply.get(3).unwrap().to_string(), "client");
+                assert!(reply.get(4).unwrap().is_empty());
+                commands.push(command);
+            }
+        }
+        assert_eq!(commands, vec![WorkerCommand::Partial, WorkerCommand::Final]);
+
+        handle.join().unwrap()
+    }
+
+    #[test]
+    fn setters_set_values() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let mut worker = Worker::new(&context, "inproc://majordomo-worker-setters", "echo")?;
+        worker.set_heartbeat_interval(100)?;
+        worker.set_heartbeat_liveness(5);
+        worker.set_reconnect_interval(200);
+
+        assert_eq!(worker.heartbeat_interval(), 100);
+        assert_eq!(worker.heartbeat_liveness(), 5);
+        assert_eq!(worker.reconnect_interval(), 200);
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_heartbeat_interval_rejects_non_positive_intervals() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let mut worker = Worker::new(&context, "inproc://majordomo-worker-interval", "echo")?;
+        for interval in [0, -1] {
+            let result = worker.set_heartbeat_interval(interval);
+            assert!(result.is_err_and(|err| err == ZmqError::InvalidArgument));
+        }
+        assert_eq!(worker.heartbeat_interval(), DEFAULT_HEARTBEAT_INTERVAL);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+//! # Reliable messaging patterns
+//!
+//! Higher-level building blocks on top of the plain 0MQ sockets, implementing the reliability
+//! patterns from the 0MQ guide.
+//!
+//! The [`ReliableRequestClient`] implements the client side of the "Lazy Pirate" pattern: a
+//! [`RequestSocket`] that gives up waiting for a reply after a timeout, and transparently replaces
+//! its socket to retry the request.
+//!
+//! The [`ParanoidPirateQueue`] load balances requests over [`ParanoidPirateWorker`]s, detecting
+//! dead workers and dead queues through heartbeating ("Paranoid Pirate").
+//!
+//! The [`majordomo`] module implements the Majordomo Protocol for service-oriented request
+//! routing through a broker.
+//!
+//! The [`LastValueCache`] proxies publishers to subscribers, and replays the last value of a topic
+//! to late-joining subscribers ("Last Value Cache").
+//!
+//! The [`clone`] module replicates a key/value map from a server to any number of clients
+//! ("Clone").
+//!
+//! [`RequestSocket`]: crate::socket::RequestSocket
+pub mod clone;
+mod last_value_cache;
+mod lazy_pirate;
+pub mod majordomo;
+mod paranoid_pirate;
+
+pub use last_value_cache::{LastValueCache, LastValueCacheHandle, LastValueCacheStats};
+pub use lazy_pirate::ReliableRequestClient;
+#[cfg(feature = "builder")]
+pub use paranoid_pirate::builder::{ParanoidPirateQueueBuilder, ParanoidPirateWorkerBuilder};
+pub use paranoid_pirate::{ParanoidPirateQueue, ParanoidPirateQueueHandle, ParanoidPirateWorker};
+
+/// Converts an interval in milliseconds into a [`Duration`](core::time::Duration), treating
+/// negative values as zero.
+fn interval_duration(interval: i64) -> core::time::Duration {
+    core::time::Duration::from_millis(u64::try_from(interval).unwrap_or_default())
+}
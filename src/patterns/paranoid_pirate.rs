@@ -0,0 +1,979 @@
+use std::{collections::VecDeque, thread, time::Instant};
+
+use derive_more::Debug as DebugDeriveMore;
+
+use super::interval_duration;
+use crate::{
+    ZmqError, ZmqResult,
+    actor::Actor,
+    context::Context,
+    endpoint::Endpoint,
+    message::{Message, MultipartMessage, RoutingId},
+    poller::Poller,
+    socket::{
+        DealerSocket, MultipartReceiver, MultipartSender, PairSocket, PollEvents, RecvFlags,
+        RouterSocket, SendFlags, Sender,
+    },
+};
+
+/// Signal of a worker that it is ready for requests
+const READY: &[u8] = &[0x01];
+/// Heartbeat signal between the queue and its workers
+const HEARTBEAT: &[u8] = &[0x02];
+
+fn is_signal(multipart: &MultipartMessage, signal: &[u8]) -> bool {
+    multipart.len() == 1
+        && multipart
+            .get(0)
+            .is_some_and(|frame| frame.as_slice() == signal)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum QueueItem {
+    Frontend,
+    Backend,
+    Control,
+}
+
+struct QueueWorker {
+    identity: RoutingId,
+    /// `None` for workers that never expire
+    expiry: Option<Instant>,
+}
+
+/// # Load balancing queue with heartbeating ("Paranoid Pirate")
+///
+/// A [`ParanoidPirateQueue`] forwards the requests of clients connected to its [`Router`]
+/// frontend to the [`ParanoidPirateWorker`]s connected to its [`Router`] backend. Ready workers
+/// are kept in a least recently used list, so that every request is handed to the worker that has
+/// been waiting the longest.
+///
+/// The queue sends a heartbeat to all ready workers every [`heartbeat_interval()`] milliseconds,
+/// and drops workers that did not send anything for [`heartbeat_liveness()`] intervals.
+///
+/// The queue either [`run()`]s on the current thread until its [`Context`] is terminated, or is
+/// [`start()`]ed on a dedicated thread, that runs until the returned
+/// [`ParanoidPirateQueueHandle`] is stopped or dropped.
+///
+/// ```
+/// # use arzmq::{
+/// #     patterns::{ParanoidPirateQueue, ParanoidPirateWorker, ReliableRequestClient},
+/// #     prelude::{Context, Message, ZmqResult},
+/// # };
+/// #
+/// fn main() -> ZmqResult<()> {
+///     let context = Context::new()?;
+///
+///     let queue = ParanoidPirateQueue::new(&context)?;
+///     queue.bind_frontend("inproc://paranoid-pirate-frontend")?;
+///     queue.bind_backend("inproc://paranoid-pirate-backend")?;
+///     let queue = queue.start()?;
+///
+///     let mut worker = ParanoidPirateWorker::new(&context, "inproc://paranoid-pirate-backend")?;
+///     std::thread::spawn(move || {
+///         let request = worker.recv_request().unwrap();
+///         worker.send_reply(request).unwrap();
+///     });
+///
+///     let mut client = ReliableRequestClient::new(&context, "inproc://paranoid-pirate-frontend")?;
+///     let reply = client.request(Message::from("Hello"))?;
+///     assert_eq!(reply.get(0).unwrap().to_string(), "Hello");
+///
+///     queue.stop()
+/// }
+/// ```
+///
+/// [`Router`]: RouterSocket
+/// [`heartbeat_interval()`]: #method.heartbeat_interval
+/// [`heartbeat_liveness()`]: #method.heartbeat_liveness
+/// [`run()`]: #method.run
+/// [`start()`]: #method.start
+pub struct ParanoidPirateQueue {
+    context: Context,
+    frontend: RouterSocket,
+    backend: RouterSocket,
+    workers: VecDeque<QueueWorker>,
+    heartbeat_interval: i64,
+    heartbeat_liveness: u32,
+}
+
+impl ParanoidPirateQueue {
+    /// Default interval between two heartbeats in milliseconds
+    pub const DEFAULT_HEARTBEAT_INTERVAL: i64 = 1_000;
+    /// Default number of missed heartbeats after which a worker is considered dead
+    pub const DEFAULT_HEARTBEAT_LIVENESS: u32 = 3;
+
+    /// Creates a new queue, whose frontend and backend still need to be bound.
+    pub fn new(context: &Context) -> ZmqResult<Self> {
+        let frontend = RouterSocket::from_context(context)?;
+        frontend.set_linger(0)?;
+
+        let backend = RouterSocket::from_context(context)?;
+        backend.set_linger(0)?;
+
+        Ok(Self {
+            context: context.clone(),
+            frontend,
+            backend,
+            workers: VecDeque::new(),
+            heartbeat_interval: Self::DEFAULT_HEARTBEAT_INTERVAL,
+            heartbeat_liveness: Self::DEFAULT_HEARTBEAT_LIVENESS,
+        })
+    }
+
+    /// Binds the frontend, where clients connect to, to the provided endpoint.
+    pub fn bind_frontend<E>(&self, endpoint: E) -> ZmqResult<()>
+    where
+        E: TryInto<Endpoint>,
+        ZmqError: From<E::Error>,
+    {
+        self.frontend.bind(endpoint)
+    }
+
+    /// Binds the backend, where workers connect to, to the provided endpoint.
+    pub fn bind_backend<E>(&self, endpoint: E) -> ZmqResult<()>
+    where
+        E: TryInto<Endpoint>,
+        ZmqError: From<E::Error>,
+    {
+        self.backend.bind(endpoint)
+    }
+
+    /// The last endpoint the frontend was bound to, if any.
+    pub fn frontend_endpoint(&self) -> ZmqResult<Option<Endpoint>> {
+        self.frontend.last_endpoint()
+    }
+
+    /// The last endpoint the backend was bound to, if any.
+    pub fn backend_endpoint(&self) -> ZmqResult<Option<Endpoint>> {
+        self.backend.last_endpoint()
+    }
+
+    /// # Set the heartbeat interval
+    ///
+    /// Sets the interval in milliseconds between two heartbeats sent to ready workers. Intervals
+    /// that are not positive are rejected with [`InvalidArgument`].
+    ///
+    /// [`InvalidArgument`]: ZmqError::InvalidArgument
+    pub fn set_heartbeat_interval(&mut self, value: i64) -> ZmqResult<()> {
+        if value <= 0 {
+            return Err(ZmqError::InvalidArgument);
+        }
+
+        self.heartbeat_interval = value;
+        Ok(())
+    }
+
+    /// # Retrieve the heartbeat interval
+    ///
+    /// Retrieves the interval in milliseconds between two heartbeats sent to ready workers.
+    pub fn heartbeat_interval(&self) -> i64 {
+        self.heartbeat_interval
+    }
+
+    /// # Set the heartbeat liveness
+    ///
+    /// Sets the number of heartbeat intervals without any message from a worker, after which the
+    /// worker is considered dead.
+    pub fn set_heartbeat_liveness(&mut self, value: u32) {
+        self.heartbeat_liveness = value;
+    }
+
+    /// # Retrieve the heartbeat liveness
+    ///
+    /// Retrieves the number of heartbeat intervals without any message from a worker, after which
+    /// the worker is considered dead.
+    pub fn heartbeat_liveness(&self) -> u32 {
+        self.heartbeat_liveness
+    }
+
+    /// Runs the queue on the current thread, until its [`Context`] is terminated.
+    pub fn run(mut self) -> ZmqResult<()> {
+        match self.run_until(None) {
+            Err(ZmqError::ContextTerminated) => Ok(()),
+            result => result,
+        }
+    }
+
+    /// Starts the queue on a dedicated thread.
+    pub fn start(mut self) -> ZmqResult<ParanoidPirateQueueHandle> {
+        let context = self.context.clone();
+        let actor = Actor::new(&context, move |pipe| self.run_until(Some(&pipe)))?;
+
+        Ok(ParanoidPirateQueueHandle { actor })
+    }
+
+    fn run_until(&mut self, control: Option<&PairSocket>) -> ZmqResult<()> {
+        let heartbeat_interval = interval_duration(self.heartbeat_interval);
+        let mut heartbeat_at = Instant::now() + heartbeat_interval;
+        loop {
+            // only accept requests from clients while there are workers to handle them
+            let mut poller = Poller::new();
+            poller.add_socket(&self.backend, PollEvents::POLL_IN, QueueItem::Backend);
+            if !self.workers.is_empty() {
+                poller.add_socket(&self.frontend, PollEvents::POLL_IN, QueueItem::Frontend);
+            }
+            if let Some(control) = control {
+                poller.add_socket(control, PollEvents::POLL_IN, QueueItem::Control);
+            }
+
+            let timeout = heartbeat_at.saturating_duration_since(Instant::now());
+            for event in poller.wait(timeout.as_millis() as i64)? {
+                match event.into_token() {
+                    QueueItem::Control => return Ok(()),
+                    QueueItem::Backend => self.handle_backend()?,
+                    QueueItem::Frontend => self.handle_frontend()?,
+                }
+            }
+
+            if Instant::now() >= heartbeat_at {
+                self.purge_workers();
+                self.send_heartbeats()?;
+                heartbeat_at = Instant::now() + heartbeat_interval;
+            }
+        }
+    }
+
+    fn handle_backend(&mut self) -> ZmqResult<()> {
+        let mut multipart = self.backend.recv_multipart(RecvFlags::empty())?;
+        let Some(identity) = multipart.pop_front().map(RoutingId::from) else {
+            return Ok(());
+        };
+
+        // any message from a worker signals that it is alive and ready for the next request
+        self.worker_ready(identity);
+
+        if is_signal(&multipart, READY) || is_signal(&multipart, HEARTBEAT) || multipart.len() < 3 {
+            return Ok(());
+        }
+
+        self.frontend.send_multipart(multipart, SendFlags::empty())
+    }
+
+    fn handle_frontend(&mut self) -> ZmqResult<()> {
+        let mut multipart = self.frontend.recv_multipart(RecvFlags::empty())?;
+        let Some(worker) = self.workers.pop_front() else {
+            return Ok(());
+        };
+
+        multipart.push_front(Message::from(worker.identity));
+        self.backend.send_multipart(multipart, SendFlags::empty())
+    }
+
+    fn worker_ready(&mut self, identity: RoutingId) {
+        // workers with an expiry too far in the future to be represented never expire
+        let expiry = interval_duration(self.heartbeat_interval)
+            .checked_mul(self.heartbeat_liveness)
+            .and_then(|liveness| Instant::now().checked_add(liveness));
+
+        self.workers.retain(|worker| worker.identity != identity);
+        self.workers.push_back(QueueWorker { identity, expiry });
+    }
+
+    fn purge_workers(&mut self) {
+        let now = Instant::now();
+        self.workers
+            .retain(|worker| worker.expiry.is_none_or(|expiry| expiry > now));
+    }
+
+    fn send_heartbeats(&self) -> ZmqResult<()> {
+        self.workers.iter().try_for_each(|worker| {
+            let heartbeat: MultipartMessage =
+                vec![Message::from(&worker.identity), Message::from(HEARTBEAT)].into();
+            self.backend.send_multipart(heartbeat, SendFlags::empty())
+        })
+    }
+}
+
+#[derive(DebugDeriveMore)]
+#[debug("ParanoidPirateQueueHandle {{ ... }}")]
+/// # Handle of a started [`ParanoidPirateQueue`]
+///
+/// Stops the queue thread when [`stop()`]ped or dropped.
+///
+/// [`stop()`]: ParanoidPirateQueueHandle::stop
+pub struct ParanoidPirateQueueHandle {
+    actor: Actor,
+}
+
+impl ParanoidPirateQueueHandle {
+    /// Stops the queue, and waits for its thread to finish. If the queue thread panicked, the
+    /// panic is resumed on the calling thread.
+    pub fn stop(self) -> ZmqResult<()> {
+        self.actor.stop()
+    }
+}
+
+/// # Worker of a [`ParanoidPirateQueue`]
+///
+/// Signals the queue that it is ready on connecting, and sends a heartbeat every
+/// [`heartbeat_interval()`] milliseconds while waiting for requests. When the queue did not send
+/// anything for [`heartbeat_liveness()`] intervals, the worker closes its socket, dropping all
+/// messages still queued on it, and connects to the queue again. The delay before reconnecting
+/// starts at [`reconnect_interval()`] milliseconds, and doubles with every consecutive reconnect
+/// up to [`reconnect_interval_max()`] milliseconds.
+///
+/// [`heartbeat_interval()`]: #method.heartbeat_interval
+/// [`heartbeat_liveness()`]: #method.heartbeat_liveness
+/// [`reconnect_interval()`]: #method.reconnect_interval
+/// [`reconnect_interval_max()`]: #method.reconnect_interval_max
+pub struct ParanoidPirateWorker {
+    context: Context,
+    endpoint: Endpoint,
+    socket: DealerSocket,
+    heartbeat_interval: i64,
+    heartbeat_liveness: u32,
+    reconnect_interval: i64,
+    reconnect_interval_max: i64,
+    liveness: u32,
+    reconnect_delay: i64,
+    heartbeat_at: Instant,
+}
+
+impl ParanoidPirateWorker {
+    /// Default interval between two heartbeats in milliseconds
+    pub const DEFAULT_HEARTBEAT_INTERVAL: i64 = 1_000;
+    /// Default number of missed heartbeats after which the queue is considered dead
+    pub const DEFAULT_HEARTBEAT_LIVENESS: u32 = 3;
+    /// Default delay in milliseconds before the first reconnect
+    pub const DEFAULT_RECONNECT_INTERVAL: i64 = 1_000;
+    /// Default upper bound in milliseconds for the delay between reconnects
+    pub const DEFAULT_RECONNECT_INTERVAL_MAX: i64 = 32_000;
+
+    /// Creates a new worker, and signals the queue at the provided endpoint that it is ready.
+    pub fn new<E>(context: &Context, endpoint: E) -> ZmqResult<Self>
+    where
+        E: TryInto<Endpoint>,
+        ZmqError: From<E::Error>,
+    {
+        let endpoint = endpoint.try_into()?;
+        let socket = Self::connect_socket(context, &endpoint)?;
+
+        Ok(Self {
+            context: context.clone(),
+            endpoint,
+            socket,
+            heartbeat_interval: Self::DEFAULT_HEARTBEAT_INTERVAL,
+            heartbeat_liveness: Self::DEFAULT_HEARTBEAT_LIVENESS,
+            reconnect_interval: Self::DEFAULT_RECONNECT_INTERVAL,
+            reconnect_interval_max: Self::DEFAULT_RECONNECT_INTERVAL_MAX,
+            liveness: Self::DEFAULT_HEARTBEAT_LIVENESS,
+            reconnect_delay: Self::DEFAULT_RECONNECT_INTERVAL,
+            heartbeat_at: Instant::now() + interval_duration(Self::DEFAULT_HEARTBEAT_INTERVAL),
+        })
+    }
+
+    fn connect_socket(context: &Context, endpoint: &Endpoint) -> ZmqResult<DealerSocket> {
+        let socket = DealerSocket::from_context(context)?;
+        socket.set_linger(0)?;
+        socket.connect(endpoint)?;
+
+        socket.send_msg(READY, SendFlags::empty())?;
+
+        Ok(socket)
+    }
+
+    fn reconnect(&mut self) -> ZmqResult<()> {
+        thread::sleep(interval_duration(self.reconnect_delay));
+        self.reconnect_delay = self
+            .reconnect_delay
+            .saturating_mul(2)
+            .min(self.reconnect_interval_max);
+
+        self.socket = Self::connect_socket(&self.context, &self.endpoint)?;
+        self.liveness = self.heartbeat_liveness;
+        self.heartbeat_at = Instant::now() + interval_duration(self.heartbeat_interval);
+
+        Ok(())
+    }
+
+    /// The endpoint of the queue the worker is connected to.
+    pub fn endpoint(&self) -> &Endpoint {
+        &self.endpoint
+    }
+
+    /// # Set the heartbeat interval
+    ///
+    /// Sets the interval in milliseconds between two heartbeats sent to the queue. Intervals that
+    /// are not positive are rejected with [`InvalidArgument`].
+    ///
+    /// [`InvalidArgument`]: ZmqError::InvalidArgument
+    pub fn set_heartbeat_interval(&mut self, value: i64) -> ZmqResult<()> {
+        if value <= 0 {
+            return Err(ZmqError::InvalidArgument);
+        }
+
+        self.heartbeat_interval = value;
+        self.heartbeat_at = Instant::now() + interval_duration(value);
+        Ok(())
+    }
+
+    /// # Retrieve the heartbeat interval
+    ///
+    /// Retrieves the interval in milliseconds between two heartbeats sent to the queue.
+    pub fn heartbeat_interval(&self) -> i64 {
+        self.heartbeat_interval
+    }
+
+    /// # Set the heartbeat liveness
+    ///
+    /// Sets the number of heartbeat intervals without any message from the queue, after which
+    /// the worker reconnects.
+    pub fn set_heartbeat_liveness(&mut self, value: u32) {
+        self.heartbeat_liveness = value;
+        self.liveness = value;
+    }
+
+    /// # Retrieve the heartbeat liveness
+    ///
+    /// Retrieves the number of heartbeat intervals without any message from the queue, after
+    /// which the worker reconnects.
+    pub fn heartbeat_liveness(&self) -> u32 {
+        self.heartbeat_liveness
+    }
+
+    /// # Set the reconnect interval
+    ///
+    /// Sets the delay in milliseconds before the first reconnect to an unresponsive queue.
+    pub fn set_reconnect_interval(&mut self, value: i64) {
+        self.reconnect_interval = value;
+        self.reconnect_delay = value;
+    }
+
+    /// # Retrieve the reconnect interval
+    ///
+    /// Retrieves the delay in milliseconds before the first reconnect to an unresponsive queue.
+    pub fn reconnect_interval(&self) -> i64 {
+        self.reconnect_interval
+    }
+
+    /// # Set the maximum reconnect interval
+    ///
+    /// Sets the upper bound in milliseconds for the delay between consecutive reconnects.
+    pub fn set_reconnect_interval_max(&mut self, value: i64) {
+        self.reconnect_interval_max = value;
+    }
+
+    /// # Retrieve the maximum reconnect interval
+    ///
+    /// Retrieves the upper bound in milliseconds for the delay between consecutive reconnects.
+    pub fn reconnect_interval_max(&self) -> i64 {
+        self.reconnect_interval_max
+    }
+
+    /// # Receive the next request
+    ///
+    /// Blocks the current thread until the queue hands over the next request, while heartbeating,
+    /// and reconnecting to the queue as necessary. The request starts with the envelope of the
+    /// requesting client, which has to be kept in the reply passed to [`send_reply()`].
+    ///
+    /// [`send_reply()`]: #method.send_reply
+    pub fn recv_request(&mut self) -> ZmqResult<MultipartMessage> {
+        loop {
+            if self
+                .socket
+                .poll(PollEvents::POLL_IN, self.heartbeat_interval)?
+                .contains(PollEvents::POLL_IN)
+            {
+                let multipart = self.socket.recv_multipart(RecvFlags::empty())?;
+                if multipart.len() >= 3 {
+                    self.liveness = self.heartbeat_liveness;
+                    self.reconnect_delay = self.reconnect_interval;
+                    return Ok(multipart);
+                }
+
+                if is_signal(&multipart, HEARTBEAT) {
+                    self.liveness = self.heartbeat_liveness;
+                    self.reconnect_delay = self.reconnect_interval;
+                }
+            } else {
+                self.liveness = self.liveness.saturating_sub(1);
+                if self.liveness == 0 {
+                    self.reconnect()?;
+                }
+            }
+
+            if Instant::now() >= self.heartbeat_at {
+                self.socket.send_msg(HEARTBEAT, SendFlags::empty())?;
+                self.heartbeat_at = Instant::now() + interval_duration(self.heartbeat_interval);
+            }
+        }
+    }
+
+    /// # Send a reply
+    ///
+    /// Sends the reply to a received request back to the queue. The reply has to start with the
+    /// envelope of the request.
+    pub fn send_reply<M>(&self, reply: M) -> ZmqResult<()>
+    where
+        M: Into<MultipartMessage>,
+    {
+        self.socket.send_multipart(reply.into(), SendFlags::empty())
+    }
+}
+
+#[cfg(feature = "builder")]
+pub(crate) mod builder {
+    use derive_builder::Builder;
+    use serde::{Deserialize, Serialize};
+
+    use super::{ParanoidPirateQueue, ParanoidPirateWorker};
+    use crate::{ZmqError, ZmqResult, context::Context, endpoint::Endpoint};
+
+    #[derive(Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Builder)]
+    #[builder(
+        pattern = "owned",
+        name = "ParanoidPirateQueueBuilder",
+        public,
+        build_fn(skip, error = "ZmqError"),
+        derive(PartialEq, Eq, Hash, Clone, serde::Serialize, serde::Deserialize)
+    )]
+    #[builder_struct_attr(doc = "Builder for [`ParanoidPirateQueue`].\n\n")]
+    #[allow(dead_code)]
+    struct ParanoidPirateQueueConfig {
+        #[builder(default = "ParanoidPirateQueue::DEFAULT_HEARTBEAT_INTERVAL")]
+        heartbeat_interval: i64,
+        #[builder(default = "ParanoidPirateQueue::DEFAULT_HEARTBEAT_LIVENESS")]
+        heartbeat_liveness: u32,
+    }
+
+    impl ParanoidPirateQueueBuilder {
+        /// Applies this builder to the provided queue
+        pub fn apply(self, queue: &mut ParanoidPirateQueue) -> ZmqResult<()> {
+            if let Some(heartbeat_interval) = self.heartbeat_interval {
+                queue.set_heartbeat_interval(heartbeat_interval)?;
+            }
+
+            if let Some(heartbeat_liveness) = self.heartbeat_liveness {
+                queue.set_heartbeat_liveness(heartbeat_liveness);
+            }
+
+            Ok(())
+        }
+
+        /// Builds a new queue and applies this builder to it.
+        pub fn build_from_context(self, context: &Context) -> ZmqResult<ParanoidPirateQueue> {
+            let mut queue = ParanoidPirateQueue::new(context)?;
+
+            self.apply(&mut queue)?;
+
+            Ok(queue)
+        }
+    }
+
+    #[derive(Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Builder)]
+    #[builder(
+        pattern = "owned",
+        name = "ParanoidPirateWorkerBuilder",
+        public,
+        build_fn(skip, error = "ZmqError"),
+        derive(PartialEq, Eq, Hash, Clone, serde::Serialize, serde::Deserialize)
+    )]
+    #[builder_struct_attr(doc = "Builder for [`ParanoidPirateWorker`].\n\n")]
+    #[allow(dead_code)]
+    struct ParanoidPirateWorkerConfig {
+        #[builder(default = "ParanoidPirateWorker::DEFAULT_HEARTBEAT_INTERVAL")]
+        heartbeat_interval: i64,
+        #[builder(default = "ParanoidPirateWorker::DEFAULT_HEARTBEAT_LIVENESS")]
+        heartbeat_liveness: u32,
+        #[builder(default = "ParanoidPirateWorker::DEFAULT_RECONNECT_INTERVAL")]
+        reconnect_interval: i64,
+        #[builder(default = "ParanoidPirateWorker::DEFAULT_RECONNECT_INTERVAL_MAX")]
+        reconnect_interval_max: i64,
+    }
+
+    impl ParanoidPirateWorkerBuilder {
+        /// Applies this builder to the provided worker
+        pub fn apply(self, worker: &mut ParanoidPirateWorker) -> ZmqResult<()> {
+            if let Some(heartbeat_interval) = self.heartbeat_interval {
+                worker.set_heartbeat_interval(heartbeat_interval)?;
+            }
+
+            if let Some(heartbeat_liveness) = self.heartbeat_liveness {
+                worker.set_heartbeat_liveness(heartbeat_liveness);
+            }
+
+            if let Some(reconnect_interval) = self.reconnect_interval {
+                worker.set_reconnect_interval(reconnect_interval);
+            }
+
+            if let Some(reconnect_interval_max) = self.reconnect_interval_max {
+                worker.set_reconnect_interval_max(reconnect_interval_max);
+            }
+
+            Ok(())
+        }
+
+        /// Builds a new worker connected to the queue at the provided endpoint, and applies this
+        /// builder to it.
+        pub fn build_from_context<E>(
+            self,
+            context: &Context,
+            endpoint: E,
+        ) -> ZmqResult<ParanoidPirateWorker>
+        where
+            E: TryInto<Endpoint>,
+            ZmqError: From<E::Error>,
+        {
+            let mut worker = ParanoidPirateWorker::new(context, endpoint)?;
+
+            self.apply(&mut worker)?;
+
+            Ok(worker)
+        }
+    }
+
+    #[cfg(test)]
+    mod paranoid_pirate_builder_tests {
+        use super::{ParanoidPirateQueueBuilder, ParanoidPirateWorkerBuilder};
+        use crate::{
+            ZmqError, ZmqResult,
+            context::Context,
+            patterns::{ParanoidPirateQueue, ParanoidPirateWorker},
+        };
+
+        #[test]
+        fn default_queue_builder() -> ZmqResult<()> {
+            let context = Context::new()?;
+
+            let queue = ParanoidPirateQueueBuilder::default().build_from_context(&context)?;
+
+            assert_eq!(
+                queue.heartbeat_interval(),
+                ParanoidPirateQueue::DEFAULT_HEARTBEAT_INTERVAL
+            );
+            assert_eq!(
+                queue.heartbeat_liveness(),
+                ParanoidPirateQueue::DEFAULT_HEARTBEAT_LIVENESS
+            );
+
+            Ok(())
+        }
+
+        #[test]
+        fn queue_builder_with_custom_values() -> ZmqResult<()> {
+            let context = Context::new()?;
+
+            let queue = ParanoidPirateQueueBuilder::default()
+                .heartbeat_interval(100)
+                .heartbeat_liveness(5)
+                .build_from_context(&context)?;
+
+            assert_eq!(queue.heartbeat_interval(), 100);
+            assert_eq!(queue.heartbeat_liveness(), 5);
+
+            Ok(())
+        }
+
+        #[test]
+        fn default_worker_builder() -> ZmqResult<()> {
+            let context = Context::new()?;
+
+            let worker = ParanoidPirateWorkerBuilder::default()
+                .build_from_context(&context, "inproc://paranoid-pirate-worker-builder")?;
+
+            assert_eq!(
+                worker.heartbeat_interval(),
+                ParanoidPirateWorker::DEFAULT_HEARTBEAT_INTERVAL
+            );
+            assert_eq!(
+                worker.heartbeat_liveness(),
+                ParanoidPirateWorker::DEFAULT_HEARTBEAT_LIVENESS
+            );
+            assert_eq!(
+                worker.reconnect_interval(),
+                ParanoidPirateWorker::DEFAULT_RECONNECT_INTERVAL
+            );
+            assert_eq!(
+                worker.reconnect_interval_max(),
+                ParanoidPirateWorker::DEFAULT_RECONNECT_INTERVAL_MAX
+            );
+
+            Ok(())
+        }
+
+        #[test]
+        fn worker_builder_with_custom_values() -> ZmqResult<()> {
+            let context = Context::new()?;
+
+            let worker = ParanoidPirateWorkerBuilder::default()
+                .heartbeat_interval(100)
+                .heartbeat_liveness(5)
+                .reconnect_interval(200)
+                .reconnect_interval_max(400)
+                .build_from_context(&context, "inproc://paranoid-pirate-worker-builder")?;
+
+            assert_eq!(worker.heartbeat_interval(), 100);
+            assert_eq!(worker.heartbeat_liveness(), 5);
+            assert_eq!(worker.reconnect_interval(), 200);
+            assert_eq!(worker.reconnect_interval_max(), 400);
+
+            Ok(())
+        }
+
+        #[test]
+        fn builders_reject_non_positive_heartbeat_interval() -> ZmqResult<()> {
+            let context = Context::new()?;
+
+            let result = ParanoidPirateQueueBuilder::default()
+                .heartbeat_interval(0)
+                .build_from_context(&context);
+            assert!(result.is_err_and(|err| err == ZmqError::InvalidArgument));
+
+            let result = ParanoidPirateWorkerBuilder::default()
+                .heartbeat_interval(-1)
+                .build_from_context(&context, "inproc://paranoid-pirate-worker-builder");
+            assert!(result.is_err_and(|err| err == ZmqError::InvalidArgument));
+
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod paranoid_pirate_tests {
+    use core::time::Duration;
+    use std::thread;
+
+    use super::{HEARTBEAT, ParanoidPirateQueue, ParanoidPirateWorker, READY};
+    use crate::{
+        ZmqError, ZmqResult,
+        context::Context,
+        message::{Message, RoutingId},
+        patterns::ReliableRequestClient,
+        socket::{
+            DealerSocket, MultipartReceiver, Receiver, RecvFlags, RouterSocket, SendFlags, Sender,
+        },
+    };
+
+    fn spawn_echo_worker(context: &Context, endpoint: &str) -> ZmqResult<()> {
+        let mut worker = ParanoidPirateWorker::new(context, endpoint)?;
+        worker.set_heartbeat_interval(50)?;
+
+        thread::spawn(move || {
+            while let Ok(request) = worker.recv_request() {
+                if worker.send_reply(request).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Ok(())
+    }
+
+    #[test]
+    fn queue_setters_set_values() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let mut queue = ParanoidPirateQueue::new(&context)?;
+        assert_eq!(
+            queue.heartbeat_interval(),
+            ParanoidPirateQueue::DEFAULT_HEARTBEAT_INTERVAL
+        );
+        assert_eq!(
+            queue.heartbeat_liveness(),
+            ParanoidPirateQueue::DEFAULT_HEARTBEAT_LIVENESS
+        );
+
+        queue.set_heartbeat_interval(100)?;
+        queue.set_heartbeat_liveness(5);
+
+        assert_eq!(queue.heartbeat_interval(), 100);
+        assert_eq!(queue.heartbeat_liveness(), 5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_heartbeat_interval_rejects_non_positive_intervals() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let mut queue = ParanoidPirateQueue::new(&context)?;
+        for interval in [0, -1] {
+            let result = queue.set_heartbeat_interval(interval);
+            assert!(result.is_err_and(|err| err == ZmqError::InvalidArgument));
+        }
+        assert_eq!(
+            queue.heartbeat_interval(),
+            ParanoidPirateQueue::DEFAULT_HEARTBEAT_INTERVAL
+        );
+
+        let mut worker = ParanoidPirateWorker::new(&context, "inproc://paranoid-pirate-interval")?;
+        for interval in [0, -1] {
+            let result = worker.set_heartbeat_interval(interval);
+            assert!(result.is_err_and(|err| err == ZmqError::InvalidArgument));
+        }
+        assert_eq!(
+            worker.heartbeat_interval(),
+            ParanoidPirateWorker::DEFAULT_HEARTBEAT_INTERVAL
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn worker_ready_does_not_overflow_expiry() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let mut queue = ParanoidPirateQueue::new(&context)?;
+        queue.set_heartbeat_interval(i64::MAX)?;
+        queue.set_heartbeat_liveness(u32::MAX);
+
+        queue.worker_ready(RoutingId::from("worker"));
+        queue.purge_workers();
+
+        assert_eq!(queue.workers.len(), 1);
+        assert!(queue.workers[0].expiry.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn worker_setters_set_values() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let mut worker = ParanoidPirateWorker::new(&context, "inproc://paranoid-pirate-setters")?;
+        assert_eq!(
+            worker.endpoint().to_string(),
+            "inproc://paranoid-pirate-setters"
+        );
+
+        worker.set_heartbeat_interval(100)?;
+        worker.set_heartbeat_liveness(5);
+        worker.set_reconnect_interval(200);
+        worker.set_reconnect_interval_max(400);
+
+        assert_eq!(worker.heartbeat_interval(), 100);
+        assert_eq!(worker.heartbeat_liveness(), 5);
+        assert_eq!(worker.reconnect_interval(), 200);
+        assert_eq!(worker.reconnect_interval_max(), 400);
+
+        Ok(())
+    }
+
+    #[test]
+    fn requests_are_balanced_over_workers() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let queue = ParanoidPirateQueue::new(&context)?;
+        queue.bind_frontend("tcp://127.0.0.1:*")?;
+        queue.bind_backend("tcp://127.0.0.1:*")?;
+        let frontend = queue.frontend_endpoint()?.unwrap();
+        let backend = queue.backend_endpoint()?.unwrap().to_string();
+        let queue = queue.start()?;
+
+        spawn_echo_worker(&context, &backend)?;
+        spawn_echo_worker(&context, &backend)?;
+
+        let mut client = ReliableRequestClient::new(&context, frontend)?;
+        for request in ["Hello", "World", "!"] {
+            let reply = client.request(Message::from(request))?;
+            assert_eq!(reply.get(0).unwrap().to_string(), request);
+        }
+
+        queue.stop()
+    }
+
+    #[test]
+    fn queue_sends_heartbeats_to_ready_workers() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let mut queue = ParanoidPirateQueue::new(&context)?;
+        queue.set_heartbeat_interval(10)?;
+        queue.bind_backend("inproc://paranoid-pirate-queue-heartbeats")?;
+        let queue = queue.start()?;
+
+        let worker = DealerSocket::from_context(&context)?;
+        worker.connect("inproc://paranoid-pirate-queue-heartbeats")?;
+        worker.send_msg(READY, SendFlags::empty())?;
+
+        let heartbeat = worker.recv_msg(RecvFlags::empty())?;
+        assert_eq!(heartbeat.as_slice(), HEARTBEAT);
+
+        queue.stop()
+    }
+
+    #[test]
+    fn queue_drops_dead_workers() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let mut queue = ParanoidPirateQueue::new(&context)?;
+        queue.set_heartbeat_interval(10)?;
+        queue.set_heartbeat_liveness(1);
+        queue.bind_frontend("inproc://paranoid-pirate-dead-frontend")?;
+        queue.bind_backend("inproc://paranoid-pirate-dead-backend")?;
+        let queue = queue.start()?;
+
+        let dead_worker = DealerSocket::from_context(&context)?;
+        dead_worker.connect("inproc://paranoid-pirate-dead-backend")?;
+        dead_worker.send_msg(READY, SendFlags::empty())?;
+
+        thread::sleep(Duration::from_millis(100));
+        spawn_echo_worker(&context, "inproc://paranoid-pirate-dead-backend")?;
+
+        let mut client =
+            ReliableRequestClient::new(&context, "inproc://paranoid-pirate-dead-frontend")?;
+        client.set_retries(0);
+        let reply = client.request(Message::from("Hello"))?;
+        assert_eq!(reply.get(0).unwrap().to_string(), "Hello");
+
+        queue.stop()
+    }
+
+    #[test]
+    fn worker_reconnects_to_silent_queue() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let queue = RouterSocket::from_context(&context)?;
+        queue.bind("inproc://paranoid-pirate-worker-reconnect")?;
+
+        let mut worker =
+            ParanoidPirateWorker::new(&context, "inproc://paranoid-pirate-worker-reconnect")?;
+        worker.set_heartbeat_interval(10)?;
+        worker.set_heartbeat_liveness(1);
+        worker.set_reconnect_interval(10);
+
+        let handle = thread::spawn(move || worker.recv_request());
+
+        let ready = queue.recv_multipart(RecvFlags::empty())?;
+        let first = RoutingId::from(ready.get(0).unwrap());
+        assert_eq!(ready.get(1).unwrap().as_slice(), READY);
+
+        // the worker registers again on a new connection
+        loop {
+            let message = queue.recv_multipart(RecvFlags::empty())?;
+            if RoutingId::from(message.get(0).unwrap()) != first {
+                assert_eq!(message.get(1).unwrap().as_slice(), READY);
+                break;
+            }
+        }
+
+        context.shutdown()?;
+        assert!(
+            handle
+                .join()
+                .unwrap()
+                .is_err_and(|err| err == ZmqError::ContextTerminated)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn worker_reconnects_with_exponential_backoff() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let mut worker = ParanoidPirateWorker::new(&context, "inproc://paranoid-pirate-backoff")?;
+        worker.set_reconnect_interval(1);
+        worker.set_reconnect_interval_max(3);
+
+        let mut delays = vec![];
+        for _ in 0..3 {
+            worker.reconnect()?;
+            delays.push(worker.reconnect_delay);
+        }
+        assert_eq!(delays, vec![2, 3, 3]);
+
+        Ok(())
+    }
+}
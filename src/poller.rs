@@ -0,0 +1,346 @@
+//! # 0MQ input/output multiplexing
+//!
+//! A [`Poller`] waits for events on multiple sockets of different types, and standard file
+//! descriptors at once. Each registered item carries its own [`PollEvents`] and a user-provided
+//! token, which is handed back in the [`PollerEvent`]s returned by [`wait()`].
+//!
+//! ```
+//! # use arzmq::prelude::{
+//! #     Context, DealerSocket, PollEvents, Poller, RouterSocket, SendFlags, Sender, ZmqResult,
+//! # };
+//! #
+//! #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+//! enum Side {
+//!     Frontend,
+//!     Backend,
+//! }
+//!
+//! fn main() -> ZmqResult<()> {
+//!     let context = Context::new()?;
+//!
+//!     let frontend = RouterSocket::from_context(&context)?;
+//!     frontend.bind("inproc://poller-frontend")?;
+//!
+//!     let backend = DealerSocket::from_context(&context)?;
+//!     backend.bind("inproc://poller-backend")?;
+//!
+//!     let worker = DealerSocket::from_context(&context)?;
+//!     worker.connect("inproc://poller-backend")?;
+//!     worker.send_msg("ready", SendFlags::empty())?;
+//!
+//!     let mut poller = Poller::new();
+//!     poller.add_socket(&frontend, PollEvents::POLL_IN, Side::Frontend);
+//!     poller.add_socket(&backend, PollEvents::POLL_IN, Side::Backend);
+//!
+//!     let events = poller.wait(-1)?;
+//!     assert_eq!(events.len(), 1);
+//!     assert_eq!(events[0].token(), &Side::Backend);
+//!     assert!(events[0].events().contains(PollEvents::POLL_IN));
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! [`wait()`]: Poller::wait
+use alloc::sync::Arc;
+
+use derive_more::Debug as DebugDeriveMore;
+
+use crate::{
+    ZmqError, ZmqResult,
+    ffi::{RawPollItems, RawSocket},
+    sealed,
+    socket::{PollEvents, Socket},
+    zmq_sys_crate::RawFd,
+};
+
+#[derive(DebugDeriveMore)]
+#[debug("Poller {{ tokens: {tokens:?} }}")]
+/// # 0MQ poller for multiple sockets and file descriptors
+///
+/// Registered sockets are kept alive by the poller until they are removed again, or the poller
+/// is dropped.
+pub struct Poller<T> {
+    items: RawPollItems,
+    sockets: Vec<Option<Arc<RawSocket>>>,
+    tokens: Vec<T>,
+}
+
+impl<T> Poller<T> {
+    /// Creates an empty poller
+    pub fn new() -> Self {
+        Self {
+            items: RawPollItems::default(),
+            sockets: Vec::new(),
+            tokens: Vec::new(),
+        }
+    }
+
+    /// Registers a socket of any type for the provided `events`. The `token` is returned with every
+    /// event on this socket.
+    pub fn add_socket<S, E>(&mut self, socket: &Socket<S>, events: E, token: T)
+    where
+        S: sealed::SocketType,
+        E: Into<PollEvents>,
+    {
+        self.items.push_socket(&socket.socket, events.into());
+        self.sockets.push(Some(socket.socket.clone()));
+        self.tokens.push(token);
+    }
+
+    /// Registers a standard file descriptor for the provided `events`. The `token` is returned with
+    /// every event on this file descriptor. [`POLL_ERR`] is reported for error conditions on the
+    /// file descriptor.
+    ///
+    /// [`POLL_ERR`]: PollEvents::POLL_ERR
+    pub fn add_fd<E>(&mut self, fd: RawFd, events: E, token: T)
+    where
+        E: Into<PollEvents>,
+    {
+        self.items.push_fd(fd, events.into());
+        self.sockets.push(None);
+        self.tokens.push(token);
+    }
+
+    /// Removes the first item registered with the provided `token`, and returns whether any item
+    /// was removed.
+    pub fn remove(&mut self, token: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        match self
+            .tokens
+            .iter()
+            .position(|registered| registered == token)
+        {
+            None => false,
+            Some(index) => {
+                self.items.remove(index);
+                self.sockets.remove(index);
+                self.tokens.remove(index);
+                true
+            }
+        }
+    }
+
+    /// Number of registered items
+    pub fn len(&self) -> usize {
+        self.tokens.len()
+    }
+
+    /// Whether no items are registered
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    /// # wait for events on the registered items
+    ///
+    /// Waits up to `timeout_ms` milliseconds for any of the requested events on the registered
+    /// items. A timeout of `0` returns immediately, whereas `-1` blocks indefinitely until an event
+    /// occurs. The returned vector contains one entry for every item with events, in registration
+    /// order, and is empty if the timeout expired.
+    ///
+    /// Waiting on a poller without any registered items fails with [`InvalidArgument`], as there
+    /// are no events that could end the wait.
+    ///
+    /// [`InvalidArgument`]: ZmqError::InvalidArgument
+    pub fn wait(&mut self, timeout_ms: i64) -> ZmqResult<Vec<PollerEvent<T>>>
+    where
+        T: Clone,
+    {
+        if self.is_empty() {
+            return Err(ZmqError::InvalidArgument);
+        }
+
+        if self.items.poll(timeout_ms)? == 0 {
+            return Ok(Vec::new());
+        }
+
+        Ok(self
+            .tokens
+            .iter()
+            .enumerate()
+            .filter_map(|(index, token)| {
+                let events = self.items.revents(index);
+                (!events.is_empty()).then(|| PollerEvent {
+                    token: token.clone(),
+                    events,
+                })
+            })
+            .collect())
+    }
+}
+
+impl<T> Default for Poller<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+unsafe impl<T: Send> Send for Poller<T> {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Events that occured on an item registered with a [`Poller`]
+pub struct PollerEvent<T> {
+    token: T,
+    events: PollEvents,
+}
+
+impl<T> PollerEvent<T> {
+    /// Token the item was registered with
+    pub fn token(&self) -> &T {
+        &self.token
+    }
+
+    /// Events that occured on the item
+    pub fn events(&self) -> PollEvents {
+        self.events
+    }
+
+    /// Consumes the event, and returns the token the item was registered with
+    pub fn into_token(self) -> T {
+        self.token
+    }
+}
+
+#[cfg(test)]
+mod poller_tests {
+    use super::Poller;
+    use crate::prelude::{
+        Context, DealerSocket, MultipartReceiver, PairSocket, PollEvents, Receiver, RecvFlags,
+        RouterSocket, SendFlags, Sender, ZmqError, ZmqResult,
+    };
+
+    #[test]
+    fn wait_times_out_without_events() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let socket = PairSocket::from_context(&context)?;
+
+        let mut poller = Poller::new();
+        poller.add_socket(&socket, PollEvents::POLL_IN, 0);
+
+        assert!(poller.wait(0)?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn wait_returns_events_of_mixed_sockets() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let frontend = RouterSocket::from_context(&context)?;
+        frontend.bind("inproc://poller-mixed-frontend")?;
+
+        let backend = DealerSocket::from_context(&context)?;
+        backend.bind("inproc://poller-mixed-backend")?;
+
+        let client = DealerSocket::from_context(&context)?;
+        client.connect("inproc://poller-mixed-frontend")?;
+
+        let worker = DealerSocket::from_context(&context)?;
+        worker.connect("inproc://poller-mixed-backend")?;
+
+        let mut poller = Poller::new();
+        poller.add_socket(&frontend, PollEvents::POLL_IN, "frontend");
+        poller.add_socket(&backend, PollEvents::POLL_IN, "backend");
+
+        client.send_msg("Hello", SendFlags::empty())?;
+        worker.send_msg("World", SendFlags::empty())?;
+
+        let mut ready = Vec::new();
+        while ready.len() < 2 {
+            for event in poller.wait(-1)? {
+                assert_eq!(event.events(), PollEvents::POLL_IN);
+                match event.into_token() {
+                    "frontend" => {
+                        frontend.recv_multipart(RecvFlags::empty())?;
+                        ready.push("frontend");
+                    }
+                    "backend" => {
+                        backend.recv_msg(RecvFlags::empty())?;
+                        ready.push("backend");
+                    }
+                    _ => unreachable!(),
+                }
+            }
+        }
+
+        ready.sort();
+        assert_eq!(ready, vec!["backend", "frontend"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn wait_reports_requested_events_only() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let pair_server = PairSocket::from_context(&context)?;
+        pair_server.bind("inproc://poller-requested-events")?;
+
+        let pair_client = PairSocket::from_context(&context)?;
+        pair_client.connect("inproc://poller-requested-events")?;
+
+        let mut poller = Poller::new();
+        poller.add_socket(&pair_client, PollEvents::POLL_OUT, 1);
+        poller.add_socket(&pair_server, PollEvents::POLL_IN, 2);
+
+        let events = poller.wait(0)?;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].token(), &1);
+        assert_eq!(events[0].events(), PollEvents::POLL_OUT);
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn wait_returns_events_on_file_descriptors() -> ZmqResult<()> {
+        use std::{io::Write, os::fd::AsRawFd, os::unix::net::UnixStream};
+
+        let (mut writer, reader) = UnixStream::pair().unwrap();
+
+        let mut poller = Poller::new();
+        poller.add_fd(reader.as_raw_fd(), PollEvents::POLL_IN, "reader");
+
+        assert!(poller.wait(0)?.is_empty());
+
+        writer.write_all(b"Hello").unwrap();
+
+        let events = poller.wait(-1)?;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].token(), &"reader");
+
+        Ok(())
+    }
+
+    #[test]
+    fn remove_unregisters_item() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let socket = PairSocket::from_context(&context)?;
+
+        let mut poller = Poller::new();
+        poller.add_socket(&socket, PollEvents::POLL_OUT, 0);
+        poller.add_socket(&socket, PollEvents::POLL_IN, 1);
+        assert_eq!(poller.len(), 2);
+
+        assert!(poller.remove(&0));
+        assert!(!poller.remove(&0));
+        assert_eq!(poller.len(), 1);
+
+        assert!(poller.remove(&1));
+        assert!(poller.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn wait_without_items_fails() {
+        let mut poller: Poller<()> = Poller::new();
+
+        let result = poller.wait(-1);
+        assert!(result.is_err_and(|err| err == ZmqError::InvalidArgument));
+    }
+}
@@ -0,0 +1,373 @@
+//! # Event-driven reactor
+//!
+//! A [`Reactor`] runs the event loop of a blocking service: it waits for events on registered
+//! sockets and file descriptors, fires timers scheduled with the `zmq_timers_*` API of 0MQ, and
+//! invokes the callbacks registered for them. Every callback returns a [`ControlFlow`], and the
+//! reactor runs until a callback returns [`ControlFlow::Break`], or fails with an error.
+//!
+//! Callbacks may borrow from their environment, e.g. the sockets they are registered for, and
+//! timers can be cancelled through their [`TimerHandle`]s, also from within callbacks.
+//!
+//! ```
+//! # use core::ops::ControlFlow;
+//! # use std::cell::Cell;
+//! #
+//! # use arzmq::{
+//! #     prelude::{
+//! #         Context, PairSocket, PollEvents, Receiver, RecvFlags, SendFlags, Sender, ZmqResult,
+//! #     },
+//! #     reactor::Reactor,
+//! # };
+//! #
+//! fn main() -> ZmqResult<()> {
+//!     let context = Context::new()?;
+//!
+//!     let receiver = PairSocket::from_context(&context)?;
+//!     receiver.bind("inproc://reactor-example")?;
+//!
+//!     let sender = PairSocket::from_context(&context)?;
+//!     sender.connect("inproc://reactor-example")?;
+//!
+//!     let received = Cell::new(0);
+//!
+//!     let mut reactor = Reactor::new()?;
+//!     reactor.add_repeating_timer(10, || {
+//!         sender.send_msg("tick", SendFlags::empty())?;
+//!         Ok(ControlFlow::Continue(()))
+//!     })?;
+//!     reactor.add_socket(&receiver, PollEvents::POLL_IN, |_events| {
+//!         receiver.recv_msg(RecvFlags::empty())?;
+//!         received.set(received.get() + 1);
+//!
+//!         if received.get() == 3 {
+//!             return Ok(ControlFlow::Break(()));
+//!         }
+//!         Ok(ControlFlow::Continue(()))
+//!     });
+//!
+//!     reactor.run()?;
+//!     assert_eq!(received.get(), 3);
+//!
+//!     Ok(())
+//! }
+//! ```
+use alloc::sync::Arc;
+use core::{
+    ops::ControlFlow,
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+use std::{collections::HashMap, thread};
+
+use derive_more::Debug as DebugDeriveMore;
+
+use crate::{
+    ZmqError, ZmqResult,
+    ffi::RawTimers,
+    poller::Poller,
+    sealed,
+    socket::{PollEvents, Socket},
+    zmq_sys_crate::RawFd,
+};
+
+type ItemCallback<'a> = Box<dyn FnMut(PollEvents) -> ZmqResult<ControlFlow<()>> + 'a>;
+type TimerCallback<'a> = Box<dyn FnMut() -> ZmqResult<ControlFlow<()>> + 'a>;
+
+struct ReactorTimer<'a> {
+    callback: TimerCallback<'a>,
+    repeating: bool,
+    cancelled: Arc<AtomicBool>,
+}
+
+#[derive(Debug, Clone)]
+/// # Cancel handle of a timer
+///
+/// Cancels the timer it was returned for by [`add_timer()`] or [`add_repeating_timer()`]. Handles
+/// can be cloned, and moved into callbacks of the reactor.
+///
+/// [`add_timer()`]: Reactor::add_timer
+/// [`add_repeating_timer()`]: Reactor::add_repeating_timer
+pub struct TimerHandle {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl TimerHandle {
+    /// Cancels the timer, so that its callback is not invoked anymore.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Release);
+    }
+
+    /// Whether the timer was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Acquire)
+    }
+}
+
+#[derive(DebugDeriveMore)]
+#[debug("Reactor {{ ... }}")]
+/// # Event loop with socket, file descriptor, and timer callbacks
+///
+/// See the [module-level documentation](self) for an example.
+pub struct Reactor<'a> {
+    poller: Poller<usize>,
+    items: Vec<ItemCallback<'a>>,
+    timers: RawTimers,
+    timer_callbacks: HashMap<i32, ReactorTimer<'a>>,
+}
+
+impl<'a> Reactor<'a> {
+    /// Creates a new reactor without any registered items or timers.
+    pub fn new() -> ZmqResult<Self> {
+        Ok(Self {
+            poller: Poller::new(),
+            items: Vec::new(),
+            timers: RawTimers::new()?,
+            timer_callbacks: HashMap::new(),
+        })
+    }
+
+    /// Registers a socket of any type. The `callback` is invoked with the occured events whenever
+    /// any of the provided `events` occur on the socket.
+    pub fn add_socket<S, E, F>(&mut self, socket: &Socket<S>, events: E, callback: F)
+    where
+        S: sealed::SocketType,
+        E: Into<PollEvents>,
+        F: FnMut(PollEvents) -> ZmqResult<ControlFlow<()>> + 'a,
+    {
+        self.poller.add_socket(socket, events, self.items.len());
+        self.items.push(Box::new(callback));
+    }
+
+    /// Registers a standard file descriptor. The `callback` is invoked with the occured events
+    /// whenever any of the provided `events` occur on the file descriptor.
+    pub fn add_fd<E, F>(&mut self, fd: RawFd, events: E, callback: F)
+    where
+        E: Into<PollEvents>,
+        F: FnMut(PollEvents) -> ZmqResult<ControlFlow<()>> + 'a,
+    {
+        self.poller.add_fd(fd, events, self.items.len());
+        self.items.push(Box::new(callback));
+    }
+
+    /// Adds a one-shot timer, whose `callback` is invoked once after `delay` milliseconds.
+    pub fn add_timer<F>(&mut self, delay: i64, callback: F) -> ZmqResult<TimerHandle>
+    where
+        F: FnMut() -> ZmqResult<ControlFlow<()>> + 'a,
+    {
+        self.insert_timer(delay, Box::new(callback), false)
+    }
+
+    /// Adds a repeating timer, whose `callback` is invoked every `interval` milliseconds, until
+    /// the timer is cancelled.
+    pub fn add_repeating_timer<F>(&mut self, interval: i64, callback: F) -> ZmqResult<TimerHandle>
+    where
+        F: FnMut() -> ZmqResult<ControlFlow<()>> + 'a,
+    {
+        self.insert_timer(interval, Box::new(callback), true)
+    }
+
+    fn insert_timer(
+        &mut self,
+        interval: i64,
+        callback: TimerCallback<'a>,
+        repeating: bool,
+    ) -> ZmqResult<TimerHandle> {
+        let interval = usize::try_from(interval).map_err(|_err| ZmqError::InvalidArgument)?;
+        let timer_id = self.timers.add(interval)?;
+
+        let cancelled = Arc::new(AtomicBool::new(false));
+        self.timer_callbacks.insert(
+            timer_id,
+            ReactorTimer {
+                callback,
+                repeating,
+                cancelled: cancelled.clone(),
+            },
+        );
+
+        Ok(TimerHandle { cancelled })
+    }
+
+    fn remove_cancelled_timers(&mut self) -> ZmqResult<()> {
+        let cancelled: Vec<_> = self
+            .timer_callbacks
+            .iter()
+            .filter(|(_timer_id, timer)| timer.cancelled.load(Ordering::Acquire))
+            .map(|(timer_id, _timer)| *timer_id)
+            .collect();
+
+        cancelled.into_iter().try_for_each(|timer_id| {
+            self.timer_callbacks.remove(&timer_id);
+            self.timers.cancel(timer_id)
+        })
+    }
+
+    /// # Run the event loop
+    ///
+    /// Invokes the callbacks of the registered items and timers, until a callback returns
+    /// [`ControlFlow::Break`] or an error, which is returned. Returns right away when neither items
+    /// nor timers are registered.
+    pub fn run(&mut self) -> ZmqResult<()> {
+        loop {
+            self.remove_cancelled_timers()?;
+            if self.poller.is_empty() && self.timer_callbacks.is_empty() {
+                return Ok(());
+            }
+
+            // the poller refuses to wait without any items, so only timers are waited for directly
+            if self.poller.is_empty() {
+                let timeout = u64::try_from(self.timers.timeout()).unwrap_or_default();
+                thread::sleep(Duration::from_millis(timeout));
+            } else {
+                for event in self.poller.wait(self.timers.timeout())? {
+                    let callback = &mut self.items[*event.token()];
+                    if callback(event.events())?.is_break() {
+                        return Ok(());
+                    }
+                }
+            }
+
+            for timer_id in self.timers.execute()? {
+                let Some(timer) = self.timer_callbacks.get_mut(&timer_id) else {
+                    continue;
+                };
+                if timer.cancelled.load(Ordering::Acquire) {
+                    continue;
+                }
+
+                if !timer.repeating {
+                    timer.cancelled.store(true, Ordering::Release);
+                }
+                if (timer.callback)()?.is_break() {
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod reactor_tests {
+    use core::{cell::Cell, ops::ControlFlow};
+
+    use super::Reactor;
+    use crate::{
+        ZmqError, ZmqResult,
+        context::Context,
+        socket::{PairSocket, PollEvents, Receiver, RecvFlags, SendFlags, Sender},
+    };
+
+    #[test]
+    fn run_without_items_returns() -> ZmqResult<()> {
+        let mut reactor = Reactor::new()?;
+
+        reactor.run()
+    }
+
+    #[test]
+    fn one_shot_timer_fires_once() -> ZmqResult<()> {
+        let fired = Cell::new(0);
+
+        let mut reactor = Reactor::new()?;
+        reactor.add_timer(1, || {
+            fired.set(fired.get() + 1);
+            Ok(ControlFlow::Continue(()))
+        })?;
+        reactor.add_timer(20, || Ok(ControlFlow::Break(())))?;
+
+        reactor.run()?;
+        assert_eq!(fired.get(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn repeating_timer_fires_until_cancelled() -> ZmqResult<()> {
+        let fired = Cell::new(0);
+        let counter = &fired;
+
+        let mut reactor = Reactor::new()?;
+        let handle = reactor.add_repeating_timer(1, || {
+            fired.set(fired.get() + 1);
+            Ok(ControlFlow::Continue(()))
+        })?;
+        assert!(!handle.is_cancelled());
+
+        reactor.add_repeating_timer(1, move || {
+            if counter.get() >= 3 {
+                handle.cancel();
+            }
+            Ok(ControlFlow::Continue(()))
+        })?;
+        reactor.add_timer(50, || Ok(ControlFlow::Break(())))?;
+
+        reactor.run()?;
+        assert!((3..=4).contains(&fired.get()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_timer_interval() -> ZmqResult<()> {
+        let mut reactor = Reactor::new()?;
+
+        let result = reactor.add_timer(-1, || Ok(ControlFlow::Continue(())));
+        assert!(result.is_err_and(|err| err == ZmqError::InvalidArgument));
+
+        Ok(())
+    }
+
+    #[test]
+    fn socket_callback_receives_messages() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let receiver = PairSocket::from_context(&context)?;
+        receiver.bind("inproc://reactor-socket-callback")?;
+
+        let sender = PairSocket::from_context(&context)?;
+        sender.connect("inproc://reactor-socket-callback")?;
+        sender.send_msg("Hello", SendFlags::empty())?;
+
+        let mut received = None;
+
+        let mut reactor = Reactor::new()?;
+        reactor.add_socket(&receiver, PollEvents::POLL_IN, |events| {
+            assert!(events.contains(PollEvents::POLL_IN));
+            received = Some(receiver.recv_msg(RecvFlags::empty())?.to_string());
+            Ok(ControlFlow::Break(()))
+        });
+        reactor.run()?;
+        drop(reactor);
+
+        assert_eq!(received.as_deref(), Some("Hello"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn callback_errors_are_returned() -> ZmqResult<()> {
+        let mut reactor = Reactor::new()?;
+        reactor.add_timer(1, || Err(ZmqError::Again))?;
+
+        assert!(reactor.run().is_err_and(|err| err == ZmqError::Again));
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn fd_callback_is_invoked() -> ZmqResult<()> {
+        use std::{io::Write, os::fd::AsRawFd, os::unix::net::UnixStream};
+
+        let (mut writer, reader) = UnixStream::pair().unwrap();
+        writer.write_all(b"Hello").unwrap();
+
+        let mut reactor = Reactor::new()?;
+        reactor.add_fd(reader.as_raw_fd(), PollEvents::POLL_IN, |events| {
+            assert!(events.contains(PollEvents::POLL_IN));
+            Ok(ControlFlow::Break(()))
+        });
+
+        reactor.run()
+    }
+}
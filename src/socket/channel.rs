@@ -99,13 +99,11 @@ mod channel_tests {
         futures::executor::block_on(async {
             channel_client
                 .send_msg_async("Hello", SendFlags::empty())
-                .await;
-            loop {
-                if let Some(msg) = channel_client.recv_msg_async().await {
-                    assert_eq!(msg.to_string(), "World");
-                    break;
-                }
-            }
+                .await?;
+
+            let msg = channel_client.recv_msg_async().await?;
+            assert_eq!(msg.to_string(), "World");
+
             Ok(())
         })
     }
@@ -121,7 +121,7 @@ pub(crate) mod builder {
     use serde::{Deserialize, Serialize};
 
     use super::ClientSocket;
-    use crate::{ZmqResult, context::Context, socket::SocketBuilder};
+    use crate::{ZmqError, ZmqResult, context::Context, socket::SocketBuilder};
 
     #[derive(Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Builder)]
     #[builder(
@@ -167,6 +167,18 @@ pub(crate) mod builder {
         }
     }
 
+    impl TryFrom<&ClientSocket> for ClientBuilder {
+        type Error = ZmqError;
+
+        /// Reads back all options of the socket that can be retrieved.
+        fn try_from(socket: &ClientSocket) -> Result<Self, Self::Error> {
+            Ok(Self {
+                socket_builder: Some(SocketBuilder::try_from(socket)?),
+                ..Default::default()
+            })
+        }
+    }
+
     #[cfg(test)]
     mod client_builder_tests {
         use super::ClientBuilder;
@@ -0,0 +1,309 @@
+use core::net::SocketAddrV4;
+
+use crate::{
+    ZmqError, ZmqResult,
+    message::{Message, MultipartMessage},
+    sealed,
+    socket::{MultipartReceiver, MultipartSender, RecvFlags, SendFlags, Socket, SocketType},
+};
+
+/// # A datagram socket `ZMQ_DGRAM`
+///
+/// A socket of type [`Datagram`] is used to send and receive raw UDP datagrams over the `udp`
+/// transport. The socket is bound to a local `udp://` endpoint, and is able to send datagrams to
+/// and receive datagrams from any IPv4 peer address.
+///
+/// Every message is a multipart message with exactly two parts: the first part is the address of
+/// the peer in the form `ip:port`, the second part is the payload of the datagram. Use
+/// [`send_to()`] and [`recv_from()`] to send and receive the payload together with the typed
+/// address of the peer.
+///
+/// [`Datagram`]: DatagramSocket
+/// [`send_to()`]: #method.send_to
+/// [`recv_from()`]: #method.recv_from
+pub type DatagramSocket = Socket<Datagram>;
+
+pub struct Datagram {}
+
+impl sealed::SenderFlag for Datagram {}
+impl sealed::ReceiverFlag for Datagram {}
+
+impl sealed::SocketType for Datagram {
+    fn raw_socket_type() -> SocketType {
+        SocketType::Datagram
+    }
+}
+
+unsafe impl Sync for Socket<Datagram> {}
+unsafe impl Send for Socket<Datagram> {}
+
+impl MultipartSender for Socket<Datagram> {}
+impl MultipartReceiver for Socket<Datagram> {}
+
+fn datagram<A, M>(addr: A, payload: M) -> MultipartMessage
+where
+    A: Into<SocketAddrV4>,
+    M: Into<Message>,
+{
+    vec![Message::from(&addr.into().to_string()), payload.into()].into()
+}
+
+fn peer_and_payload(mut multipart: MultipartMessage) -> ZmqResult<(SocketAddrV4, Message)> {
+    if multipart.len() != 2 {
+        return Err(ZmqError::InvalidArgument);
+    }
+
+    let (Some(addr), Some(payload)) = (multipart.pop_front(), multipart.pop_front()) else {
+        return Err(ZmqError::InvalidArgument);
+    };
+    // the address of received datagrams is NUL-terminated
+    let addr = addr.as_slice();
+    let addr = addr.strip_suffix(&[0]).unwrap_or(&addr);
+    let addr = core::str::from_utf8(addr)
+        .ok()
+        .and_then(|addr| addr.parse().ok())
+        .ok_or(ZmqError::InvalidArgument)?;
+
+    Ok((addr, payload))
+}
+
+impl Socket<Datagram> {
+    /// # Send a datagram to a peer
+    ///
+    /// Sends the payload as a single datagram to the provided IPv4 peer address, the only kind of
+    /// address supported by the `udp` transport.
+    pub fn send_to<A, M, F>(&self, addr: A, payload: M, flags: F) -> ZmqResult<()>
+    where
+        A: Into<SocketAddrV4>,
+        M: Into<Message>,
+        F: Into<SendFlags> + Copy,
+    {
+        self.send_multipart(datagram(addr, payload), flags)
+    }
+
+    /// # Send a datagram to a peer
+    ///
+    /// This is the async variant of [`send_to()`].
+    ///
+    /// [`send_to()`]: #method.send_to
+    #[cfg(feature = "futures")]
+    pub async fn send_to_async<A, M, F>(&self, addr: A, payload: M, flags: F) -> ZmqResult<()>
+    where
+        A: Into<SocketAddrV4>,
+        M: Into<Message>,
+        F: Into<SendFlags> + Copy + Send,
+    {
+        self.send_multipart_async(datagram(addr, payload), flags)
+            .await
+    }
+
+    /// # Receive a datagram from a peer
+    ///
+    /// Receives the next datagram, together with the address of the peer it was sent from.
+    /// Messages that don't consist of the peer address and the payload are rejected with
+    /// [`InvalidArgument`].
+    ///
+    /// [`InvalidArgument`]: ZmqError::InvalidArgument
+    pub fn recv_from<F>(&self, flags: F) -> ZmqResult<(SocketAddrV4, Message)>
+    where
+        F: Into<RecvFlags> + Copy,
+    {
+        peer_and_payload(self.recv_multipart(flags)?)
+    }
+
+    /// # Receive a datagram from a peer
+    ///
+    /// This is the async variant of [`recv_from()`].
+    ///
+    /// [`recv_from()`]: #method.recv_from
+    #[cfg(feature = "futures")]
+    pub async fn recv_from_async(&self) -> ZmqResult<(SocketAddrV4, Message)> {
+        peer_and_payload(self.recv_multipart_async().await?)
+    }
+}
+
+#[cfg(test)]
+mod datagram_tests {
+    use core::net::SocketAddrV4;
+    use std::net::{SocketAddr, UdpSocket};
+
+    use super::DatagramSocket;
+    use crate::prelude::{
+        Context, Message, MultipartReceiver, MultipartSender, PollEvents, RecvFlags, SendFlags,
+        ZmqError, ZmqResult,
+    };
+
+    /// The `udp` transport can't bind to a wildcard port, so an unused port is picked up front.
+    fn unused_addr() -> SocketAddrV4 {
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        match socket.local_addr().unwrap() {
+            SocketAddr::V4(addr) => addr,
+            SocketAddr::V6(_) => unreachable!(),
+        }
+    }
+
+    fn send_until_received(
+        sender: &DatagramSocket,
+        receiver: &DatagramSocket,
+        addr: SocketAddrV4,
+        payload: &str,
+    ) -> ZmqResult<(SocketAddrV4, Message)> {
+        while !receiver
+            .poll(PollEvents::POLL_IN, 10)?
+            .contains(PollEvents::POLL_IN)
+        {
+            sender.send_to(addr, payload, SendFlags::empty())?;
+        }
+
+        receiver.recv_from(RecvFlags::empty())
+    }
+
+    #[test]
+    fn send_to_sends_to_peer() -> ZmqResult<()> {
+        let server_addr = unused_addr();
+        let client_addr = unused_addr();
+
+        let context = Context::new()?;
+
+        let server = DatagramSocket::from_context(&context)?;
+        server.bind(format!("udp://{server_addr}"))?;
+
+        let client = DatagramSocket::from_context(&context)?;
+        client.bind(format!("udp://{client_addr}"))?;
+
+        let (peer, msg) = send_until_received(&client, &server, server_addr, "Hello")?;
+        assert_eq!(peer, client_addr);
+        assert_eq!(msg.to_string(), "Hello");
+
+        let (peer, msg) = send_until_received(&server, &client, peer, "World")?;
+        assert_eq!(peer, server_addr);
+        assert_eq!(msg.to_string(), "World");
+
+        Ok(())
+    }
+
+    #[test]
+    fn recv_from_receives_raw_multipart() -> ZmqResult<()> {
+        let server_addr = unused_addr();
+        let client_addr = unused_addr();
+
+        let context = Context::new()?;
+
+        let server = DatagramSocket::from_context(&context)?;
+        server.bind(format!("udp://{server_addr}"))?;
+
+        let client = DatagramSocket::from_context(&context)?;
+        client.bind(format!("udp://{client_addr}"))?;
+
+        while !server
+            .poll(PollEvents::POLL_IN, 10)?
+            .contains(PollEvents::POLL_IN)
+        {
+            let datagram: Vec<Message> =
+                vec![Message::from(&server_addr.to_string()), "Hello".into()];
+            client.send_multipart(datagram, SendFlags::empty())?;
+        }
+
+        let mut multipart = server.recv_multipart(RecvFlags::empty())?;
+        assert_eq!(multipart.len(), 2);
+        assert_eq!(
+            multipart.pop_front().unwrap().as_slice(),
+            format!("{client_addr}\0").as_bytes()
+        );
+        assert_eq!(multipart.pop_front().unwrap().to_string(), "Hello");
+
+        Ok(())
+    }
+
+    #[test]
+    fn send_multipart_rejects_more_than_two_parts() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let socket = DatagramSocket::from_context(&context)?;
+        socket.bind(format!("udp://{}", unused_addr()))?;
+
+        let datagram: Vec<Message> = vec![
+            Message::from(&unused_addr().to_string()),
+            "Hello".into(),
+            "World".into(),
+        ];
+        let result = socket.send_multipart(datagram, SendFlags::empty());
+        assert!(result.is_err_and(|err| err == ZmqError::InvalidArgument));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "futures")]
+    #[test]
+    fn send_to_async_sends_to_peer() -> ZmqResult<()> {
+        let server_addr = unused_addr();
+        let client_addr = unused_addr();
+
+        let context = Context::new()?;
+
+        let server = DatagramSocket::from_context(&context)?;
+        server.bind(format!("udp://{server_addr}"))?;
+
+        let client = DatagramSocket::from_context(&context)?;
+        client.bind(format!("udp://{client_addr}"))?;
+
+        send_until_received(&client, &server, server_addr, "Hello")?;
+
+        futures::executor::block_on(async {
+            client
+                .send_to_async(server_addr, "World", SendFlags::empty())
+                .await?;
+
+            // skip the datagrams sent while waiting for the sockets to be ready
+            loop {
+                let (peer, msg) = server.recv_from_async().await?;
+                assert_eq!(peer, client_addr);
+                if msg.to_string() == "World" {
+                    return Ok(());
+                }
+            }
+        })
+    }
+}
+
+#[cfg(feature = "builder")]
+pub(crate) mod builder {
+    use crate::socket::SocketBuilder;
+
+    /// Builder for [`DatagramSocket`](super::DatagramSocket)
+    pub type DatagramBuilder = SocketBuilder;
+
+    #[cfg(test)]
+    mod datagram_builder_tests {
+        use super::DatagramBuilder;
+        use crate::prelude::{Context, DatagramSocket, ZmqResult};
+
+        #[test]
+        fn builder_from_default() -> ZmqResult<()> {
+            let context = Context::new()?;
+
+            let socket: DatagramSocket = DatagramBuilder::default().build_from_context(&context)?;
+
+            assert_eq!(socket.linger()?, -1);
+            assert_eq!(socket.receive_highwater_mark()?, 1_000);
+            assert_eq!(socket.send_highwater_mark()?, 1_000);
+
+            Ok(())
+        }
+
+        #[test]
+        fn builder_with_options() -> ZmqResult<()> {
+            let context = Context::new()?;
+
+            let socket: DatagramSocket = DatagramBuilder::default()
+                .linger(0)
+                .receive_highwater_mark(42)
+                .build_from_context(&context)?;
+
+            assert_eq!(socket.linger()?, 0);
+            assert_eq!(socket.receive_highwater_mark()?, 42);
+
+            Ok(())
+        }
+    }
+}
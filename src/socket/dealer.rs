@@ -1,5 +1,5 @@
 use super::{MultipartReceiver, MultipartSender, Socket, SocketOption, SocketType};
-use crate::{ZmqResult, sealed};
+use crate::{ZmqResult, message::RoutingId, sealed};
 
 /// # A dealer socket `ZMQ_DEALER`
 ///
@@ -103,9 +103,9 @@ impl Socket<Dealer> {
     /// [`set_router_handover()`]: #method.set_router_handover
     pub fn set_routing_id<V>(&self, value: V) -> ZmqResult<()>
     where
-        V: AsRef<str>,
+        V: Into<RoutingId>,
     {
-        self.set_sockopt_string(SocketOption::RoutingId, value)
+        self.set_sockopt_routing_id(SocketOption::RoutingId, value.into())
     }
 
     /// # Retrieve socket routing id `ZMQ_ROUTING_ID`
@@ -119,8 +119,8 @@ impl Socket<Dealer> {
     ///
     /// [`routing_id()`]: #method.routing_id
     /// [`Router`]: super::RouterSocket
-    pub fn routing_id(&self) -> ZmqResult<String> {
-        self.get_sockopt_string(SocketOption::RoutingId)
+    pub fn routing_id(&self) -> ZmqResult<RoutingId> {
+        self.get_sockopt_routing_id(SocketOption::RoutingId)
     }
 
     /// # bootstrap connections to ROUTER sockets `ZMQ_PROBE_ROUTER`
@@ -190,7 +190,8 @@ impl Socket<Dealer> {
 mod dealer_tests {
     use super::DealerSocket;
     use crate::prelude::{
-        Context, Message, MultipartReceiver, MultipartSender, RecvFlags, SendFlags, ZmqResult,
+        Context, Message, MultipartReceiver, MultipartSender, RecvFlags, RoutingId, SendFlags,
+        ZmqResult,
     };
 
     #[test]
@@ -212,7 +213,7 @@ mod dealer_tests {
         let socket = DealerSocket::from_context(&context)?;
         socket.set_routing_id("test123")?;
 
-        assert_eq!(socket.routing_id()?, "test123");
+        assert_eq!(socket.routing_id()?, RoutingId::from("test123"));
 
         Ok(())
     }
@@ -255,7 +256,7 @@ mod dealer_tests {
 
         let dealer_server = DealerSocket::from_context(&context)?;
         dealer_server.bind("tcp://127.0.0.1:*")?;
-        let client_endpoint = dealer_server.last_endpoint()?;
+        let client_endpoint = dealer_server.last_endpoint()?.unwrap();
 
         std::thread::spawn(move || {
             let mut multipart = dealer_server.recv_multipart(RecvFlags::empty()).unwrap();
@@ -292,7 +293,7 @@ mod dealer_tests {
 
         let dealer_server = DealerSocket::from_context(&context)?;
         dealer_server.bind("tcp://127.0.0.1:*")?;
-        let client_endpoint = dealer_server.last_endpoint()?;
+        let client_endpoint = dealer_server.last_endpoint()?.unwrap();
 
         std::thread::spawn(move || {
             let mut multipart = dealer_server.recv_multipart(RecvFlags::empty()).unwrap();
@@ -314,9 +315,9 @@ mod dealer_tests {
             let multipart: Vec<Message> = vec![vec![].into(), "Hello".into()];
             dealer_client
                 .send_multipart_async(multipart, SendFlags::empty())
-                .await;
+                .await?;
 
-            let mut response = dealer_client.recv_multipart_async().await;
+            let mut response = dealer_client.recv_multipart_async().await?;
 
             let content = response.pop_back().unwrap();
             assert!(!content.is_empty());
@@ -335,7 +336,7 @@ pub(crate) mod builder {
     use serde::{Deserialize, Serialize};
 
     use super::DealerSocket;
-    use crate::{ZmqResult, context::Context, socket::SocketBuilder};
+    use crate::{ZmqError, ZmqResult, context::Context, message::RoutingId, socket::SocketBuilder};
 
     #[derive(Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Builder)]
     #[builder(
@@ -358,7 +359,7 @@ pub(crate) mod builder {
         #[builder(setter(into), default = "Default::default()")]
         hello_message: String,
         #[builder(setter(into), default = "Default::default()")]
-        routing_id: String,
+        routing_id: RoutingId,
     }
 
     impl DealerBuilder {
@@ -397,10 +398,27 @@ pub(crate) mod builder {
         }
     }
 
+    impl TryFrom<&DealerSocket> for DealerBuilder {
+        type Error = ZmqError;
+
+        /// Reads back all options of the socket that can be retrieved.
+        fn try_from(socket: &DealerSocket) -> Result<Self, Self::Error> {
+            Ok(Self {
+                socket_builder: Some(SocketBuilder::try_from(socket)?),
+                conflate: Some(socket.conflate()?),
+                #[cfg(feature = "draft-api")]
+                hiccup_msg: None,
+                #[cfg(feature = "draft-api")]
+                hello_message: None,
+                routing_id: Some(socket.routing_id()?).filter(RoutingId::is_valid),
+            })
+        }
+    }
+
     #[cfg(test)]
     mod dealer_builder_tests {
         use super::DealerBuilder;
-        use crate::prelude::{Context, SocketBuilder, ZmqResult};
+        use crate::prelude::{Context, RoutingId, SocketBuilder, ZmqResult};
 
         #[test]
         fn default_dealer_builder() -> ZmqResult<()> {
@@ -408,7 +426,7 @@ pub(crate) mod builder {
 
             let socket = DealerBuilder::default().build_from_context(&context)?;
             assert!(!socket.conflate()?);
-            assert_eq!(socket.routing_id()?, "");
+            assert_eq!(socket.routing_id()?, RoutingId::default());
 
             Ok(())
         }
@@ -432,7 +450,7 @@ pub(crate) mod builder {
             let socket = dealer_builder.build_from_context(&context)?;
 
             assert!(socket.conflate()?);
-            assert_eq!(socket.routing_id()?, "test123");
+            assert_eq!(socket.routing_id()?, RoutingId::from("test123"));
 
             Ok(())
         }
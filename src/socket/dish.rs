@@ -101,7 +101,7 @@ mod dish_tests {
 
         let radio = RadioSocket::from_context(&context)?;
         radio.bind("tcp://127.0.0.1:*")?;
-        let dish_endpoint = radio.last_endpoint()?;
+        let dish_endpoint = radio.last_endpoint()?.unwrap();
 
         std::thread::spawn(move || {
             loop {
@@ -129,14 +129,17 @@ mod dish_tests {
 
         let radio = RadioSocket::from_context(&context)?;
         radio.bind("tcp://127.0.0.1:*")?;
-        let dish_endpoint = radio.last_endpoint()?;
+        let dish_endpoint = radio.last_endpoint()?.unwrap();
 
         std::thread::spawn(move || {
             futures::executor::block_on(async {
                 loop {
                     let message: Message = "radio-msg".into();
                     message.set_group("asdf").unwrap();
-                    radio.send_msg_async(message, SendFlags::DONT_WAIT).await;
+                    radio
+                        .send_msg_async(message, SendFlags::DONT_WAIT)
+                        .await
+                        .unwrap();
                 }
             })
         });
@@ -146,16 +149,12 @@ mod dish_tests {
         dish.join("asdf")?;
 
         futures::executor::block_on(async {
-            loop {
-                if let Some(msg) = dish.recv_msg_async().await {
-                    assert_eq!(msg.group().unwrap(), "asdf");
-                    assert_eq!(msg.to_string(), "radio-msg");
-                    break;
-                }
-            }
-        });
+            let msg = dish.recv_msg_async().await?;
+            assert_eq!(msg.group().unwrap(), "asdf");
+            assert_eq!(msg.to_string(), "radio-msg");
 
-        Ok(())
+            Ok(())
+        })
     }
 }
 
@@ -165,7 +164,7 @@ pub(crate) mod builder {
     use serde::{Deserialize, Serialize};
 
     use super::DishSocket;
-    use crate::{ZmqResult, context::Context, socket::SocketBuilder};
+    use crate::{ZmqError, ZmqResult, context::Context, socket::SocketBuilder};
 
     #[derive(Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Builder)]
     #[builder(
@@ -202,6 +201,19 @@ pub(crate) mod builder {
             Ok(socket)
         }
     }
+
+    impl TryFrom<&DishSocket> for DishBuilder {
+        type Error = ZmqError;
+
+        /// Reads back all options of the socket that can be retrieved.
+        fn try_from(socket: &DishSocket) -> Result<Self, Self::Error> {
+            Ok(Self {
+                socket_builder: Some(SocketBuilder::try_from(socket)?),
+                ..Default::default()
+            })
+        }
+    }
+
     #[cfg(test)]
     mod dish_builder_tests {
         use super::DishBuilder;
@@ -38,7 +38,7 @@ mod gather_tests {
 
         let scatter = ScatterSocket::from_context(&context)?;
         scatter.bind("tcp://127.0.0.1:*")?;
-        let gather_endpoint = scatter.last_endpoint()?;
+        let gather_endpoint = scatter.last_endpoint()?.unwrap();
 
         std::thread::spawn(move || {
             loop {
@@ -62,12 +62,15 @@ mod gather_tests {
 
         let scatter = ScatterSocket::from_context(&context)?;
         scatter.bind("tcp://127.0.0.1:*")?;
-        let gather_endpoint = scatter.last_endpoint()?;
+        let gather_endpoint = scatter.last_endpoint()?.unwrap();
 
         std::thread::spawn(move || {
             futures::executor::block_on(async {
                 loop {
-                    scatter.send_msg_async("asdf", SendFlags::empty()).await;
+                    scatter
+                        .send_msg_async("asdf", SendFlags::empty())
+                        .await
+                        .unwrap();
                 }
             })
         });
@@ -76,12 +79,8 @@ mod gather_tests {
         gather.connect(gather_endpoint)?;
 
         futures::executor::block_on(async {
-            loop {
-                if let Some(msg) = gather.recv_msg_async().await {
-                    assert_eq!(msg.to_string(), "asdf");
-                    break;
-                }
-            }
+            let msg = gather.recv_msg_async().await?;
+            assert_eq!(msg.to_string(), "asdf");
 
             Ok(())
         })
@@ -77,7 +77,7 @@
 //!
 //!     let publish = PublishSocket::from_context(&context)?;
 //!     publish.bind("tcp://127.0.0.1:*")?;
-//!     let subscribe_endpoint = publish.last_endpoint()?;
+//!     let subscribe_endpoint = publish.last_endpoint()?.unwrap();
 //!
 //!     thread::spawn(move || {
 //!         let published_msg = format!("{SUBSCRIBED_TOPIC} important update");
@@ -137,7 +137,7 @@
 //!
 //!     let xpublish = XPublishSocket::from_context(&context)?;
 //!     xpublish.bind("tcp://127.0.0.1:*")?;
-//!     let xsubscribe_endpoint = xpublish.last_endpoint()?;
+//!     let xsubscribe_endpoint = xpublish.last_endpoint()?.unwrap();
 //!
 //!     thread::spawn(move || {
 //!         run_xpublish_socket(&xpublish, "important update").unwrap();
@@ -213,7 +213,7 @@
 //!
 //!     let server = ServerSocket::from_context(&context)?;
 //!     server.bind("tcp://127.0.0.1:*")?;
-//!     let client_endpoint = server.last_endpoint()?;
+//!     let client_endpoint = server.last_endpoint()?.unwrap();
 //!
 //!     thread::spawn(move || {
 //!             run_server_socket(&server, "World").unwrap();
@@ -285,7 +285,7 @@
 //!
 //!     let radio = RadioSocket::from_context(&context)?;
 //!     radio.bind("tcp://127.0.0.1:*")?;
-//!     let dish_endpoint = radio.last_endpoint()?;
+//!     let dish_endpoint = radio.last_endpoint()?.unwrap();
 //!
 //!     thread::spawn(move || {
 //!         run_radio_socket(&radio, "radio msg").unwrap();
@@ -334,7 +334,7 @@
 //!
 //!     let push = PushSocket::from_context(&context)?;
 //!     push.bind("tcp://127.0.0.1:*")?;
-//!     let pull_endpoint = push.last_endpoint()?;
+//!     let pull_endpoint = push.last_endpoint()?.unwrap();
 //!
 //!     thread::spawn(move || {
 //!         while KEEP_RUNNING.load(Ordering::Acquire) {
@@ -401,7 +401,7 @@
 //!
 //!     let scatter = ScatterSocket::from_context(&context)?;
 //!     scatter.bind("tcp://127.0.0.1:*")?;
-//!     let gather_endpoint = scatter.last_endpoint()?;
+//!     let gather_endpoint = scatter.last_endpoint()?.unwrap();
 //!
 //!     thread::spawn(move || {
 //!         run_publisher(&scatter, "important update").unwrap();
@@ -731,7 +731,7 @@
 //!
 //!     let zmq_stream = StreamSocket::from_context(&context)?;
 //!     zmq_stream.bind("tcp://127.0.0.1:*")?;
-//!     let tcp_endpoint = zmq_stream.last_endpoint()?;
+//!     let tcp_endpoint = zmq_stream.last_endpoint()?.unwrap();
 //!
 //!     thread::spawn(move || {
 //!         let mut connect_msg = zmq_stream.recv_multipart(RecvFlags::empty()).unwrap();
@@ -742,7 +742,7 @@
 //!         }
 //!     });
 //!
-//!     run_tcp_client(tcp_endpoint.strip_prefix("tcp://").unwrap(), iterations)?;
+//!     run_tcp_client(&format!("127.0.0.1:{}", tcp_endpoint.port().unwrap()), iterations)?;
 //!
 //!     Ok(())
 //! }
@@ -790,7 +790,7 @@
 //!
 //!     let reply = ReplySocket::from_context(&context)?;
 //!     reply.bind("tcp://127.0.0.1:*")?;
-//!     let request_endpoint = reply.last_endpoint()?;
+//!     let request_endpoint = reply.last_endpoint()?.unwrap();
 //!
 //!     thread::spawn(move || {
 //!         (1..=iterations)
@@ -845,7 +845,7 @@
 //!
 //!     let router = RouterSocket::from_context(&context)?;
 //!     router.bind("tcp://127.0.0.1:*")?;
-//!     let request_endpoint = router.last_endpoint()?;
+//!     let request_endpoint = router.last_endpoint()?.unwrap();
 //!
 //!     thread::spawn(move || {
 //!         (0..iterations)
@@ -905,7 +905,7 @@
 //!
 //!     let router = RouterSocket::from_context(&context)?;
 //!     router.bind("tcp://127.0.0.1:*")?;
-//!     let dealer_endpoint = router.last_endpoint()?;
+//!     let dealer_endpoint = router.last_endpoint()?.unwrap();
 //!
 //!     thread::spawn(move || {
 //!         (0..iterations)
@@ -926,19 +926,23 @@
 use alloc::sync::Arc;
 use core::{iter, marker::PhantomData, ops::ControlFlow};
 
-#[cfg(feature = "futures")]
-use ::futures::{FutureExt, TryStreamExt};
 #[cfg(feature = "futures")]
 use async_trait::async_trait;
 use bitflags::bitflags;
-use derive_more::From;
+use derive_more::{Debug as DebugDeriveMore, From};
 use num_traits::PrimInt;
+#[cfg(feature = "codec")]
+use serde::{Serialize, de::DeserializeOwned};
+use thiserror::Error;
 
+#[cfg(feature = "codec")]
+use crate::codec::{self, Codec, CodecError};
 use crate::{
     ZmqError, ZmqResult,
     context::Context,
+    endpoint::Endpoint,
     ffi::RawSocket,
-    message::{Message, MultipartMessage, Sendable},
+    message::{Message, MultipartMessage, RoutingId, Sendable},
     sealed, zmq_sys_crate,
 };
 
@@ -946,6 +950,8 @@ use crate::{
 mod channel;
 #[cfg(feature = "draft-api")]
 mod client;
+#[cfg(feature = "draft-api")]
+mod datagram;
 mod dealer;
 #[cfg(feature = "draft-api")]
 mod dish;
@@ -982,6 +988,10 @@ pub use channel::builder::ChannelBuilder;
 pub use client::ClientSocket;
 #[cfg(all(feature = "draft-api", feature = "builder"))]
 pub use client::builder::ClientBuilder;
+#[cfg(feature = "draft-api")]
+pub use datagram::DatagramSocket;
+#[cfg(all(feature = "draft-api", feature = "builder"))]
+pub use datagram::builder::DatagramBuilder;
 pub use dealer::DealerSocket;
 #[cfg(feature = "builder")]
 pub use dealer::builder::DealerBuilder;
@@ -989,12 +999,18 @@ pub use dealer::builder::DealerBuilder;
 pub use dish::DishSocket;
 #[cfg(all(feature = "draft-api", feature = "builder"))]
 pub use dish::builder::DishBuilder;
+#[cfg(feature = "futures")]
+pub use futures::MultipartStream;
 #[cfg(feature = "draft-api")]
 pub use gather::GatherSocket;
 #[cfg(all(feature = "draft-api", feature = "builder"))]
 pub use gather::builder::GatherBuilder;
 use monitor::Monitor;
+#[cfg(feature = "draft-api")]
+use monitor::MonitorV2;
 pub use monitor::{HandshakeProtocolError, MonitorReceiver, MonitorSocket, MonitorSocketEvent};
+#[cfg(feature = "draft-api")]
+pub use monitor::{MonitorEventV2, MonitorSocketEventV2, MonitorSocketV2};
 pub use pair::PairSocket;
 #[cfg(feature = "builder")]
 pub use pair::builder::PairBuilder;
@@ -1015,12 +1031,12 @@ pub use push::builder::PushBuilder;
 pub use radio::RadioSocket;
 #[cfg(all(feature = "draft-api", feature = "builder"))]
 pub use radio::builder::RadioBuilder;
-pub use reply::ReplySocket;
 #[cfg(feature = "builder")]
 pub use reply::builder::ReplyBuilder;
-pub use request::RequestSocket;
+pub use reply::{RepIdle, RepReplyPending, ReplySocket};
 #[cfg(feature = "builder")]
 pub use request::builder::RequestBuilder;
+pub use request::{ReqAwaitingReply, ReqIdle, RequestSocket};
 #[cfg(feature = "draft-api")]
 pub use router::RouterNotify;
 pub use router::RouterSocket;
@@ -1040,9 +1056,9 @@ pub use stream::builder::StreamBuilder;
 pub use subscribe::SubscribeSocket;
 #[cfg(feature = "builder")]
 pub use subscribe::builder::SubscribeBuilder;
-pub use xpublish::XPublishSocket;
 #[cfg(feature = "builder")]
 pub use xpublish::builder::XPublishBuilder;
+pub use xpublish::{SubscriptionEvent, Subscriptions, XPublishSocket};
 #[doc(hidden)]
 pub use xsubscribe::XSubscribeSocket;
 #[cfg(feature = "builder")]
@@ -1098,7 +1114,7 @@ pub enum SocketType {
     /// [`ScatterSocket`]
     Scatter,
     #[cfg(feature = "draft-api")]
-    /// DGRAM sockets
+    /// [`DatagramSocket`]
     Datagram,
     #[cfg(feature = "draft-api")]
     /// [`PeerSocket`]
@@ -1740,8 +1756,13 @@ mod socket_option_tests {
 
 /// generic 0MQ socket
 pub struct Socket<T: sealed::SocketType> {
-    context: Context,
+    #[cfg(feature = "futures")]
+    async_fd: futures::AsyncSocketFd,
+    #[cfg(feature = "futures")]
+    stream_state: futures::StreamState,
     pub(crate) socket: Arc<RawSocket>,
+    // dropped after the socket, as terminating the context blocks while sockets are still open.
+    context: Context,
     marker: PhantomData<T>,
 }
 
@@ -1750,6 +1771,10 @@ impl<T: sealed::SocketType> Socket<T> {
     pub fn from_context(context: &Context) -> ZmqResult<Self> {
         let socket = RawSocket::from_ctx(&context.inner, T::raw_socket_type() as i32)?;
         Ok(Self {
+            #[cfg(feature = "futures")]
+            async_fd: Default::default(),
+            #[cfg(feature = "futures")]
+            stream_state: Default::default(),
             context: context.clone(),
             socket: socket.into(),
             marker: PhantomData,
@@ -1831,6 +1856,24 @@ impl<T: sealed::SocketType> Socket<T> {
         self.socket.get_sockopt_bytes(option.into())
     }
 
+    pub(crate) fn set_sockopt_routing_id(
+        &self,
+        option: SocketOption,
+        value: RoutingId,
+    ) -> ZmqResult<()> {
+        if !value.is_valid() {
+            return Err(ZmqError::InvalidArgument);
+        }
+
+        self.set_sockopt_bytes(option, value)
+    }
+
+    pub(crate) fn get_sockopt_routing_id(&self, option: SocketOption) -> ZmqResult<RoutingId> {
+        self.socket
+            .get_sockopt_binary(option.into())
+            .map(RoutingId::from)
+    }
+
     /// # get 0MQ socket options
     ///
     /// Gets a [`SocketOption`] option on the socket. The string version is mostly suitable for
@@ -2426,17 +2469,24 @@ impl<T: sealed::SocketType> Socket<T> {
     /// # Retrieve the last endpoint set `ZMQ_LAST_ENDPOINT`
     ///
     /// The [`LastEndpoint`] option shall retrieve the last endpoint bound for TCP and IPC
-    /// transports. The returned value will be a string in the form of a ZMQ DSN. Note that if the
-    /// TCP host is INADDR_ANY, indicated by a *, then the returned address will be `0.0.0.0`
-    /// (for IPv4). Note: not supported on GNU/Hurd with IPC due to non-working getsockname().
+    /// transports. The returned value will be an [`Endpoint`] with the wildcard port of a TCP
+    /// bind resolved, i.e. available through [`Endpoint::port()`]. Note that if the TCP host is
+    /// INADDR_ANY, indicated by a *, then the returned address will be `0.0.0.0` (for IPv4).
+    /// Returns `None` if the socket was neither bound nor connected yet. Note: not supported on
+    /// GNU/Hurd with IPC due to non-working getsockname().
     ///
     /// | Default value | Applicable socket types                 |
     /// | :-----------: | :-------------------------------------: |
     /// | None          | all, when binding TCP or IPC transports |
     ///
     /// [`LastEndpoint`]: SocketOption::LastEndpoint
-    pub fn last_endpoint(&self) -> ZmqResult<String> {
-        self.get_sockopt_string(SocketOption::LastEndpoint)
+    pub fn last_endpoint(&self) -> ZmqResult<Option<Endpoint>> {
+        let last_endpoint = self.get_sockopt_string(SocketOption::LastEndpoint)?;
+        if last_endpoint.is_empty() {
+            return Ok(None);
+        }
+
+        last_endpoint.parse().map(Some)
     }
 
     /// # Maximum acceptable inbound message size `ZMQ_MAXMSGSIZE`
@@ -2487,6 +2537,29 @@ impl<T: sealed::SocketType> Socket<T> {
         SecurityMechanism::try_from(self)
     }
 
+    /// # Attach application metadata to the connection `ZMQ_METADATA`
+    ///
+    /// Adds an application metadata property `key` with the provided `value` to the connections
+    /// of this socket. The `key` must start with `X-`, and the property is available on every
+    /// message received by the peer through [`Message::property()`].
+    ///
+    /// | Default value | Applicable socket types |
+    /// | :-----------: | :---------------------: |
+    /// | not set       | all                     |
+    ///
+    /// [`Message::property()`]: crate::message::Message::property
+    #[cfg(feature = "draft-api")]
+    pub fn set_metadata<K, V>(&self, key: K, value: V) -> ZmqResult<()>
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        self.set_sockopt_string(
+            SocketOption::Metadata,
+            format!("{}:{}", key.as_ref(), value.as_ref()),
+        )
+    }
+
     /// # Maximum network hops for multicast packets `ZMQ_MULTICAST_HOPS`
     ///
     /// Sets the time-to-live field in every multicast packet sent from this socket. The default
@@ -3204,9 +3277,11 @@ impl<T: sealed::SocketType> Socket<T> {
     /// [`Channel`]: ChannelSocket
     pub fn bind<E>(&self, endpoint: E) -> ZmqResult<()>
     where
-        E: AsRef<str>,
+        E: TryInto<Endpoint>,
+        ZmqError: From<E::Error>,
     {
-        self.socket.bind(endpoint.as_ref())
+        let endpoint = endpoint.try_into()?;
+        self.socket.bind(&endpoint.to_string())
     }
 
     /// # Stop accepting connections on a socket
@@ -3231,9 +3306,11 @@ impl<T: sealed::SocketType> Socket<T> {
     /// [`last_endpoint()`]: #method.last_endpoint
     pub fn unbind<E>(&self, endpoint: E) -> ZmqResult<()>
     where
-        E: AsRef<str>,
+        E: TryInto<Endpoint>,
+        ZmqError: From<E::Error>,
     {
-        self.socket.unbind(endpoint.as_ref())
+        let endpoint = endpoint.try_into()?;
+        self.socket.unbind(&endpoint.to_string())
     }
 
     /// # create outgoing connection from socket
@@ -3262,9 +3339,11 @@ impl<T: sealed::SocketType> Socket<T> {
     /// [`Channel`]: ChannelSocket
     pub fn connect<E>(&self, endpoint: E) -> ZmqResult<()>
     where
-        E: AsRef<str>,
+        E: TryInto<Endpoint>,
+        ZmqError: From<E::Error>,
     {
-        self.socket.connect(endpoint.as_ref())
+        let endpoint = endpoint.try_into()?;
+        self.socket.connect(&endpoint.to_string())
     }
 
     /// # Disconnect a socket from an endpoint
@@ -3285,9 +3364,11 @@ impl<T: sealed::SocketType> Socket<T> {
     /// [`connect()`]: #method.connect
     pub fn disconnect<E>(&self, endpoint: E) -> ZmqResult<()>
     where
-        E: AsRef<str>,
+        E: TryInto<Endpoint>,
+        ZmqError: From<E::Error>,
     {
-        self.socket.disconnect(endpoint.as_ref())
+        let endpoint = endpoint.try_into()?;
+        self.socket.disconnect(&endpoint.to_string())
     }
 
     /// # monitor socket events
@@ -3323,12 +3404,84 @@ impl<T: sealed::SocketType> Socket<T> {
         monitor.connect(&monitor_endpoint)?;
 
         Ok(Socket {
+            #[cfg(feature = "futures")]
+            async_fd: Default::default(),
+            #[cfg(feature = "futures")]
+            stream_state: Default::default(),
+            context: self.context.clone(),
+            socket: monitor.into(),
+            marker: PhantomData,
+        })
+    }
+
+    /// # monitor socket events with 64-bit values and endpoints `ZMQ_CURRENT_EVENT_VERSION_DRAFT`
+    ///
+    /// The [`monitor_versioned()`] method works like [`monitor()`], but uses the second version
+    /// of the monitoring protocol. Events received on the returned [`MonitorV2`] socket carry all
+    /// event values as 64-bit values, as well as the local and remote endpoint of the affected
+    /// connection.
+    ///
+    /// Besides the provided `events`, the monitor always receives [`PipesStats`] events. These are
+    /// only emitted on demand when calling [`request_pipes_stats()`].
+    ///
+    /// [`monitor_versioned()`]: #method.monitor_versioned
+    /// [`monitor()`]: #method.monitor
+    /// [`MonitorV2`]: MonitorSocketV2
+    /// [`PipesStats`]: MonitorEventV2::PipesStats
+    /// [`request_pipes_stats()`]: #method.request_pipes_stats
+    #[cfg(feature = "draft-api")]
+    pub fn monitor_versioned<F>(&self, events: F) -> ZmqResult<MonitorSocketV2>
+    where
+        F: Into<MonitorFlags>,
+    {
+        let fd = self.get_sockopt_int::<usize>(SocketOption::FileDescriptor)?;
+        let monitor_endpoint = format!("inproc://monitor-v2.s-{fd}");
+
+        self.socket.monitor_versioned(
+            &monitor_endpoint,
+            events.into().bits() as u64 | zmq_sys_crate::ZMQ_EVENT_PIPES_STATS as u64,
+            zmq_sys_crate::ZMQ_CURRENT_EVENT_VERSION_DRAFT as i32,
+            <MonitorV2 as sealed::SocketType>::raw_socket_type() as i32,
+        )?;
+
+        let monitor = RawSocket::from_ctx(
+            self.context.as_raw(),
+            <MonitorV2 as sealed::SocketType>::raw_socket_type() as i32,
+        )?;
+
+        monitor.connect(&monitor_endpoint)?;
+
+        Ok(Socket {
+            #[cfg(feature = "futures")]
+            async_fd: Default::default(),
+            #[cfg(feature = "futures")]
+            stream_state: Default::default(),
             context: self.context.clone(),
             socket: monitor.into(),
             marker: PhantomData,
         })
     }
 
+    /// # request statistics of the socket's pipes
+    ///
+    /// Requests the number of queued messages in both directions for every connection of the
+    /// socket. The statistics are delivered asynchronously as [`PipesStats`] events to the monitor
+    /// set up with [`monitor_versioned()`], one for each connection, once the socket processes
+    /// its pending commands, i.e. on the next send, receive, or [`events()`] call.
+    ///
+    /// Fails with [`InvalidArgument`] if no versioned monitor is set up, and with [`Again`] if the
+    /// socket has no connections.
+    ///
+    /// [`PipesStats`]: MonitorEventV2::PipesStats
+    /// [`monitor_versioned()`]: #method.monitor_versioned
+    /// [`events()`]: #method.events
+    /// [`InvalidArgument`]: ZmqError::InvalidArgument
+    /// [`Again`]: ZmqError::Again
+    #[cfg(feature = "draft-api")]
+    pub fn request_pipes_stats(&self) -> ZmqResult<()> {
+        self.socket.monitor_pipes_stats()
+    }
+
     /// # input/output multiplexing
     ///
     /// Poll this socket for input/output events.
@@ -3454,8 +3607,27 @@ pub trait Receiver {
     where
         F: Into<RecvFlags> + Copy;
 
+    /// Receives a single part message, and decodes it with the provided [`Codec`].
+    ///
+    /// [`Codec`]: crate::codec::Codec
+    #[cfg(feature = "codec")]
+    fn recv_typed<C, T, F>(&self, codec: C, flags: F) -> Result<T, CodecError>
+    where
+        C: Codec,
+        T: DeserializeOwned,
+        F: Into<RecvFlags> + Copy,
+    {
+        let msg = self.recv_msg(flags)?;
+        codec.decode(&msg.as_slice())
+    }
+
+    /// Receives a single part message without blocking the current thread. The returned future
+    /// only resolves once a message was actually received, or an error other than
+    /// [`Again`] occured.
+    ///
+    /// [`Again`]: ZmqError::Again
     #[cfg(feature = "futures")]
-    async fn recv_msg_async(&self) -> Option<Message>;
+    async fn recv_msg_async(&self) -> ZmqResult<Message>;
 }
 
 #[cfg_attr(feature = "futures", async_trait)]
@@ -3474,8 +3646,8 @@ where
     }
 
     #[cfg(feature = "futures")]
-    async fn recv_msg_async(&self) -> Option<Message> {
-        futures::MessageReceivingFuture { receiver: self }.now_or_never()
+    async fn recv_msg_async(&self) -> ZmqResult<Message> {
+        futures::MessageReceivingFuture::new(self).await
     }
 }
 
@@ -3506,21 +3678,37 @@ pub trait MultipartReceiver: Receiver {
             .unwrap()
     }
 
+    /// Receives a multipart message, and decodes its frames into the fields of a struct, or the
+    /// elements of a tuple or sequence, each with the provided [`Codec`].
+    ///
+    /// [`Codec`]: crate::codec::Codec
+    #[cfg(feature = "codec")]
+    fn recv_multipart_typed<C, T, F>(&self, codec: C, flags: F) -> Result<T, CodecError>
+    where
+        C: Codec,
+        T: DeserializeOwned,
+        F: Into<RecvFlags> + Copy,
+    {
+        let multipart = self.recv_multipart(flags)?;
+        codec::decode_frames(&codec, &multipart)
+    }
+
+    /// Receives a multipart message without blocking the current thread. The returned future only
+    /// resolves once all parts of the message were received, or an error other than [`Again`]
+    /// occured.
+    ///
+    /// [`Again`]: ZmqError::Again
     #[cfg(feature = "futures")]
-    async fn recv_multipart_async(&self) -> MultipartMessage {
-        ::futures::stream::repeat_with(|| Ok(self.recv_msg_async()))
-            .try_fold(MultipartMessage::new(), |mut parts, zmq_msg| async move {
-                if let Some(msg) = zmq_msg.await {
-                    let got_more = msg.get_more();
-                    parts.push_back(msg);
-                    if !got_more {
-                        return Err(parts);
-                    }
-                }
-                Ok(parts)
-            })
-            .await
-            .unwrap_err()
+    async fn recv_multipart_async(&self) -> ZmqResult<MultipartMessage> {
+        let mut parts = MultipartMessage::new();
+        loop {
+            let zmq_msg = self.recv_msg_async().await?;
+            let got_more = zmq_msg.get_more();
+            parts.push_back(zmq_msg);
+            if !got_more {
+                return Ok(parts);
+            }
+        }
     }
 }
 
@@ -3553,10 +3741,28 @@ pub trait Sender {
         M: Into<Message>,
         F: Into<SendFlags> + Copy;
 
+    /// Encodes the value with the provided [`Codec`], and sends it as a single part message.
+    ///
+    /// [`Codec`]: crate::codec::Codec
+    #[cfg(feature = "codec")]
+    fn send_typed<C, T, F>(&self, codec: C, value: &T, flags: F) -> Result<(), CodecError>
+    where
+        C: Codec,
+        T: Serialize + ?Sized,
+        F: Into<SendFlags> + Copy,
+    {
+        let msg = codec.encode(value)?;
+        self.send_msg(msg, flags).map_err(CodecError::from)
+    }
+
+    /// Sends a single part message without blocking the current thread. The returned future only
+    /// resolves once the message was actually sent, or an error other than [`Again`] occured.
+    ///
+    /// [`Again`]: ZmqError::Again
     #[cfg(feature = "futures")]
-    async fn send_msg_async<M, F>(&self, msg: M, flags: F) -> Option<()>
+    async fn send_msg_async<M, F>(&self, msg: M, flags: F) -> ZmqResult<()>
     where
-        M: Into<Message> + Clone + Send,
+        M: Into<Message> + Send,
         F: Into<SendFlags> + Copy + Send;
 }
 
@@ -3575,17 +3781,12 @@ where
     }
 
     #[cfg(feature = "futures")]
-    async fn send_msg_async<M, F>(&self, msg: M, flags: F) -> Option<()>
+    async fn send_msg_async<M, F>(&self, msg: M, flags: F) -> ZmqResult<()>
     where
-        M: Into<Message> + Clone + Send,
+        M: Into<Message> + Send,
         F: Into<SendFlags> + Copy + Send,
     {
-        futures::MessageSendingFuture {
-            receiver: self,
-            message: msg,
-            flags: flags.into(),
-        }
-        .now_or_never()
+        futures::MessageSendingFuture::new(self, msg.into(), flags.into()).await
     }
 }
 
@@ -3612,8 +3813,29 @@ pub trait MultipartSender: Sender {
         }
     }
 
+    /// Encodes the fields of a struct, or the elements of a tuple or sequence, each with the
+    /// provided [`Codec`], and sends them as the frames of a multipart message.
+    ///
+    /// [`Codec`]: crate::codec::Codec
+    #[cfg(feature = "codec")]
+    fn send_multipart_typed<C, T, F>(&self, codec: C, value: &T, flags: F) -> Result<(), CodecError>
+    where
+        C: Codec,
+        T: Serialize + ?Sized,
+        F: Into<SendFlags> + Copy,
+    {
+        let multipart = codec::encode_frames(&codec, value)?;
+        self.send_multipart(multipart, flags)
+            .map_err(CodecError::from)
+    }
+
+    /// Sends a multipart message without blocking the current thread. The returned future only
+    /// resolves once all parts of the message were actually sent, or an error other than
+    /// [`Again`] occured.
+    ///
+    /// [`Again`]: ZmqError::Again
     #[cfg(feature = "futures")]
-    async fn send_multipart_async<M, F>(&self, multipart: M, flags: F) -> Option<()>
+    async fn send_multipart_async<M, F>(&self, multipart: M, flags: F) -> ZmqResult<()>
     where
         M: Into<MultipartMessage> + Send,
         F: Into<SendFlags> + Copy + Send,
@@ -3630,196 +3852,843 @@ pub trait MultipartSender: Sender {
         if let Some(last) = last_part {
             self.send_msg_async(last, flags.into()).await
         } else {
-            None
+            Ok(())
         }
     }
 }
 
-#[cfg(feature = "futures")]
-mod futures {
-    use core::{pin::Pin, task::Poll};
+#[derive(Error, DebugDeriveMore)]
+#[debug("TransitionError {{ error: {error:?}, .. }}")]
+#[error("{error}")]
+/// # Failed state transition of a typed socket
+///
+/// Carries the error of the failed operation, and the typed socket in its unchanged state, so
+/// that the operation can be retried. When the operation failed after sending only part of a
+/// multipart message, the socket is left in an undefined state, and is not returned. Converts
+/// into the [`ZmqError`] for use with the `?` operator.
+pub struct TransitionError<S> {
+    error: ZmqError,
+    state: Option<Box<S>>,
+}
 
-    use super::{RecvFlags, SendFlags, Socket};
-    use crate::{
-        message::{Message, Sendable},
-        sealed,
-    };
+impl<S> TransitionError<S> {
+    pub(crate) fn new(error: ZmqError, state: S) -> Self {
+        Self {
+            error,
+            state: Some(Box::new(state)),
+        }
+    }
 
-    pub(super) struct MessageSendingFuture<'a, T, M>
-    where
-        T: sealed::SocketType + sealed::SenderFlag + Unpin,
-        M: Into<Message> + Clone + Send,
-    {
-        pub(super) receiver: &'a Socket<T>,
-        pub(super) message: M,
-        pub(super) flags: SendFlags,
+    pub(crate) fn unrecoverable(error: ZmqError) -> Self {
+        Self { error, state: None }
     }
 
-    #[rustversion::attr(before(1.87), allow(clippy::needless_lifetimes))]
-    impl<'a, T, M> Future for MessageSendingFuture<'a, T, M>
+    /// Sends a multipart message on the socket of a typed socket state. Failing to send the first
+    /// part returns the state for a retry, failing on a later part drops it.
+    pub(crate) fn send_multipart<T, M, F>(
+        state: S,
+        socket: fn(&S) -> &Socket<T>,
+        multipart: M,
+        flags: F,
+    ) -> Result<S, Self>
     where
-        T: sealed::SocketType + sealed::SenderFlag + Unpin,
-        M: Into<Message> + Clone + Send,
+        T: sealed::SocketType,
+        Socket<T>: Sender,
+        M: Into<MultipartMessage>,
+        F: Into<SendFlags> + Copy,
     {
-        type Output = ();
+        let mut parts = multipart.into().into_iter().peekable();
+        let Some(first) = parts.next() else {
+            return Err(Self::new(ZmqError::InvalidArgument, state));
+        };
 
-        fn poll(self: Pin<&mut Self>, _ctx: &mut core::task::Context<'_>) -> Poll<Self::Output> {
-            let message = self.message.clone().into();
+        let part_flags = |more: bool| {
+            if more {
+                flags.into() | SendFlags::SEND_MORE
+            } else {
+                flags.into()
+            }
+        };
+        if let Err(err) = socket(&state).send_msg(first, part_flags(parts.peek().is_some())) {
+            return Err(Self::new(err, state));
+        }
 
-            message
-                .send(self.receiver, self.flags.bits())
-                .map_or(Poll::Pending, Poll::Ready)
+        while let Some(part) = parts.next() {
+            socket(&state)
+                .send_msg(part, part_flags(parts.peek().is_some()))
+                .map_err(Self::unrecoverable)?;
         }
-    }
 
-    pub(super) struct MessageReceivingFuture<'a, T>
-    where
-        T: sealed::SocketType + sealed::ReceiverFlag + Unpin,
-    {
-        pub(super) receiver: &'a Socket<T>,
+        Ok(state)
     }
 
-    impl<T> Future for MessageReceivingFuture<'_, T>
+    /// Receives a single part message on the socket of a typed socket state. The remaining parts
+    /// of a multipart message are discarded, so that the socket completes the transition. Failing
+    /// to receive the first part returns the state for a retry, failing on a later part drops it.
+    pub(crate) fn recv_msg<T, F>(
+        state: S,
+        socket: fn(&S) -> &Socket<T>,
+        flags: F,
+    ) -> Result<(Message, S), Self>
     where
-        T: sealed::SocketType + sealed::ReceiverFlag + Unpin,
+        T: sealed::SocketType,
+        Socket<T>: Receiver,
+        F: Into<RecvFlags> + Copy,
     {
-        type Output = Message;
-
-        fn poll(self: Pin<&mut Self>, _ctx: &mut std::task::Context<'_>) -> Poll<Self::Output> {
-            self.receiver
-                .socket
-                .recv(RecvFlags::DONT_WAIT.bits())
-                .map(Message::from_raw_msg)
-                .map_or(Poll::Pending, Poll::Ready)
+        let msg = match socket(&state).recv_msg(flags) {
+            Ok(msg) => msg,
+            Err(err) => return Err(Self::new(err, state)),
+        };
+
+        let mut more = msg.get_more();
+        while more {
+            more = socket(&state)
+                .recv_msg(flags)
+                .map_err(Self::unrecoverable)?
+                .get_more();
         }
+
+        Ok((msg, state))
     }
-}
 
-#[repr(transparent)]
-#[derive(Debug, Clone, Copy, From, Default, PartialEq, Eq, PartialOrd, Ord)]
-/// Flags for poll operations on sockets
-pub struct PollEvents(i16);
+    /// The error of the failed operation.
+    pub fn error(&self) -> &ZmqError {
+        &self.error
+    }
 
-bitflags! {
-    impl PollEvents: i16 {
-        /// For 0MQ sockets, at least one message may be received from the `Socket` without
-        /// blocking. For standard sockets this is equivalent to the `POLLIN` flag of the `poll()`
-        /// system call and generally means that at least one byte of data may be read from `fd`
-        /// without blocking.
-        const POLL_IN = 0b0000_0001;
-        /// For 0MQ sockets, at least one message may be sent to the `Socket` without blocking. For
-        /// standard sockets this is equivalent to the `POLLOUT` flag of the `poll()` system call
-        /// and generally means that at least one byte of data may be written to `fd` without
-        /// blocking.
-        const POLL_OUT = 0b0000_0010;
-        /// For standard sockets, this flag is passed to the underlying `poll()` system call and
-        /// generally means that some sort of error condition is present on the socket specified by
-        /// `fd`. For 0MQ sockets this flag has no effect if set in `events`.
-        const POLL_ERR = 0b0000_0100;
-        /// For 0MQ sockets this flags is of no use. For standard sockets this means there isurgent data to read. Refer to the POLLPRI flag for more information. For filedescriptor, refer to your use case: as an example, GPIO interrupts are signaled througha POLLPRI event. This flag has no effect on Windows.
-        const POLL_PRI = 0b0000_1000;
+    /// Returns the typed socket in its unchanged state, unless it was left in an undefined state.
+    pub fn into_state(self) -> Option<S> {
+        self.state.map(|state| *state)
     }
-}
 
-#[cfg(feature = "draft-api")]
-#[repr(transparent)]
-#[derive(Debug, Clone, Copy, From, Default, PartialEq, Eq, PartialOrd, Ord)]
-/// Flags for the [`ReconnectStop`] socket option
-///
-/// [`ReconnectStop`]: SocketOption::ReconnectStop
-pub struct ReconnectStop(i32);
+    /// Returns the error, and the typed socket in its unchanged state, unless it was left in an
+    /// undefined state.
+    pub fn into_parts(self) -> (ZmqError, Option<S>) {
+        (self.error, self.state.map(|state| *state))
+    }
+}
 
-#[cfg(feature = "draft-api")]
-bitflags! {
-    impl ReconnectStop: i32 {
-        /// The [`CONNECTION_REFUSED`] option will stop reconnection when 0MQ receives the
-        /// [`ConnectionRefused`] return code from the connect. This indicates that there is no
-        /// code bound to the specified endpoint.
-        ///
-        /// [`CONNECTION_REFUSED`]: ReconnectStop::CONNECTION_REFUSED
-        /// [`ConnectionRefused`]: crate::ZmqError::ConnectionRefused
-        const CONNECTION_REFUSED = zmq_sys_crate::ZMQ_RECONNECT_STOP_CONN_REFUSED as i32;
-        /// The [`HANDSHAKE_FAILED`] option will stop reconnection if the 0MQ handshake fails. This
-        /// can be used to detect and/or prevent errant connection attempts to non-0MQ sockets.
-        /// Note that when specifying this option you may also want to set [`HandshakeInterval`]
-        /// — the default handshake interval is 30000 (30 seconds), which is typically too large.
-        ///
-        /// [`HANDSHAKE_FAILED`]: ReconnectStop::HANDSHAKE_FAILED
-        /// [`HandshakeInterval`]: SocketOption::HandshakeInterval
-        const HANDSHAKE_FAILED = zmq_sys_crate::ZMQ_RECONNECT_STOP_HANDSHAKE_FAILED as i32;
-        /// The [`AFTER_DISCONNECT`] option will stop reconnection when `disconnect()` has been
-        /// called. This can be useful when the user’s request failed (server not ready), as the
-        /// socket does not need to continue to reconnect after user disconnect actively.
-        ///
-        /// [`AFTER_DISCONNECT`]: ReconnectStop::AFTER_DISCONNECT
-        const AFTER_DISCONNECT = zmq_sys_crate::ZMQ_RECONNECT_STOP_AFTER_DISCONNECT as i32;
-}}
+impl<S> From<TransitionError<S>> for ZmqError {
+    fn from(value: TransitionError<S>) -> Self {
+        value.error
+    }
+}
 
-#[cfg(test)]
-mod socket_tests {
-    use std::{thread, time::Duration};
+#[cfg(feature = "futures")]
+mod futures {
+    use alloc::sync::Arc;
+    use core::{
+        mem,
+        pin::Pin,
+        task::{Context, Poll, ready},
+    };
+    use std::sync::OnceLock;
 
-    #[cfg(feature = "draft-api")]
-    use rstest::*;
+    use ::futures::{Sink, Stream};
+    use async_io::{Async, ReadableOwned};
 
-    #[cfg(feature = "draft-api")]
-    use super::ReconnectStop;
     use super::{
-        DealerSocket, MonitorFlags, MonitorSocketEvent, PairSocket, PollEvents, SendFlags,
+        MultipartReceiver, MultipartSender, PollEvents, RecvFlags, SendFlags, Socket, SocketOption,
     };
-    #[cfg(zmq_has = "gssapi")]
-    use crate::security::GssApiNametype;
+    #[cfg(feature = "draft-api")]
+    use crate::ffi::RawPoller;
     use crate::{
-        prelude::{Context, MonitorReceiver, Sender, ZmqResult},
-        security::SecurityMechanism,
+        ZmqError, ZmqResult,
+        ffi::RawSocket,
+        message::{Message, MultipartMessage},
+        sealed,
     };
 
-    #[test]
-    fn set_affinity_sets_affinity() -> ZmqResult<()> {
-        let context = Context::new()?;
-
-        let socket = PairSocket::from_context(&context)?;
-        socket.set_affinity(42)?;
+    #[cfg(unix)]
+    type RawSocketFd = std::os::fd::RawFd;
+    #[cfg(windows)]
+    type RawSocketFd = std::os::windows::io::RawSocket;
 
-        assert_eq!(socket.affinity()?, 42);
+    /// The `ZMQ_FD` file descriptor of a 0MQ socket. It is owned by `libzmq`, and therefore never
+    /// closed from this side.
+    pub(crate) struct SocketFd(RawSocketFd);
 
-        Ok(())
+    #[cfg(unix)]
+    impl std::os::fd::AsFd for SocketFd {
+        fn as_fd(&self) -> std::os::fd::BorrowedFd<'_> {
+            unsafe { std::os::fd::BorrowedFd::borrow_raw(self.0) }
+        }
     }
 
-    #[cfg(feature = "draft-api")]
-    #[test]
-    fn set_backlog_sets_backlog() -> ZmqResult<()> {
-        let context = Context::new()?;
+    #[cfg(windows)]
+    impl std::os::windows::io::AsSocket for SocketFd {
+        fn as_socket(&self) -> std::os::windows::io::BorrowedSocket<'_> {
+            unsafe { std::os::windows::io::BorrowedSocket::borrow_raw(self.0) }
+        }
+    }
 
-        let socket = PairSocket::from_context(&context)?;
-        socket.set_backlog(42)?;
+    /// File descriptor of a socket, registered with the `async-io` reactor on first use.
+    ///
+    /// Thread-safe socket types do not provide a `ZMQ_FD`. For those, the socket is added to a
+    /// dedicated 0MQ poller instead, whose file descriptor signals changes of the socket state.
+    pub(crate) struct AsyncFd {
+        fd: Arc<Async<SocketFd>>,
+        #[cfg(feature = "draft-api")]
+        poller: Option<RawPoller>,
+    }
+
+    impl AsyncFd {
+        fn from_socket(socket: &RawSocket) -> ZmqResult<Self> {
+            match socket.get_sockopt_int(SocketOption::FileDescriptor.into()) {
+                // libzmq already put the file descriptor into non-blocking mode.
+                Ok(fd) => Ok(Self {
+                    fd: Arc::new(Async::new_nonblocking(SocketFd(fd))?),
+                    #[cfg(feature = "draft-api")]
+                    poller: None,
+                }),
+                #[cfg(feature = "draft-api")]
+                Err(ZmqError::InvalidArgument) => {
+                    let poller = RawPoller::new()?;
+                    poller.add(socket, PollEvents::POLL_IN | PollEvents::POLL_OUT)?;
+                    let fd = poller.fd()?;
+                    Ok(Self {
+                        fd: Arc::new(Async::new_nonblocking(SocketFd(fd))?),
+                        poller: Some(poller),
+                    })
+                }
+                Err(err) => Err(err),
+            }
+        }
 
-        assert_eq!(socket.backlog()?, 42);
+        fn events(&self, socket: &RawSocket) -> ZmqResult<PollEvents> {
+            #[cfg(feature = "draft-api")]
+            if let Some(poller) = &self.poller {
+                return poller.wait(0);
+            }
 
-        Ok(())
+            socket
+                .get_sockopt_int::<i32>(SocketOption::Events.into())?
+                .try_into()
+                .map(PollEvents::from_bits_truncate)
+                .map_err(|_err| ZmqError::InvalidArgument)
+        }
     }
 
-    #[test]
-    fn set_connect_timeout_sets_connect_timeout() -> ZmqResult<()> {
-        let context = Context::new()?;
+    pub(crate) type AsyncSocketFd = OnceLock<ZmqResult<AsyncFd>>;
 
-        let socket = PairSocket::from_context(&context)?;
-        socket.set_connect_timeout(42)?;
+    fn get_async_fd<'a>(async_fd: &'a AsyncSocketFd, socket: &RawSocket) -> ZmqResult<&'a AsyncFd> {
+        async_fd
+            .get_or_init(|| AsyncFd::from_socket(socket))
+            .as_ref()
+            .map_err(Clone::clone)
+    }
 
-        assert_eq!(socket.connect_timeout()?, 42);
+    impl<T: sealed::SocketType> Socket<T> {
+        fn async_fd(&self) -> ZmqResult<&AsyncFd> {
+            get_async_fd(&self.async_fd, &self.socket)
+        }
+    }
 
-        Ok(())
+    /// Readiness tracking for a single pending operation on a 0MQ socket.
+    ///
+    /// The registered file descriptor only signals that the socket state might have changed. The
+    /// actual state is therefore always re-checked, which also resets the signal.
+    #[derive(Default)]
+    pub(super) struct Readiness {
+        readable: Option<ReadableOwned<SocketFd>>,
     }
 
-    #[test]
-    fn events_when_no_events_available() -> ZmqResult<()> {
-        let context = Context::new()?;
+    impl Readiness {
+        pub(super) fn poll_ready<T>(
+            &mut self,
+            socket: &Socket<T>,
+            ctx: &mut Context<'_>,
+            interest: PollEvents,
+        ) -> Poll<ZmqResult<()>>
+        where
+            T: sealed::SocketType,
+        {
+            self.poll_raw_ready(socket.async_fd()?, &socket.socket, ctx, interest)
+        }
 
-        let socket = PairSocket::from_context(&context)?;
+        fn poll_raw_ready(
+            &mut self,
+            async_fd: &AsyncFd,
+            socket: &RawSocket,
+            ctx: &mut Context<'_>,
+            interest: PollEvents,
+        ) -> Poll<ZmqResult<()>> {
+            loop {
+                // register the waker before checking the events, so that no edge gets lost in
+                // between.
+                let edge = Pin::new(
+                    self.readable
+                        .get_or_insert_with(|| async_fd.fd.clone().readable_owned()),
+                )
+                .poll(ctx);
+                if edge.is_ready() {
+                    self.readable = None;
+                }
 
-        assert_eq!(socket.events()?, PollEvents::empty());
+                if async_fd.events(socket)?.intersects(interest) {
+                    return Poll::Ready(Ok(()));
+                }
 
-        Ok(())
+                ready!(edge)?;
+            }
+        }
+    }
+
+    pub(super) struct MessageSendingFuture<'a, T>
+    where
+        T: sealed::SocketType + sealed::SenderFlag + Unpin,
+    {
+        sender: &'a Socket<T>,
+        message: Message,
+        flags: SendFlags,
+        readiness: Readiness,
+    }
+
+    impl<'a, T> MessageSendingFuture<'a, T>
+    where
+        T: sealed::SocketType + sealed::SenderFlag + Unpin,
+    {
+        pub(super) fn new(sender: &'a Socket<T>, message: Message, flags: SendFlags) -> Self {
+            Self {
+                sender,
+                message,
+                flags: flags | SendFlags::DONT_WAIT,
+                readiness: Readiness::default(),
+            }
+        }
+    }
+
+    impl<T> Future for MessageSendingFuture<'_, T>
+    where
+        T: sealed::SocketType + sealed::SenderFlag + Unpin,
+    {
+        type Output = ZmqResult<()>;
+
+        fn poll(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
+            let Self {
+                sender,
+                message,
+                flags,
+                readiness,
+            } = &mut *self;
+
+            loop {
+                let result = sender.socket.send(&mut message.inner.lock(), flags.bits());
+                match result {
+                    Err(ZmqError::Again) => {
+                        ready!(readiness.poll_ready(*sender, ctx, PollEvents::POLL_OUT))?
+                    }
+                    result => return Poll::Ready(result),
+                }
+            }
+        }
+    }
+
+    pub(super) struct MessageReceivingFuture<'a, T>
+    where
+        T: sealed::SocketType + sealed::ReceiverFlag + Unpin,
+    {
+        receiver: &'a Socket<T>,
+        readiness: Readiness,
+    }
+
+    impl<'a, T> MessageReceivingFuture<'a, T>
+    where
+        T: sealed::SocketType + sealed::ReceiverFlag + Unpin,
+    {
+        pub(super) fn new(receiver: &'a Socket<T>) -> Self {
+            Self {
+                receiver,
+                readiness: Readiness::default(),
+            }
+        }
+    }
+
+    impl<T> Future for MessageReceivingFuture<'_, T>
+    where
+        T: sealed::SocketType + sealed::ReceiverFlag + Unpin,
+    {
+        type Output = ZmqResult<Message>;
+
+        fn poll(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
+            let Self {
+                receiver,
+                readiness,
+            } = &mut *self;
+
+            loop {
+                match receiver.socket.recv(RecvFlags::DONT_WAIT.bits()) {
+                    Err(ZmqError::Again) => {
+                        ready!(readiness.poll_ready(*receiver, ctx, PollEvents::POLL_IN))?
+                    }
+                    result => return Poll::Ready(result.map(Message::from_raw_msg)),
+                }
+            }
+        }
+    }
+
+    /// State of the [`Stream`] and [`Sink`] implementations of a socket.
+    #[derive(Default)]
+    pub(crate) struct StreamState {
+        receive: Readiness,
+        send: Readiness,
+        pending: MultipartMessage,
+    }
+
+    impl StreamState {
+        /// Sends the pending parts without blocking, until all of them are sent.
+        fn poll_send_pending(
+            &mut self,
+            async_fd: &AsyncSocketFd,
+            socket: &RawSocket,
+            ctx: &mut Context<'_>,
+        ) -> Poll<ZmqResult<()>> {
+            while let Some(part) = self.pending.pop_front() {
+                let flags = if self.pending.is_empty() {
+                    SendFlags::DONT_WAIT
+                } else {
+                    SendFlags::DONT_WAIT | SendFlags::SEND_MORE
+                };
+
+                let result = socket.send(&mut part.inner.lock(), flags.bits());
+                match result {
+                    Ok(()) => (),
+                    Err(ZmqError::Again) => {
+                        self.pending.push_front(part);
+                        ready!(self.send.poll_raw_ready(
+                            get_async_fd(async_fd, socket)?,
+                            socket,
+                            ctx,
+                            PollEvents::POLL_OUT
+                        ))?
+                    }
+                    Err(err) => {
+                        self.pending = MultipartMessage::new();
+                        return Poll::Ready(Err(err));
+                    }
+                }
+            }
+
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    impl<T> Socket<T>
+    where
+        T: sealed::SocketType + sealed::SenderFlag + Unpin,
+    {
+        fn poll_send_pending(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<ZmqResult<()>> {
+            let Self {
+                async_fd,
+                socket,
+                stream_state,
+                ..
+            } = self.get_mut();
+
+            stream_state.poll_send_pending(async_fd, socket, ctx)
+        }
+    }
+
+    /// Receives single part messages as a [`Stream`]. The stream ends once the context of the
+    /// socket was terminated.
+    impl<T> Stream for Socket<T>
+    where
+        T: sealed::SocketType + sealed::ReceiverFlag + Unpin,
+    {
+        type Item = ZmqResult<Message>;
+
+        fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            let Self {
+                async_fd,
+                socket,
+                stream_state,
+                ..
+            } = self.get_mut();
+
+            loop {
+                match socket.recv(RecvFlags::DONT_WAIT.bits()) {
+                    Err(ZmqError::Again) => ready!(stream_state.receive.poll_raw_ready(
+                        get_async_fd(async_fd, socket)?,
+                        socket,
+                        ctx,
+                        PollEvents::POLL_IN
+                    ))?,
+                    Err(ZmqError::ContextTerminated) => return Poll::Ready(None),
+                    result => return Poll::Ready(Some(result.map(Message::from_raw_msg))),
+                }
+            }
+        }
+    }
+
+    /// Sends single part messages through a [`Sink`]. Sending a message only completes once the
+    /// socket accepts it without blocking, so that [`poll_ready()`] applies backpressure whenever
+    /// a send operation with [`DONT_WAIT`] would fail with [`Again`].
+    ///
+    /// [`poll_ready()`]: Sink::poll_ready
+    /// [`DONT_WAIT`]: SendFlags::DONT_WAIT
+    /// [`Again`]: ZmqError::Again
+    impl<T> Sink<Message> for Socket<T>
+    where
+        T: sealed::SocketType + sealed::SenderFlag + Unpin,
+    {
+        type Error = ZmqError;
+
+        fn poll_ready(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<ZmqResult<()>> {
+            self.poll_send_pending(ctx)
+        }
+
+        fn start_send(self: Pin<&mut Self>, item: Message) -> ZmqResult<()> {
+            self.get_mut().stream_state.pending.push_back(item);
+
+            Ok(())
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<ZmqResult<()>> {
+            self.poll_send_pending(ctx)
+        }
+
+        fn poll_close(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<ZmqResult<()>> {
+            self.poll_send_pending(ctx)
+        }
+    }
+
+    /// Sends multipart messages through a [`Sink`], with the same backpressure as for single part
+    /// messages.
+    impl<T> Sink<MultipartMessage> for Socket<T>
+    where
+        T: sealed::SocketType + sealed::SenderFlag + Unpin,
+        Socket<T>: MultipartSender,
+    {
+        type Error = ZmqError;
+
+        fn poll_ready(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<ZmqResult<()>> {
+            self.poll_send_pending(ctx)
+        }
+
+        fn start_send(self: Pin<&mut Self>, item: MultipartMessage) -> ZmqResult<()> {
+            let pending = &mut self.get_mut().stream_state.pending;
+            item.into_iter().for_each(|part| pending.push_back(part));
+
+            Ok(())
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<ZmqResult<()>> {
+            self.poll_send_pending(ctx)
+        }
+
+        fn poll_close(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<ZmqResult<()>> {
+            self.poll_send_pending(ctx)
+        }
+    }
+
+    /// # Stream of multipart messages
+    ///
+    /// Collects the single parts received from a socket into [`MultipartMessage`]s. Obtained
+    /// through [`multipart_stream()`] or [`into_multipart_stream()`] of sockets that support
+    /// multipart messages.
+    ///
+    /// [`multipart_stream()`]: Socket::multipart_stream
+    /// [`into_multipart_stream()`]: Socket::into_multipart_stream
+    pub struct MultipartStream<S> {
+        stream: S,
+        parts: MultipartMessage,
+    }
+
+    impl<S> Stream for MultipartStream<S>
+    where
+        S: Stream<Item = ZmqResult<Message>> + Unpin,
+    {
+        type Item = ZmqResult<MultipartMessage>;
+
+        fn poll_next(self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            let Self { stream, parts } = self.get_mut();
+
+            loop {
+                match ready!(Pin::new(&mut *stream).poll_next(ctx)) {
+                    Some(Ok(part)) => {
+                        let got_more = part.get_more();
+                        parts.push_back(part);
+                        if !got_more {
+                            return Poll::Ready(Some(Ok(mem::take(parts))));
+                        }
+                    }
+                    Some(Err(err)) => return Poll::Ready(Some(Err(err))),
+                    None => return Poll::Ready(None),
+                }
+            }
+        }
+    }
+
+    impl<T> Socket<T>
+    where
+        T: sealed::SocketType + sealed::ReceiverFlag + Unpin,
+        Socket<T>: MultipartReceiver,
+    {
+        /// Borrows the socket as a [`Stream`] of multipart messages.
+        pub fn multipart_stream(&mut self) -> MultipartStream<&mut Self> {
+            MultipartStream {
+                stream: self,
+                parts: MultipartMessage::new(),
+            }
+        }
+
+        /// Turns the socket into a [`Stream`] of multipart messages.
+        pub fn into_multipart_stream(self) -> MultipartStream<Self> {
+            MultipartStream {
+                stream: self,
+                parts: MultipartMessage::new(),
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod stream_sink_tests {
+        use ::futures::{SinkExt, StreamExt, executor::block_on, poll};
+
+        use crate::{
+            ZmqResult,
+            context::Context,
+            message::{Message, MultipartMessage},
+            socket::{
+                MultipartReceiver, PairSocket, PullSocket, PushSocket, Receiver, RecvFlags,
+                SendFlags, Sender,
+            },
+        };
+
+        #[test]
+        fn stream_receives_messages() -> ZmqResult<()> {
+            let context = Context::new()?;
+
+            let mut receiver = PairSocket::from_context(&context)?;
+            receiver.bind("inproc://stream-receives-messages")?;
+
+            let sender = PairSocket::from_context(&context)?;
+            sender.connect("inproc://stream-receives-messages")?;
+            sender.send_msg("Hello", SendFlags::empty())?;
+            sender.send_msg("World", SendFlags::empty())?;
+
+            block_on(async {
+                let messages = receiver
+                    .by_ref()
+                    .take(2)
+                    .map(|msg| msg.map(|msg| msg.to_string()))
+                    .collect::<Vec<_>>()
+                    .await;
+                assert_eq!(messages, vec![Ok("Hello".into()), Ok("World".into())]);
+
+                Ok(())
+            })
+        }
+
+        #[test]
+        fn multipart_stream_receives_multipart_messages() -> ZmqResult<()> {
+            let context = Context::new()?;
+
+            let receiver = PullSocket::from_context(&context)?;
+            receiver.bind("inproc://multipart-stream-receives")?;
+
+            let mut sender = PushSocket::from_context(&context)?;
+            sender.connect("inproc://multipart-stream-receives")?;
+
+            block_on(async {
+                let multipart: MultipartMessage =
+                    vec![Message::from("Hello"), Message::from("World")].into();
+                sender.send(multipart).await?;
+                sender.send(Message::from("single")).await?;
+
+                let mut stream = receiver.into_multipart_stream();
+
+                let multipart = stream.next().await.unwrap()?;
+                assert_eq!(multipart.len(), 2);
+                assert_eq!(multipart.get(0).unwrap().to_string(), "Hello");
+                assert_eq!(multipart.get(1).unwrap().to_string(), "World");
+
+                let multipart = stream.next().await.unwrap()?;
+                assert_eq!(multipart.len(), 1);
+                assert_eq!(multipart.get(0).unwrap().to_string(), "single");
+
+                Ok(())
+            })
+        }
+
+        #[test]
+        fn sink_sends_messages() -> ZmqResult<()> {
+            let context = Context::new()?;
+
+            let receiver = PairSocket::from_context(&context)?;
+            receiver.bind("inproc://sink-sends-messages")?;
+
+            let mut sender = PairSocket::from_context(&context)?;
+            sender.connect("inproc://sink-sends-messages")?;
+
+            block_on(async {
+                sender.feed(Message::from("Hello")).await?;
+                sender.feed(Message::from("World")).await?;
+                SinkExt::<Message>::flush(&mut sender).await?;
+
+                Ok::<_, crate::ZmqError>(())
+            })?;
+
+            assert_eq!(receiver.recv_msg(RecvFlags::empty())?.to_string(), "Hello");
+            assert_eq!(receiver.recv_msg(RecvFlags::empty())?.to_string(), "World");
+
+            Ok(())
+        }
+
+        #[test]
+        fn sink_applies_backpressure_without_peer() -> ZmqResult<()> {
+            let context = Context::new()?;
+
+            let mut sender = PushSocket::from_context(&context)?;
+            sender.bind("inproc://sink-applies-backpressure")?;
+
+            let receiver = PullSocket::from_context(&context)?;
+
+            block_on(async {
+                let mut send = sender.send(Message::from("Hello"));
+                assert!(poll!(&mut send).is_pending());
+
+                receiver.connect("inproc://sink-applies-backpressure")?;
+                send.await?;
+
+                let multipart = receiver.recv_multipart_async().await?;
+                assert_eq!(multipart.get(0).unwrap().to_string(), "Hello");
+
+                Ok(())
+            })
+        }
+    }
+}
+
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, From, Default, PartialEq, Eq, PartialOrd, Ord)]
+/// Flags for poll operations on sockets
+pub struct PollEvents(i16);
+
+bitflags! {
+    impl PollEvents: i16 {
+        /// For 0MQ sockets, at least one message may be received from the `Socket` without
+        /// blocking. For standard sockets this is equivalent to the `POLLIN` flag of the `poll()`
+        /// system call and generally means that at least one byte of data may be read from `fd`
+        /// without blocking.
+        const POLL_IN = 0b0000_0001;
+        /// For 0MQ sockets, at least one message may be sent to the `Socket` without blocking. For
+        /// standard sockets this is equivalent to the `POLLOUT` flag of the `poll()` system call
+        /// and generally means that at least one byte of data may be written to `fd` without
+        /// blocking.
+        const POLL_OUT = 0b0000_0010;
+        /// For standard sockets, this flag is passed to the underlying `poll()` system call and
+        /// generally means that some sort of error condition is present on the socket specified by
+        /// `fd`. For 0MQ sockets this flag has no effect if set in `events`.
+        const POLL_ERR = 0b0000_0100;
+        /// For 0MQ sockets this flags is of no use. For standard sockets this means there isurgent data to read. Refer to the POLLPRI flag for more information. For filedescriptor, refer to your use case: as an example, GPIO interrupts are signaled througha POLLPRI event. This flag has no effect on Windows.
+        const POLL_PRI = 0b0000_1000;
+    }
+}
+
+#[cfg(feature = "draft-api")]
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, From, Default, PartialEq, Eq, PartialOrd, Ord)]
+/// Flags for the [`ReconnectStop`] socket option
+///
+/// [`ReconnectStop`]: SocketOption::ReconnectStop
+pub struct ReconnectStop(i32);
+
+#[cfg(feature = "draft-api")]
+bitflags! {
+    impl ReconnectStop: i32 {
+        /// The [`CONNECTION_REFUSED`] option will stop reconnection when 0MQ receives the
+        /// [`ConnectionRefused`] return code from the connect. This indicates that there is no
+        /// code bound to the specified endpoint.
+        ///
+        /// [`CONNECTION_REFUSED`]: ReconnectStop::CONNECTION_REFUSED
+        /// [`ConnectionRefused`]: crate::ZmqError::ConnectionRefused
+        const CONNECTION_REFUSED = zmq_sys_crate::ZMQ_RECONNECT_STOP_CONN_REFUSED as i32;
+        /// The [`HANDSHAKE_FAILED`] option will stop reconnection if the 0MQ handshake fails. This
+        /// can be used to detect and/or prevent errant connection attempts to non-0MQ sockets.
+        /// Note that when specifying this option you may also want to set [`HandshakeInterval`]
+        /// — the default handshake interval is 30000 (30 seconds), which is typically too large.
+        ///
+        /// [`HANDSHAKE_FAILED`]: ReconnectStop::HANDSHAKE_FAILED
+        /// [`HandshakeInterval`]: SocketOption::HandshakeInterval
+        const HANDSHAKE_FAILED = zmq_sys_crate::ZMQ_RECONNECT_STOP_HANDSHAKE_FAILED as i32;
+        /// The [`AFTER_DISCONNECT`] option will stop reconnection when `disconnect()` has been
+        /// called. This can be useful when the user’s request failed (server not ready), as the
+        /// socket does not need to continue to reconnect after user disconnect actively.
+        ///
+        /// [`AFTER_DISCONNECT`]: ReconnectStop::AFTER_DISCONNECT
+        const AFTER_DISCONNECT = zmq_sys_crate::ZMQ_RECONNECT_STOP_AFTER_DISCONNECT as i32;
+}}
+
+#[cfg(test)]
+mod socket_tests {
+    use std::{thread, time::Duration};
+
+    #[cfg(feature = "draft-api")]
+    use rstest::*;
+
+    #[cfg(any(feature = "futures", feature = "draft-api"))]
+    use super::RecvFlags;
+    use super::{
+        DealerSocket, MonitorFlags, MonitorSocketEvent, PairSocket, PollEvents, SendFlags,
+    };
+    #[cfg(feature = "draft-api")]
+    use super::{MonitorEventV2, ReconnectStop};
+    #[cfg(any(feature = "futures", feature = "draft-api"))]
+    use crate::prelude::Receiver;
+    #[cfg(zmq_has = "gssapi")]
+    use crate::security::GssApiNametype;
+    use crate::{
+        prelude::{Context, Endpoint, MonitorReceiver, Sender, ZmqError, ZmqResult},
+        security::SecurityMechanism,
+    };
+
+    #[test]
+    fn set_affinity_sets_affinity() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let socket = PairSocket::from_context(&context)?;
+        socket.set_affinity(42)?;
+
+        assert_eq!(socket.affinity()?, 42);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "draft-api")]
+    #[test]
+    fn set_backlog_sets_backlog() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let socket = PairSocket::from_context(&context)?;
+        socket.set_backlog(42)?;
+
+        assert_eq!(socket.backlog()?, 42);
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_connect_timeout_sets_connect_timeout() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let socket = PairSocket::from_context(&context)?;
+        socket.set_connect_timeout(42)?;
+
+        assert_eq!(socket.connect_timeout()?, 42);
+
+        Ok(())
+    }
+
+    #[test]
+    fn events_when_no_events_available() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let socket = PairSocket::from_context(&context)?;
+
+        assert_eq!(socket.events()?, PollEvents::empty());
+
+        Ok(())
     }
 
     #[test]
@@ -3986,7 +4855,7 @@ mod socket_tests {
 
         let socket = PairSocket::from_context(&context)?;
 
-        assert_eq!(socket.last_endpoint()?, "");
+        assert_eq!(socket.last_endpoint()?, None);
 
         Ok(())
     }
@@ -3998,7 +4867,58 @@ mod socket_tests {
         let socket = PairSocket::from_context(&context)?;
         socket.bind("inproc://last-endpoint-test")?;
 
-        assert_eq!(socket.last_endpoint()?, "inproc://last-endpoint-test");
+        assert_eq!(
+            socket.last_endpoint()?,
+            Some(Endpoint::Inproc {
+                name: "last-endpoint-test".to_string()
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn last_endpoint_resolves_wildcard_port() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let socket = PairSocket::from_context(&context)?;
+        socket.bind("tcp://127.0.0.1:*")?;
+
+        let endpoint = socket.last_endpoint()?.unwrap();
+        assert!(endpoint.port().is_some_and(|port| port != 0));
+        assert_eq!(
+            endpoint.to_string(),
+            format!("tcp://127.0.0.1:{}", endpoint.port().unwrap())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn bind_with_invalid_endpoint() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let socket = PairSocket::from_context(&context)?;
+
+        let result = socket.bind("tcp://127.0.0.1");
+        assert!(result.is_err_and(|err| err == ZmqError::InvalidArgument));
+
+        Ok(())
+    }
+
+    #[test]
+    fn connect_with_typed_endpoint() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let endpoint = Endpoint::Inproc {
+            name: "typed-endpoint-test".to_string(),
+        };
+
+        let server = PairSocket::from_context(&context)?;
+        server.bind(&endpoint)?;
+
+        let client = PairSocket::from_context(&context)?;
+        client.connect(endpoint)?;
 
         Ok(())
     }
@@ -4361,7 +5281,7 @@ mod socket_tests {
 
         let dealer_server = DealerSocket::from_context(&context)?;
         dealer_server.bind("tcp://127.0.0.1:*")?;
-        let client_endpoint = dealer_server.last_endpoint()?;
+        let client_endpoint = dealer_server.last_endpoint()?.unwrap();
 
         thread::spawn(move || {
             loop {
@@ -4394,7 +5314,7 @@ mod socket_tests {
 
         let dealer_server = DealerSocket::from_context(&context)?;
         dealer_server.bind("tcp://127.0.0.1:*")?;
-        let client_endpoint = dealer_server.last_endpoint()?;
+        let client_endpoint = dealer_server.last_endpoint()?.unwrap();
 
         thread::spawn(move || {
             loop {
@@ -4408,18 +5328,175 @@ mod socket_tests {
 
             dealer_client.connect(client_endpoint)?;
 
+            let event = dealer_monitor.recv_monitor_event_async().await?;
+            assert_eq!(event, MonitorSocketEvent::Connected);
+
+            Ok(())
+        })
+    }
+
+    #[cfg(feature = "draft-api")]
+    #[test]
+    fn monitor_versioned_sets_up_socket_monitor_with_endpoints() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let dealer_server = DealerSocket::from_context(&context)?;
+        dealer_server.bind("tcp://127.0.0.1:*")?;
+        let client_endpoint = dealer_server.last_endpoint()?.unwrap();
+
+        let dealer_client = DealerSocket::from_context(&context)?;
+        let dealer_monitor = dealer_client.monitor_versioned(MonitorFlags::Connected)?;
+
+        dealer_client.connect(&client_endpoint)?;
+
+        let event = loop {
+            match dealer_monitor.recv_monitor_event() {
+                Err(_) => continue,
+                Ok(event) => break event,
+            }
+        };
+
+        assert!(matches!(event.event, MonitorEventV2::Connected(_)));
+        assert_eq!(event.remote_endpoint, client_endpoint.to_string());
+        assert!(event.local_endpoint.starts_with("tcp://127.0.0.1:"));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "draft-api")]
+    #[test]
+    fn request_pipes_stats_without_versioned_monitor() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let socket = DealerSocket::from_context(&context)?;
+
+        let result = socket.request_pipes_stats();
+        assert!(result.is_err_and(|err| err == ZmqError::InvalidArgument));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "draft-api")]
+    #[test]
+    fn request_pipes_stats_emits_pipes_stats_event() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let dealer_server = DealerSocket::from_context(&context)?;
+        dealer_server.bind("tcp://127.0.0.1:*")?;
+        let client_endpoint = dealer_server.last_endpoint()?.unwrap();
+
+        let dealer_client = DealerSocket::from_context(&context)?;
+        let dealer_monitor = dealer_client.monitor_versioned(MonitorFlags::Connected)?;
+        dealer_client.connect(&client_endpoint)?;
+
+        loop {
+            if let Ok(event) = dealer_monitor.recv_monitor_event() {
+                assert!(matches!(event.event, MonitorEventV2::Connected(_)));
+                break;
+            }
+        }
+
+        dealer_client.send_msg("Hello", SendFlags::empty())?;
+        dealer_server.recv_msg(RecvFlags::empty())?;
+
+        dealer_client.request_pipes_stats()?;
+
+        let event = loop {
+            dealer_client.events()?;
+            if let Ok(event) = dealer_monitor.recv_monitor_event() {
+                break event;
+            }
+        };
+
+        assert!(matches!(event.event, MonitorEventV2::PipesStats { .. }));
+        assert_eq!(event.remote_endpoint, client_endpoint.to_string());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "futures")]
+    #[test]
+    fn recv_msg_async_is_pending_without_message() -> ZmqResult<()> {
+        use futures::FutureExt;
+
+        let context = Context::new()?;
+
+        let pair_server = PairSocket::from_context(&context)?;
+        pair_server.bind("inproc://recv-msg-async-pending")?;
+
+        let pair_client = PairSocket::from_context(&context)?;
+        pair_client.connect("inproc://recv-msg-async-pending")?;
+
+        assert!(pair_server.recv_msg_async().now_or_never().is_none());
+
+        pair_client.send_msg("Hello", SendFlags::empty())?;
+
+        let msg = futures::executor::block_on(pair_server.recv_msg_async())?;
+        assert_eq!(msg.to_string(), "Hello");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "futures")]
+    #[test]
+    fn recv_msg_async_wakes_up_on_incoming_message() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let pair_server = PairSocket::from_context(&context)?;
+        pair_server.bind("tcp://127.0.0.1:*")?;
+        let client_endpoint = pair_server.last_endpoint()?.unwrap();
+
+        let pair_client = PairSocket::from_context(&context)?;
+        pair_client.connect(client_endpoint)?;
+
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(100));
+            pair_client.send_msg("Hello", SendFlags::empty())
+        });
+
+        let msg = futures::executor::block_on(pair_server.recv_msg_async())?;
+        assert_eq!(msg.to_string(), "Hello");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "futures")]
+    #[test]
+    fn send_msg_async_wakes_up_when_queue_drains() -> ZmqResult<()> {
+        use futures::FutureExt;
+
+        let context = Context::new()?;
+
+        let pair_server = PairSocket::from_context(&context)?;
+        pair_server.set_receive_highwater_mark(1)?;
+        pair_server.bind("inproc://send-msg-async-wakes-up")?;
+
+        let pair_client = PairSocket::from_context(&context)?;
+        pair_client.set_send_highwater_mark(1)?;
+        pair_client.connect("inproc://send-msg-async-wakes-up")?;
+
+        while pair_client.send_msg("Hello", SendFlags::DONT_WAIT).is_ok() {}
+
+        assert!(
+            pair_client
+                .send_msg_async("World", SendFlags::empty())
+                .now_or_never()
+                .is_none()
+        );
+
+        let handle = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(100));
             loop {
-                match dealer_monitor.recv_monitor_event_async().await {
-                    Some(event) => {
-                        assert_eq!(event, MonitorSocketEvent::Connected);
-                        break;
-                    }
-                    _ => continue,
+                let msg = pair_server.recv_msg(RecvFlags::empty())?;
+                if msg.to_string() == "World" {
+                    return Ok::<_, crate::ZmqError>(());
                 }
             }
+        });
 
-            Ok(())
-        })
+        futures::executor::block_on(pair_client.send_msg_async("World", SendFlags::empty()))?;
+
+        handle.join().unwrap()
     }
 
     #[test]
@@ -4460,8 +5537,8 @@ pub(crate) mod builder {
     use serde::{Deserialize, Serialize};
 
     use crate::{
-        ZmqResult, auth::ZapDomain, context::Context, sealed, security::SecurityMechanism,
-        socket::Socket,
+        ZmqError, ZmqResult, auth::ZapDomain, context::Context, sealed,
+        security::SecurityMechanism, socket::Socket,
     };
 
     #[derive(Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Builder)]
@@ -4638,6 +5715,44 @@ pub(crate) mod builder {
         }
     }
 
+    impl<T> TryFrom<&Socket<T>> for SocketBuilder
+    where
+        T: sealed::SocketType,
+    {
+        type Error = ZmqError;
+
+        /// Reads back all options of the socket that can be retrieved.
+        fn try_from(socket: &Socket<T>) -> Result<Self, Self::Error> {
+            Ok(Self {
+                #[cfg(feature = "draft-api")]
+                busy_poll: None,
+                connect_timeout: Some(socket.connect_timeout()?),
+                handshake_interval: Some(socket.handshake_interval()?),
+                heartbeat_interval: Some(socket.heartbeat_interval()?),
+                // an unset heartbeat timeout reads back as -1, which can't be set again
+                heartbeat_timeout: Some(socket.heartbeat_timeout()?)
+                    .filter(|timeout| *timeout >= 0),
+                heartbeat_timetolive: Some(socket.heartbeat_timetolive()?),
+                immediate: Some(socket.immediate()?),
+                ipv6: Some(socket.ipv6()?),
+                linger: Some(socket.linger()?),
+                max_message_size: Some(socket.max_message_size()?),
+                receive_buffer: Some(socket.receive_buffer()?),
+                receive_highwater_mark: Some(socket.receive_highwater_mark()?),
+                receive_timeout: Some(socket.receive_timeout()?),
+                reconnect_interval: Some(socket.reconnect_interval()?),
+                reconnect_interval_max: Some(socket.reconnect_interval_max()?),
+                send_buffer: Some(socket.send_buffer()?),
+                send_highwater_mark: Some(socket.send_highwater_mark()?),
+                send_timeout: Some(socket.send_timeout()?),
+                // an empty ZAP domain reads back fine, but is rejected when set again
+                zap_domain: Some(socket.zap_domain()?)
+                    .filter(|zap_domain| *zap_domain != ZapDomain::default()),
+                security_mechanism: Some(socket.security_mechanism()?),
+            })
+        }
+    }
+
     #[cfg(test)]
     mod socket_builder_tests {
         use super::SocketBuilder;
@@ -4738,5 +5853,65 @@ pub(crate) mod builder {
 
             Ok(())
         }
+
+        #[test]
+        fn builder_from_socket() -> ZmqResult<()> {
+            let context = Context::new()?;
+
+            let builder = SocketBuilder::default()
+                .connect_timeout(42)
+                .heartbeat_interval(666)
+                .immediate(true)
+                .linger(1337)
+                .receive_highwater_mark(1337)
+                .send_timeout(1337)
+                .zap_domain(ZapDomain::new("test".into()))
+                .security_mechanism(SecurityMechanism::Plain {
+                    username: "username".into(),
+                    password: "supersecret".into(),
+                });
+            let socket = PairSocket::from_context(&context)?;
+            builder.apply(&socket)?;
+
+            let read_back = SocketBuilder::try_from(&socket)?;
+            assert!(
+                read_back
+                    == SocketBuilder::default()
+                        .connect_timeout(42)
+                        .handshake_interval(30_000)
+                        .heartbeat_interval(666)
+                        .heartbeat_timetolive(0)
+                        .immediate(true)
+                        .ipv6(false)
+                        .linger(1337)
+                        .max_message_size(-1)
+                        .receive_buffer(-1)
+                        .receive_highwater_mark(1337)
+                        .receive_timeout(-1)
+                        .reconnect_interval(100)
+                        .reconnect_interval_max(0)
+                        .send_buffer(-1)
+                        .send_highwater_mark(1_000)
+                        .send_timeout(1337)
+                        .zap_domain(ZapDomain::new("test".into()))
+                        .security_mechanism(SecurityMechanism::Plain {
+                            username: "username".into(),
+                            password: "supersecret".into(),
+                        })
+            );
+
+            let other_socket = PairSocket::from_context(&context)?;
+            read_back.apply(&other_socket)?;
+            assert_eq!(other_socket.linger()?, 1337);
+            assert_eq!(
+                other_socket.security_mechanism()?,
+                SecurityMechanism::Plain {
+                    username: "username".into(),
+                    password: "supersecret".into()
+                }
+            );
+
+            Ok(())
+        }
     }
 }
@@ -1,12 +1,15 @@
 #[cfg(feature = "futures")]
-use core::{pin::Pin, task::Context, task::Poll};
+use core::{
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
 
 #[cfg(feature = "futures")]
 use async_trait::async_trait;
-#[cfg(feature = "futures")]
-use futures::FutureExt;
 
 use super::{MonitorFlags, MultipartReceiver, RecvFlags, SocketType};
+#[cfg(feature = "futures")]
+use super::{PollEvents, futures::Readiness};
 use crate::{
     ZmqError, ZmqResult, message::MultipartMessage, sealed, socket::Socket, zmq_sys_crate,
 };
@@ -436,6 +439,331 @@ mod monitor_socket_event_tests {
     }
 }
 
+#[cfg(feature = "draft-api")]
+#[derive(Debug, Clone, PartialEq)]
+/// Monitor events that can be received from a versioned monitor socket. In contrast to
+/// [`MonitorSocketEvent`], all event values are provided as 64-bit values.
+pub enum MonitorEventV2 {
+    /// The socket has successfully connected to a remote peer. The event value is the file
+    /// descriptor (FD) of the underlying network socket.
+    ///
+    /// <div class="warning">
+    ///
+    /// Warning:
+    ///
+    /// There is no guarantee that the FD is still valid by the time your code receives this
+    /// event.
+    ///
+    /// </div>
+    Connected(u64),
+    /// A connect request on the socket is pending. The event value is unspecified.
+    ConnectDelayed,
+    /// A connect request failed, and is now being retried. The event value is the reconnect
+    /// interval in milliseconds.
+    ///
+    /// Note that the reconnect interval is recalculated at each retry.
+    ConnectRetried(u64),
+    /// The socket was successfully bound to a network interface. The event value is the FD of
+    /// the underlying network socket.
+    ///
+    /// <div class="warning">
+    ///
+    /// Warning:
+    ///
+    /// There is no guarantee that the FD is still valid by the time your code receives this
+    /// event.
+    ///
+    /// </div>
+    Listening(u64),
+    /// The socket could not bind to a given interface. The event value is the errno generated
+    /// by the system bind call.
+    BindFailed(ZmqError),
+    /// The socket has accepted a connection from a remote peer. The event value is the FD of
+    /// the underlying network socket.
+    ///
+    /// <div class="warning">
+    ///
+    /// Warning:
+    ///
+    /// There is no guarantee that the FD is still valid by the time your code receives this
+    /// event.
+    ///
+    /// </div>
+    Accepted(u64),
+    /// The socket has rejected a connection from a remote peer. The event value is the errno
+    /// generated by the accept call.
+    AcceptFailed(ZmqError),
+    /// The socket was closed. The event value is the FD of the (now closed) network socket.
+    Closed(u64),
+    /// The socket close failed. The event value is the errno returned by the system call.
+    ///
+    /// Note that this event occurs only on IPC transports.
+    CloseFailed(ZmqError),
+    /// The socket was disconnected unexpectedly. The event value is the FD of the underlying
+    /// network socket.
+    ///
+    /// <div class="warning">
+    ///
+    /// Warning:
+    ///
+    /// This socket will be closed.
+    ///
+    /// </div>
+    Disconnected(u64),
+    /// Monitoring on this socket ended.
+    MonitorStopped,
+    /// Unspecified error during handshake. The event value is an errno.
+    HandshakeFailedNoDetail(ZmqError),
+    /// The ZMTP security mechanism handshake succeeded. The event value is unspecified.
+    HandshakeSucceeded,
+    /// The ZMTP security mechanism handshake failed due to some mechanism protocol error,
+    /// either between the ZMTP mechanism peers, or between the mechanism server and the ZAP
+    /// handler. This indicates a configuration or implementation error in either peer resp.
+    /// the ZAP handler.
+    HandshakeFailedProtocol(HandshakeProtocolError),
+    /// The ZMTP security mechanism handshake failed due to an authentication failure. The
+    /// event value is the status code returned by the ZAP handler (i.e. `300`, `400` or `500`).
+    HandshakeFailedAuth(u64),
+    /// Queue statistics of a single connection, as requested through [`request_pipes_stats()`].
+    ///
+    /// [`request_pipes_stats()`]: Socket::request_pipes_stats
+    PipesStats {
+        /// Number of messages queued for sending to the peer
+        outbound_queue_count: u64,
+        /// Number of messages received from the peer, that are not yet read by the application
+        inbound_queue_count: u64,
+    },
+    /// An event not known to this crate. Contains the raw event id and the raw event values as
+    /// sent by `libzmq`.
+    UnSupported(u64, Vec<u64>),
+}
+
+#[cfg(feature = "draft-api")]
+impl MonitorEventV2 {
+    fn from_raw(event_id: u64, values: Vec<u64>) -> Self {
+        let first_value = values.first().copied().unwrap_or_default();
+
+        match (event_id as u32, values.as_slice()) {
+            (zmq_sys_crate::ZMQ_EVENT_CONNECTED, _) => Self::Connected(first_value),
+            (zmq_sys_crate::ZMQ_EVENT_CONNECT_DELAYED, _) => Self::ConnectDelayed,
+            (zmq_sys_crate::ZMQ_EVENT_CONNECT_RETRIED, _) => Self::ConnectRetried(first_value),
+            (zmq_sys_crate::ZMQ_EVENT_LISTENING, _) => Self::Listening(first_value),
+            (zmq_sys_crate::ZMQ_EVENT_BIND_FAILED, _) => {
+                Self::BindFailed(ZmqError::from(first_value as i32))
+            }
+            (zmq_sys_crate::ZMQ_EVENT_ACCEPTED, _) => Self::Accepted(first_value),
+            (zmq_sys_crate::ZMQ_EVENT_ACCEPT_FAILED, _) => {
+                Self::AcceptFailed(ZmqError::from(first_value as i32))
+            }
+            (zmq_sys_crate::ZMQ_EVENT_CLOSED, _) => Self::Closed(first_value),
+            (zmq_sys_crate::ZMQ_EVENT_CLOSE_FAILED, _) => {
+                Self::CloseFailed(ZmqError::from(first_value as i32))
+            }
+            (zmq_sys_crate::ZMQ_EVENT_DISCONNECTED, _) => Self::Disconnected(first_value),
+            (zmq_sys_crate::ZMQ_EVENT_MONITOR_STOPPED, _) => Self::MonitorStopped,
+            (zmq_sys_crate::ZMQ_EVENT_HANDSHAKE_FAILED_NO_DETAIL, _) => {
+                Self::HandshakeFailedNoDetail(ZmqError::from(first_value as i32))
+            }
+            (zmq_sys_crate::ZMQ_EVENT_HANDSHAKE_SUCCEEDED, _) => Self::HandshakeSucceeded,
+            (zmq_sys_crate::ZMQ_EVENT_HANDSHAKE_FAILED_PROTOCOL, _) => {
+                Self::HandshakeFailedProtocol((first_value as u32).into())
+            }
+            (zmq_sys_crate::ZMQ_EVENT_HANDSHAKE_FAILED_AUTH, _) => {
+                Self::HandshakeFailedAuth(first_value)
+            }
+            (zmq_sys_crate::ZMQ_EVENT_PIPES_STATS, [outbound_queue_count, inbound_queue_count]) => {
+                Self::PipesStats {
+                    outbound_queue_count: *outbound_queue_count,
+                    inbound_queue_count: *inbound_queue_count,
+                }
+            }
+            _ => Self::UnSupported(event_id, values),
+        }
+    }
+}
+
+#[cfg(feature = "draft-api")]
+#[derive(Debug, Clone, PartialEq)]
+/// Monitor event received from a [`MonitorSocketV2`], together with the endpoints of the
+/// affected connection
+pub struct MonitorSocketEventV2 {
+    /// The monitor event
+    pub event: MonitorEventV2,
+    /// Local endpoint of the connection
+    pub local_endpoint: String,
+    /// Remote endpoint of the connection. Empty for events not related to a specific peer, i.e.
+    /// [`Listening`].
+    ///
+    /// [`Listening`]: MonitorEventV2::Listening
+    pub remote_endpoint: String,
+}
+
+#[cfg(feature = "draft-api")]
+impl TryFrom<MultipartMessage> for MonitorSocketEventV2 {
+    type Error = ZmqError;
+
+    fn try_from(zmq_msgs: MultipartMessage) -> Result<Self, Self::Error> {
+        if zmq_msgs.len() < 4 {
+            return Err(ZmqError::InvalidArgument);
+        }
+
+        let mut frames = zmq_msgs.into_iter();
+
+        let Some(event_id) = frames
+            .next()
+            .and_then(|frame| u64_from_frame(&frame.as_slice()))
+        else {
+            return Err(ZmqError::InvalidArgument);
+        };
+
+        let Some(values_count) = frames
+            .next()
+            .and_then(|frame| u64_from_frame(&frame.as_slice()))
+        else {
+            return Err(ZmqError::InvalidArgument);
+        };
+
+        if frames.len() as u64 != values_count + 2 {
+            return Err(ZmqError::InvalidArgument);
+        }
+
+        let values = frames
+            .by_ref()
+            .take(values_count as usize)
+            .map(|frame| u64_from_frame(&frame.as_slice()).ok_or(ZmqError::InvalidArgument))
+            .collect::<ZmqResult<Vec<_>>>()?;
+
+        let (Some(local_endpoint), Some(remote_endpoint)) = (frames.next(), frames.next()) else {
+            unreachable!();
+        };
+
+        Ok(Self {
+            event: MonitorEventV2::from_raw(event_id, values),
+            local_endpoint: String::from_utf8_lossy(&local_endpoint.as_slice()).into_owned(),
+            remote_endpoint: String::from_utf8_lossy(&remote_endpoint.as_slice()).into_owned(),
+        })
+    }
+}
+
+#[cfg(feature = "draft-api")]
+fn u64_from_frame(frame: &[u8]) -> Option<u64> {
+    frame.try_into().ok().map(u64::from_ne_bytes)
+}
+
+#[cfg(all(test, feature = "draft-api"))]
+mod monitor_socket_event_v2_tests {
+    use rstest::*;
+
+    use super::{HandshakeProtocolError, MonitorEventV2, MonitorSocketEventV2};
+    use crate::{
+        prelude::{Message, MultipartMessage, ZmqError},
+        zmq_sys_crate,
+    };
+
+    fn multipart(event_id: u32, values: &[u64], local: &str, remote: &str) -> MultipartMessage {
+        let mut multipart = MultipartMessage::new();
+        multipart.push_back((event_id as u64).to_ne_bytes().to_vec().into());
+        multipart.push_back((values.len() as u64).to_ne_bytes().to_vec().into());
+        values
+            .iter()
+            .for_each(|value| multipart.push_back(value.to_ne_bytes().to_vec().into()));
+        multipart.push_back(local.into());
+        multipart.push_back(remote.into());
+        multipart
+    }
+
+    #[rstest]
+    #[case(zmq_sys_crate::ZMQ_EVENT_CONNECTED, vec![42], MonitorEventV2::Connected(42))]
+    #[case(zmq_sys_crate::ZMQ_EVENT_CONNECT_DELAYED, vec![0], MonitorEventV2::ConnectDelayed)]
+    #[case(
+        zmq_sys_crate::ZMQ_EVENT_CONNECT_RETRIED,
+        vec![u32::MAX as u64 + 1],
+        MonitorEventV2::ConnectRetried(u32::MAX as u64 + 1)
+    )]
+    #[case(zmq_sys_crate::ZMQ_EVENT_LISTENING, vec![42], MonitorEventV2::Listening(42))]
+    #[case(
+        zmq_sys_crate::ZMQ_EVENT_BIND_FAILED,
+        vec![14],
+        MonitorEventV2::BindFailed(ZmqError::ContextInvalid)
+    )]
+    #[case(zmq_sys_crate::ZMQ_EVENT_ACCEPTED, vec![42], MonitorEventV2::Accepted(42))]
+    #[case(zmq_sys_crate::ZMQ_EVENT_CLOSED, vec![42], MonitorEventV2::Closed(42))]
+    #[case(zmq_sys_crate::ZMQ_EVENT_DISCONNECTED, vec![42], MonitorEventV2::Disconnected(42))]
+    #[case(zmq_sys_crate::ZMQ_EVENT_MONITOR_STOPPED, vec![0], MonitorEventV2::MonitorStopped)]
+    #[case(
+        zmq_sys_crate::ZMQ_EVENT_HANDSHAKE_FAILED_PROTOCOL,
+        vec![zmq_sys_crate::ZMQ_PROTOCOL_ERROR_ZMTP_UNSPECIFIED as u64],
+        MonitorEventV2::HandshakeFailedProtocol(HandshakeProtocolError::ZmtpUnspecified)
+    )]
+    #[case(
+        zmq_sys_crate::ZMQ_EVENT_HANDSHAKE_FAILED_AUTH,
+        vec![400],
+        MonitorEventV2::HandshakeFailedAuth(400)
+    )]
+    #[case(
+        zmq_sys_crate::ZMQ_EVENT_PIPES_STATS,
+        vec![3, 5],
+        MonitorEventV2::PipesStats { outbound_queue_count: 3, inbound_queue_count: 5 }
+    )]
+    #[case(0x20000, vec![1, 2, 3], MonitorEventV2::UnSupported(0x20000, vec![1, 2, 3]))]
+    fn try_from_multipart_succeeds(
+        #[case] event_id: u32,
+        #[case] values: Vec<u64>,
+        #[case] expected: MonitorEventV2,
+    ) {
+        let multipart = multipart(
+            event_id,
+            &values,
+            "tcp://127.0.0.1:5555",
+            "tcp://127.0.0.1:6666",
+        );
+
+        assert_eq!(
+            MonitorSocketEventV2::try_from(multipart),
+            Ok(MonitorSocketEventV2 {
+                event: expected,
+                local_endpoint: "tcp://127.0.0.1:5555".to_string(),
+                remote_endpoint: "tcp://127.0.0.1:6666".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn try_from_multipart_with_too_few_parts() {
+        let multipart: MultipartMessage = vec!["asdf".into(), "asdf".into()].into();
+        let result = MonitorSocketEventV2::try_from(multipart);
+
+        assert!(result.is_err_and(|err| err == ZmqError::InvalidArgument));
+    }
+
+    #[test]
+    fn try_from_multipart_with_mismatching_values_count() {
+        let mut multipart = multipart(zmq_sys_crate::ZMQ_EVENT_CONNECTED, &[42], "", "");
+        multipart.push_back(Message::new());
+        let result = MonitorSocketEventV2::try_from(multipart);
+
+        assert!(result.is_err_and(|err| err == ZmqError::InvalidArgument));
+    }
+
+    #[test]
+    fn try_from_multipart_with_malformed_value() {
+        let multipart: MultipartMessage = vec![
+            (zmq_sys_crate::ZMQ_EVENT_CONNECTED as u64)
+                .to_ne_bytes()
+                .to_vec()
+                .into(),
+            1u64.to_ne_bytes().to_vec().into(),
+            vec![1, 2, 3].into(),
+            "".into(),
+            "".into(),
+        ]
+        .into();
+        let result = MonitorSocketEventV2::try_from(multipart);
+
+        assert!(result.is_err_and(|err| err == ZmqError::InvalidArgument));
+    }
+}
+
 /// # A monitor socket `ZMQ_PAIR`
 pub type MonitorSocket = Socket<Monitor>;
 
@@ -456,43 +784,121 @@ impl sealed::SocketType for Monitor {
 
 impl Socket<Monitor> {}
 
+#[cfg(feature = "draft-api")]
+/// # A versioned monitor socket `ZMQ_PAIR`
+///
+/// Monitor socket set up through [`monitor_versioned()`], receiving [`MonitorSocketEventV2`]s.
+///
+/// [`monitor_versioned()`]: Socket::monitor_versioned
+pub type MonitorSocketV2 = Socket<MonitorV2>;
+
+#[cfg(feature = "draft-api")]
+pub struct MonitorV2 {}
+
+#[cfg(feature = "draft-api")]
+impl sealed::ReceiverFlag for MonitorV2 {}
+
+#[cfg(feature = "draft-api")]
+unsafe impl Sync for Socket<MonitorV2> {}
+#[cfg(feature = "draft-api")]
+unsafe impl Send for Socket<MonitorV2> {}
+
+#[cfg(feature = "draft-api")]
+impl MultipartReceiver for Socket<MonitorV2> {}
+
+#[cfg(feature = "draft-api")]
+impl sealed::SocketType for MonitorV2 {
+    fn raw_socket_type() -> SocketType {
+        SocketType::Pair
+    }
+}
+
 #[cfg_attr(feature = "futures", async_trait)]
 /// Trait for receiving [`MonitorSocketEvent`] from a monitor socket
 ///
 /// [`MonitorSocketEvent`]: MonitorSocketEvent
 pub trait MonitorReceiver {
-    fn recv_monitor_event(&self) -> ZmqResult<MonitorSocketEvent>;
+    /// Type of the events received from the monitor socket
+    type Event;
+
+    fn recv_monitor_event(&self) -> ZmqResult<Self::Event>;
 
+    /// Receives a [`MonitorSocketEvent`] without blocking the current thread. The returned future
+    /// only resolves once an event was actually received, or an error other than [`Again`]
+    /// occured.
+    ///
+    /// [`MonitorSocketEvent`]: MonitorSocketEvent
+    /// [`Again`]: ZmqError::Again
     #[cfg(feature = "futures")]
-    async fn recv_monitor_event_async(&self) -> Option<MonitorSocketEvent>;
+    async fn recv_monitor_event_async(&self) -> ZmqResult<Self::Event>;
 }
 
 #[cfg_attr(feature = "futures", async_trait)]
 impl MonitorReceiver for MonitorSocket {
+    type Event = MonitorSocketEvent;
+
     fn recv_monitor_event(&self) -> ZmqResult<MonitorSocketEvent> {
         self.recv_multipart(RecvFlags::DONT_WAIT)
             .and_then(MonitorSocketEvent::try_from)
     }
 
     #[cfg(feature = "futures")]
-    async fn recv_monitor_event_async(&self) -> Option<MonitorSocketEvent> {
-        MonitorSocketEventFuture { receiver: self }.now_or_never()
+    async fn recv_monitor_event_async(&self) -> ZmqResult<MonitorSocketEvent> {
+        MonitorSocketEventFuture {
+            receiver: self,
+            readiness: Readiness::default(),
+        }
+        .await
     }
 }
 
-#[cfg(feature = "futures")]
-struct MonitorSocketEventFuture<'a> {
-    receiver: &'a MonitorSocket,
+#[cfg(feature = "draft-api")]
+#[cfg_attr(feature = "futures", async_trait)]
+impl MonitorReceiver for MonitorSocketV2 {
+    type Event = MonitorSocketEventV2;
+
+    fn recv_monitor_event(&self) -> ZmqResult<MonitorSocketEventV2> {
+        self.recv_multipart(RecvFlags::DONT_WAIT)
+            .and_then(MonitorSocketEventV2::try_from)
+    }
+
+    #[cfg(feature = "futures")]
+    async fn recv_monitor_event_async(&self) -> ZmqResult<MonitorSocketEventV2> {
+        MonitorSocketEventFuture {
+            receiver: self,
+            readiness: Readiness::default(),
+        }
+        .await
+    }
 }
 
 #[cfg(feature = "futures")]
-impl Future for MonitorSocketEventFuture<'_> {
-    type Output = MonitorSocketEvent;
+struct MonitorSocketEventFuture<'a, T: sealed::SocketType> {
+    receiver: &'a Socket<T>,
+    readiness: Readiness,
+}
 
-    fn poll(self: Pin<&mut Self>, _ctx: &mut Context<'_>) -> Poll<Self::Output> {
-        match self.receiver.recv_monitor_event() {
-            Ok(event) => Poll::Ready(event),
-            _ => Poll::Pending,
+#[cfg(feature = "futures")]
+impl<T> Future for MonitorSocketEventFuture<'_, T>
+where
+    T: sealed::SocketType,
+    Socket<T>: MonitorReceiver,
+{
+    type Output = ZmqResult<<Socket<T> as MonitorReceiver>::Event>;
+
+    fn poll(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
+        let Self {
+            receiver,
+            readiness,
+        } = &mut *self;
+
+        loop {
+            match receiver.recv_monitor_event() {
+                Err(ZmqError::Again) => {
+                    ready!(readiness.poll_ready(*receiver, ctx, PollEvents::POLL_IN))?
+                }
+                result => return Poll::Ready(result),
+            }
         }
     }
 }
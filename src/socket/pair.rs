@@ -105,14 +105,10 @@ mod pair_tests {
         futures::executor::block_on(async {
             pair_client
                 .send_msg_async("Hello", SendFlags::empty())
-                .await;
-
-            loop {
-                if let Some(msg) = pair_client.recv_msg_async().await {
-                    assert_eq!(msg.to_string(), "World");
-                    break;
-                }
-            }
+                .await?;
+
+            let msg = pair_client.recv_msg_async().await?;
+            assert_eq!(msg.to_string(), "World");
 
             Ok(())
         })
@@ -1,5 +1,7 @@
 use crate::{
-    ZmqResult, sealed,
+    ZmqError, ZmqResult,
+    endpoint::Endpoint,
+    sealed,
     socket::{Socket, SocketOption, SocketType},
 };
 
@@ -68,11 +70,13 @@ impl Socket<Peer> {
     /// [`connect_peer()`]: #method.connect_peer
     /// [`Unsupported`]: crate::ZmqError::Unsupported
     /// [`routing_id()`]: crate::message::Message::routing_id
-    pub fn connect_peer<V>(&self, endpoint: V) -> ZmqResult<u32>
+    pub fn connect_peer<E>(&self, endpoint: E) -> ZmqResult<u32>
     where
-        V: AsRef<str>,
+        E: TryInto<Endpoint>,
+        ZmqError: From<E::Error>,
     {
-        self.socket.connect_peer(endpoint.as_ref())
+        let endpoint = endpoint.try_into()?;
+        self.socket.connect_peer(&endpoint.to_string())
     }
 
     /// # set a hiccup message that the socket will generate when connected peer temporarily disconnect `ZMQ_HICCUP_MSG`
@@ -215,17 +219,15 @@ mod peer_test {
 
         std::thread::spawn(move || {
             futures::executor::block_on(async {
-                loop {
-                    if let Some(msg) = peer_server.recv_msg_async().await {
-                        assert_eq!(msg.to_string(), "Hello");
-
-                        let reply: Message = "World".into();
-                        reply.set_routing_id(msg.routing_id().unwrap()).unwrap();
-                        peer_server.send_msg_async(reply, SendFlags::empty()).await;
-
-                        break;
-                    }
-                }
+                let msg = peer_server.recv_msg_async().await.unwrap();
+                assert_eq!(msg.to_string(), "Hello");
+
+                let reply: Message = "World".into();
+                reply.set_routing_id(msg.routing_id().unwrap()).unwrap();
+                peer_server
+                    .send_msg_async(reply, SendFlags::empty())
+                    .await
+                    .unwrap();
             })
         });
 
@@ -236,15 +238,12 @@ mod peer_test {
         msg.set_routing_id(routing_id)?;
 
         futures::executor::block_on(async {
-            peer_client.send_msg_async(msg, SendFlags::empty()).await;
-
-            loop {
-                if let Some(msg) = peer_client.recv_msg_async().await {
-                    assert_eq!(msg.routing_id(), Some(routing_id));
-                    assert_eq!(msg.to_string(), "World");
-                    break;
-                };
-            }
+            peer_client.send_msg_async(msg, SendFlags::empty()).await?;
+
+            let msg = peer_client.recv_msg_async().await?;
+            assert_eq!(msg.routing_id(), Some(routing_id));
+            assert_eq!(msg.to_string(), "World");
+
             Ok(())
         })
     }
@@ -276,7 +275,7 @@ pub(crate) mod builder {
     use serde::{Deserialize, Serialize};
 
     use super::PeerSocket;
-    use crate::{ZmqResult, context::Context, socket::SocketBuilder};
+    use crate::{ZmqError, ZmqResult, context::Context, socket::SocketBuilder};
 
     #[derive(Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Builder)]
     #[builder(
@@ -330,6 +329,18 @@ pub(crate) mod builder {
         }
     }
 
+    impl TryFrom<&PeerSocket> for PeerBuilder {
+        type Error = ZmqError;
+
+        /// Reads back all options of the socket that can be retrieved.
+        fn try_from(socket: &PeerSocket) -> Result<Self, Self::Error> {
+            Ok(Self {
+                socket_builder: Some(SocketBuilder::try_from(socket)?),
+                ..Default::default()
+            })
+        }
+    }
+
     #[cfg(test)]
     mod peer_builder_test {
         use super::PeerBuilder;
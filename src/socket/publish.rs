@@ -212,7 +212,7 @@ pub(crate) mod builder {
     use serde::{Deserialize, Serialize};
 
     use super::PublishSocket;
-    use crate::{ZmqResult, context::Context, socket::SocketBuilder};
+    use crate::{ZmqError, ZmqResult, context::Context, socket::SocketBuilder};
 
     #[derive(Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Builder)]
     #[builder(
@@ -264,6 +264,20 @@ pub(crate) mod builder {
         }
     }
 
+    impl TryFrom<&PublishSocket> for PublishBuilder {
+        type Error = ZmqError;
+
+        /// Reads back all options of the socket that can be retrieved.
+        fn try_from(socket: &PublishSocket) -> Result<Self, Self::Error> {
+            Ok(Self {
+                socket_builder: Some(SocketBuilder::try_from(socket)?),
+                conflate: Some(socket.conflate()?),
+                invert_matching: Some(socket.invert_matching()?),
+                ..Default::default()
+            })
+        }
+    }
+
     #[cfg(test)]
     mod publish_builder_tests {
         use super::PublishBuilder;
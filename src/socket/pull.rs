@@ -95,7 +95,7 @@ mod pull_tests {
 
         let push = PushSocket::from_context(&context)?;
         push.bind("tcp://127.0.0.1:*")?;
-        let pull_endpoint = push.last_endpoint()?;
+        let pull_endpoint = push.last_endpoint()?.unwrap();
 
         std::thread::spawn(move || {
             loop {
@@ -119,12 +119,14 @@ mod pull_tests {
 
         let push = PushSocket::from_context(&context)?;
         push.bind("tcp://127.0.0.1:*")?;
-        let pull_endpoint = push.last_endpoint()?;
+        let pull_endpoint = push.last_endpoint()?.unwrap();
 
         std::thread::spawn(move || {
             futures::executor::block_on(async {
                 loop {
-                    push.send_msg_async("Hello", SendFlags::empty()).await;
+                    push.send_msg_async("Hello", SendFlags::empty())
+                        .await
+                        .unwrap();
                 }
             })
         });
@@ -133,12 +135,8 @@ mod pull_tests {
         pull.connect(pull_endpoint)?;
 
         futures::executor::block_on(async {
-            loop {
-                if let Some(msg) = pull.recv_msg_async().await {
-                    assert_eq!(msg.to_string(), "Hello");
-                    break;
-                }
-            }
+            let msg = pull.recv_msg_async().await?;
+            assert_eq!(msg.to_string(), "Hello");
 
             Ok(())
         })
@@ -153,7 +151,7 @@ pub(crate) mod builder {
     use serde::{Deserialize, Serialize};
 
     use super::PullSocket;
-    use crate::{ZmqResult, context::Context, socket::SocketBuilder};
+    use crate::{ZmqError, ZmqResult, context::Context, socket::SocketBuilder};
 
     #[derive(Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Builder)]
     #[builder(
@@ -193,6 +191,18 @@ pub(crate) mod builder {
         }
     }
 
+    impl TryFrom<&PullSocket> for PullBuilder {
+        type Error = ZmqError;
+
+        /// Reads back all options of the socket that can be retrieved.
+        fn try_from(socket: &PullSocket) -> Result<Self, Self::Error> {
+            Ok(Self {
+                socket_builder: Some(SocketBuilder::try_from(socket)?),
+                conflate: Some(socket.conflate()?),
+            })
+        }
+    }
+
     #[cfg(test)]
     mod pull_builder_tests {
         use super::PullBuilder;
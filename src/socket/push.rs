@@ -106,7 +106,7 @@ pub(crate) mod builder {
     use serde::{Deserialize, Serialize};
 
     use super::PushSocket;
-    use crate::{ZmqResult, context::Context, socket::SocketBuilder};
+    use crate::{ZmqError, ZmqResult, context::Context, socket::SocketBuilder};
 
     #[derive(Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Builder)]
     #[builder(
@@ -146,6 +146,18 @@ pub(crate) mod builder {
         }
     }
 
+    impl TryFrom<&PushSocket> for PushBuilder {
+        type Error = ZmqError;
+
+        /// Reads back all options of the socket that can be retrieved.
+        fn try_from(socket: &PushSocket) -> Result<Self, Self::Error> {
+            Ok(Self {
+                socket_builder: Some(SocketBuilder::try_from(socket)?),
+                conflate: Some(socket.conflate()?),
+            })
+        }
+    }
+
     #[cfg(test)]
     mod pull_builder_tests {
         use super::PushBuilder;
@@ -75,7 +75,7 @@ pub(crate) mod builder {
     use serde::{Deserialize, Serialize};
 
     use super::RadioSocket;
-    use crate::{ZmqResult, context::Context, socket::SocketBuilder};
+    use crate::{ZmqError, ZmqResult, context::Context, socket::SocketBuilder};
 
     #[derive(Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Builder)]
     #[builder(
@@ -115,6 +115,18 @@ pub(crate) mod builder {
         }
     }
 
+    impl TryFrom<&RadioSocket> for RadioBuilder {
+        type Error = ZmqError;
+
+        /// Reads back all options of the socket that can be retrieved.
+        fn try_from(socket: &RadioSocket) -> Result<Self, Self::Error> {
+            Ok(Self {
+                socket_builder: Some(SocketBuilder::try_from(socket)?),
+                multicast_loop: Some(socket.multicast_loop()?),
+            })
+        }
+    }
+
     #[cfg(test)]
     mod radio_builder_tests {
         use super::RadioBuilder;
@@ -1,6 +1,13 @@
+use derive_more::Debug as DebugDeriveMore;
+
 use crate::{
-    ZmqResult, sealed,
-    socket::{MultipartReceiver, MultipartSender, Socket, SocketOption, SocketType},
+    ZmqResult,
+    message::{Message, MultipartMessage, RoutingId},
+    sealed,
+    socket::{
+        MultipartReceiver, MultipartSender, PollEvents, RecvFlags, SendFlags, Sender, Socket,
+        SocketOption, SocketType, TransitionError,
+    },
 };
 
 /// # A Reply socket `ZMQ_REP`
@@ -53,9 +60,9 @@ impl Socket<Reply> {
     /// [`set_router_handover()`]: super::RouterSocket::set_router_handover
     pub fn set_routing_id<V>(&self, value: V) -> ZmqResult<()>
     where
-        V: AsRef<str>,
+        V: Into<RoutingId>,
     {
-        self.set_sockopt_string(SocketOption::RoutingId, value)
+        self.set_sockopt_routing_id(SocketOption::RoutingId, value.into())
     }
 
     /// # Retrieve socket routing id `ZMQ_ROUTING_ID`
@@ -69,15 +76,176 @@ impl Socket<Reply> {
     ///
     /// [`routing_id()`]: #method.routing_id
     /// [`Router`]: super::RouterSocket
-    pub fn routing_id(&self) -> ZmqResult<String> {
-        self.get_sockopt_string(SocketOption::RoutingId)
+    pub fn routing_id(&self) -> ZmqResult<RoutingId> {
+        self.get_sockopt_routing_id(SocketOption::RoutingId)
+    }
+
+    /// # Track the request/reply state at compile time
+    ///
+    /// Converts the socket into a [`RepIdle`] typed socket, that only allows receiving a request,
+    /// which turns it into a [`RepReplyPending`] typed socket, that only allows sending the reply.
+    /// The socket must not have a reply pending.
+    pub fn into_typed(self) -> RepIdle {
+        RepIdle { socket: self }
+    }
+}
+
+#[derive(DebugDeriveMore)]
+#[debug("RepIdle {{ ... }}")]
+/// # Typed [`Reply`] socket ready to receive a request
+///
+/// Created through [`into_typed()`]. Receiving a request turns it into a [`RepReplyPending`].
+///
+/// ```
+/// # use arzmq::prelude::{
+/// #     Context, Receiver, RecvFlags, ReplySocket, RequestSocket, SendFlags, Sender, ZmqResult,
+/// # };
+/// #
+/// fn main() -> ZmqResult<()> {
+///     let context = Context::new()?;
+///
+///     let reply = ReplySocket::from_context(&context)?;
+///     reply.bind("inproc://typed-reply-example")?;
+///
+///     let request = RequestSocket::from_context(&context)?;
+///     request.connect("inproc://typed-reply-example")?;
+///     request.send_msg("Hello", SendFlags::empty())?;
+///
+///     let idle = reply.into_typed();
+///     let (msg, reply_pending) = idle.recv_msg(RecvFlags::empty())?;
+///     assert_eq!(msg.to_string(), "Hello");
+///     let _idle = reply_pending.send_msg("World", SendFlags::empty())?;
+///
+///     assert_eq!(request.recv_msg(RecvFlags::empty())?.to_string(), "World");
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`Reply`]: ReplySocket
+/// [`into_typed()`]: ReplySocket::into_typed
+pub struct RepIdle {
+    socket: ReplySocket,
+}
+
+impl RepIdle {
+    /// Receives a single part request, and returns it with the socket that has to send the reply.
+    /// The remaining parts of a multipart request are discarded, use [`recv_multipart()`] to
+    /// receive all of them.
+    ///
+    /// [`recv_multipart()`]: Self::recv_multipart
+    pub fn recv_msg<F>(self, flags: F) -> Result<(Message, RepReplyPending), TransitionError<Self>>
+    where
+        F: Into<RecvFlags> + Copy,
+    {
+        TransitionError::recv_msg(self, |state| &state.socket, flags).map(|(msg, state)| {
+            (
+                msg,
+                RepReplyPending {
+                    socket: state.socket,
+                },
+            )
+        })
+    }
+
+    /// Receives a multipart request, and returns it with the socket that has to send the reply.
+    pub fn recv_multipart<F>(
+        self,
+        flags: F,
+    ) -> Result<(MultipartMessage, RepReplyPending), TransitionError<Self>>
+    where
+        F: Into<RecvFlags> + Copy,
+    {
+        match self.socket.recv_multipart(flags) {
+            Ok(multipart) => Ok((
+                multipart,
+                RepReplyPending {
+                    socket: self.socket,
+                },
+            )),
+            Err(err) => Err(TransitionError::new(err, self)),
+        }
+    }
+
+    /// Polls the underlying socket, see [`poll()`](Socket::poll).
+    pub fn poll<E>(&self, events: E, timeout_ms: i64) -> ZmqResult<PollEvents>
+    where
+        E: Into<PollEvents>,
+    {
+        self.socket.poll(events, timeout_ms)
+    }
+
+    /// Returns the underlying untyped socket.
+    pub fn into_inner(self) -> ReplySocket {
+        self.socket
+    }
+}
+
+#[derive(DebugDeriveMore)]
+#[debug("RepReplyPending {{ ... }}")]
+/// # Typed [`Reply`] socket that has to send a reply
+///
+/// Sending the reply turns it back into a [`RepIdle`].
+///
+/// [`Reply`]: ReplySocket
+pub struct RepReplyPending {
+    socket: ReplySocket,
+}
+
+impl RepReplyPending {
+    /// Sends a single part reply, and returns the socket ready for the next request.
+    pub fn send_msg<M, F>(self, msg: M, flags: F) -> Result<RepIdle, TransitionError<Self>>
+    where
+        M: Into<Message>,
+        F: Into<SendFlags> + Copy,
+    {
+        match self.socket.send_msg(msg, flags) {
+            Ok(()) => Ok(RepIdle {
+                socket: self.socket,
+            }),
+            Err(err) => Err(TransitionError::new(err, self)),
+        }
+    }
+
+    /// Sends a multipart reply, and returns the socket ready for the next request. Empty replies
+    /// are rejected with [`InvalidArgument`]. The socket is not returned on failure, when only
+    /// part of the reply was sent.
+    ///
+    /// [`InvalidArgument`]: crate::ZmqError::InvalidArgument
+    pub fn send_multipart<M, F>(
+        self,
+        multipart: M,
+        flags: F,
+    ) -> Result<RepIdle, TransitionError<Self>>
+    where
+        M: Into<MultipartMessage>,
+        F: Into<SendFlags> + Copy,
+    {
+        TransitionError::send_multipart(self, |state| &state.socket, multipart, flags).map(
+            |state| RepIdle {
+                socket: state.socket,
+            },
+        )
+    }
+
+    /// Polls the underlying socket, see [`poll()`](Socket::poll).
+    pub fn poll<E>(&self, events: E, timeout_ms: i64) -> ZmqResult<PollEvents>
+    where
+        E: Into<PollEvents>,
+    {
+        self.socket.poll(events, timeout_ms)
+    }
+
+    /// Returns the underlying untyped socket, that still has to send the reply.
+    pub fn into_inner(self) -> ReplySocket {
+        self.socket
     }
 }
 
 #[cfg(test)]
 mod reply_tests {
     use super::*;
-    use crate::socket::{Context, ZmqResult};
+    use crate::socket::{Context, Receiver, RequestSocket, RoutingId, ZmqError, ZmqResult};
 
     #[test]
     fn set_routing_id_sets_routing_id() -> ZmqResult<()> {
@@ -86,7 +254,66 @@ mod reply_tests {
         let socket = ReplySocket::from_context(&context)?;
         socket.set_routing_id("asdf")?;
 
-        assert_eq!(socket.routing_id()?, "asdf");
+        assert_eq!(socket.routing_id()?, RoutingId::from("asdf"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn typed_reply_request() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let reply = ReplySocket::from_context(&context)?;
+        reply.bind("inproc://typed-reply-request")?;
+
+        let request = RequestSocket::from_context(&context)?;
+        request.connect("inproc://typed-reply-request")?;
+
+        let idle = reply.into_typed();
+        let result = idle.recv_msg(RecvFlags::DONT_WAIT);
+        let (err, idle) = result.unwrap_err().into_parts();
+        assert_eq!(err, ZmqError::Again);
+        let mut idle = idle.unwrap();
+
+        for _ in 0..2 {
+            let multipart: Vec<Message> = vec!["Hello".into(), "!".into()];
+            request.send_multipart(multipart, SendFlags::empty())?;
+
+            let (multipart, reply_pending) = idle.recv_multipart(RecvFlags::empty())?;
+            assert_eq!(multipart.len(), 2);
+            idle = reply_pending.send_msg("World", SendFlags::empty())?;
+
+            assert_eq!(request.recv_msg(RecvFlags::empty())?.to_string(), "World");
+        }
+
+        // escape hatch
+        assert!(idle.into_inner().recv_msg(RecvFlags::DONT_WAIT).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn typed_reply_discards_remaining_request_parts() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let reply = ReplySocket::from_context(&context)?;
+        reply.bind("inproc://typed-reply-multipart-request")?;
+
+        let request = RequestSocket::from_context(&context)?;
+        request.connect("inproc://typed-reply-multipart-request")?;
+
+        let multipart: Vec<Message> = vec!["Hello".into(), "!".into()];
+        request.send_multipart(multipart, SendFlags::empty())?;
+
+        let (msg, reply_pending) = reply.into_typed().recv_msg(RecvFlags::empty())?;
+        assert_eq!(msg.to_string(), "Hello");
+
+        let idle = reply_pending.send_msg("World", SendFlags::empty())?;
+        assert_eq!(request.recv_msg(RecvFlags::empty())?.to_string(), "World");
+
+        request.send_msg("Hello", SendFlags::empty())?;
+        let (msg, _reply_pending) = idle.recv_msg(RecvFlags::empty())?;
+        assert_eq!(msg.to_string(), "Hello");
 
         Ok(())
     }
@@ -100,7 +327,7 @@ pub(crate) mod builder {
     use serde::{Deserialize, Serialize};
 
     use super::ReplySocket;
-    use crate::{ZmqResult, context::Context, socket::SocketBuilder};
+    use crate::{ZmqError, ZmqResult, context::Context, message::RoutingId, socket::SocketBuilder};
 
     #[derive(Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Builder)]
     #[builder(
@@ -115,7 +342,7 @@ pub(crate) mod builder {
     struct ReplyConfig {
         socket_builder: SocketBuilder,
         #[builder(setter(into), default = "Default::default()")]
-        routing_id: String,
+        routing_id: RoutingId,
     }
 
     impl ReplyBuilder {
@@ -140,10 +367,22 @@ pub(crate) mod builder {
         }
     }
 
+    impl TryFrom<&ReplySocket> for ReplyBuilder {
+        type Error = ZmqError;
+
+        /// Reads back all options of the socket that can be retrieved.
+        fn try_from(socket: &ReplySocket) -> Result<Self, Self::Error> {
+            Ok(Self {
+                socket_builder: Some(SocketBuilder::try_from(socket)?),
+                routing_id: Some(socket.routing_id()?).filter(RoutingId::is_valid),
+            })
+        }
+    }
+
     #[cfg(test)]
     mod reply_builder_tests {
         use super::ReplyBuilder;
-        use crate::socket::{Context, SocketBuilder, ZmqResult};
+        use crate::socket::{Context, RoutingId, SocketBuilder, ZmqResult};
 
         #[test]
         fn default_reply_builder() -> ZmqResult<()> {
@@ -151,7 +390,7 @@ pub(crate) mod builder {
 
             let socket = ReplyBuilder::default().build_from_context(&context)?;
 
-            assert_eq!(socket.routing_id()?, "");
+            assert_eq!(socket.routing_id()?, RoutingId::default());
 
             Ok(())
         }
@@ -165,7 +404,7 @@ pub(crate) mod builder {
                 .routing_id("asdf")
                 .build_from_context(&context)?;
 
-            assert_eq!(socket.routing_id()?, "asdf");
+            assert_eq!(socket.routing_id()?, RoutingId::from("asdf"));
 
             Ok(())
         }
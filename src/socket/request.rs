@@ -1,6 +1,13 @@
+use derive_more::Debug as DebugDeriveMore;
+
 use crate::{
-    ZmqResult, sealed,
-    socket::{MultipartReceiver, MultipartSender, Socket, SocketOption, SocketType},
+    ZmqResult,
+    message::{Message, MultipartMessage, RoutingId},
+    sealed,
+    socket::{
+        MultipartReceiver, MultipartSender, PollEvents, RecvFlags, SendFlags, Sender, Socket,
+        SocketOption, SocketType, TransitionError,
+    },
 };
 
 /// # A Requester socket `ZMQ_REQ`
@@ -89,9 +96,9 @@ impl Socket<Request> {
     /// [`set_router_handover()`]: super::RouterSocket::set_router_handover
     pub fn set_routing_id<V>(&self, value: V) -> ZmqResult<()>
     where
-        V: AsRef<str>,
+        V: Into<RoutingId>,
     {
-        self.set_sockopt_string(SocketOption::RoutingId, value)
+        self.set_sockopt_routing_id(SocketOption::RoutingId, value.into())
     }
 
     /// # Retrieve socket routing id `ZMQ_ROUTING_ID`
@@ -105,8 +112,8 @@ impl Socket<Request> {
     ///
     /// [`routing_id()`]: #method.routing_id
     /// [`Router`]: super::RouterSocket
-    pub fn routing_id(&self) -> ZmqResult<String> {
-        self.get_sockopt_string(SocketOption::RoutingId)
+    pub fn routing_id(&self) -> ZmqResult<RoutingId> {
+        self.get_sockopt_routing_id(SocketOption::RoutingId)
     }
 
     /// # bootstrap connections to ROUTER sockets `ZMQ_PROBE_ROUTER`
@@ -128,12 +135,177 @@ impl Socket<Request> {
     pub fn set_probe_router(&self, value: bool) -> ZmqResult<()> {
         self.set_sockopt_bool(SocketOption::ProbeRouter, value)
     }
+
+    /// # Track the request/reply state at compile time
+    ///
+    /// Converts the socket into a [`ReqIdle`] typed socket, that only allows sending a request,
+    /// which turns it into a [`ReqAwaitingReply`] typed socket, that only allows receiving the
+    /// reply. The socket must not be waiting for a reply.
+    pub fn into_typed(self) -> ReqIdle {
+        ReqIdle { socket: self }
+    }
+}
+
+#[derive(DebugDeriveMore)]
+#[debug("ReqIdle {{ ... }}")]
+/// # Typed [`Request`] socket ready to send a request
+///
+/// Created through [`into_typed()`]. Sending a request turns it into a [`ReqAwaitingReply`].
+///
+/// ```
+/// # use arzmq::prelude::{
+/// #     Context, Receiver, RecvFlags, ReplySocket, RequestSocket, SendFlags, Sender, ZmqResult,
+/// # };
+/// #
+/// fn main() -> ZmqResult<()> {
+///     let context = Context::new()?;
+///
+///     let reply = ReplySocket::from_context(&context)?;
+///     reply.bind("inproc://typed-request-example")?;
+///
+///     let request = RequestSocket::from_context(&context)?;
+///     request.connect("inproc://typed-request-example")?;
+///
+///     let idle = request.into_typed();
+///     let awaiting_reply = idle.send_msg("Hello", SendFlags::empty())?;
+///
+///     reply.recv_msg(RecvFlags::empty())?;
+///     reply.send_msg("World", SendFlags::empty())?;
+///
+///     let (msg, _idle) = awaiting_reply.recv_msg(RecvFlags::empty())?;
+///     assert_eq!(msg.to_string(), "World");
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`Request`]: RequestSocket
+/// [`into_typed()`]: RequestSocket::into_typed
+pub struct ReqIdle {
+    socket: RequestSocket,
+}
+
+impl ReqIdle {
+    /// Sends a single part request, and returns the socket awaiting the reply.
+    pub fn send_msg<M, F>(self, msg: M, flags: F) -> Result<ReqAwaitingReply, TransitionError<Self>>
+    where
+        M: Into<Message>,
+        F: Into<SendFlags> + Copy,
+    {
+        match self.socket.send_msg(msg, flags) {
+            Ok(()) => Ok(ReqAwaitingReply {
+                socket: self.socket,
+            }),
+            Err(err) => Err(TransitionError::new(err, self)),
+        }
+    }
+
+    /// Sends a multipart request, and returns the socket awaiting the reply. Empty requests are
+    /// rejected with [`InvalidArgument`]. The socket is not returned on failure, when only part of
+    /// the request was sent.
+    ///
+    /// [`InvalidArgument`]: crate::ZmqError::InvalidArgument
+    pub fn send_multipart<M, F>(
+        self,
+        multipart: M,
+        flags: F,
+    ) -> Result<ReqAwaitingReply, TransitionError<Self>>
+    where
+        M: Into<MultipartMessage>,
+        F: Into<SendFlags> + Copy,
+    {
+        TransitionError::send_multipart(self, |state| &state.socket, multipart, flags).map(
+            |state| ReqAwaitingReply {
+                socket: state.socket,
+            },
+        )
+    }
+
+    /// Polls the underlying socket, see [`poll()`](Socket::poll).
+    pub fn poll<E>(&self, events: E, timeout_ms: i64) -> ZmqResult<PollEvents>
+    where
+        E: Into<PollEvents>,
+    {
+        self.socket.poll(events, timeout_ms)
+    }
+
+    /// Returns the underlying untyped socket.
+    pub fn into_inner(self) -> RequestSocket {
+        self.socket
+    }
+}
+
+#[derive(DebugDeriveMore)]
+#[debug("ReqAwaitingReply {{ ... }}")]
+/// # Typed [`Request`] socket awaiting a reply
+///
+/// Receiving the reply turns it back into a [`ReqIdle`].
+///
+/// [`Request`]: RequestSocket
+pub struct ReqAwaitingReply {
+    socket: RequestSocket,
+}
+
+impl ReqAwaitingReply {
+    /// Receives a single part reply, and returns it with the socket ready for the next request.
+    /// The remaining parts of a multipart reply are discarded, use [`recv_multipart()`] to
+    /// receive all of them.
+    ///
+    /// [`recv_multipart()`]: Self::recv_multipart
+    pub fn recv_msg<F>(self, flags: F) -> Result<(Message, ReqIdle), TransitionError<Self>>
+    where
+        F: Into<RecvFlags> + Copy,
+    {
+        TransitionError::recv_msg(self, |state| &state.socket, flags).map(|(msg, state)| {
+            (
+                msg,
+                ReqIdle {
+                    socket: state.socket,
+                },
+            )
+        })
+    }
+
+    /// Receives a multipart reply, and returns it with the socket ready for the next request.
+    pub fn recv_multipart<F>(
+        self,
+        flags: F,
+    ) -> Result<(MultipartMessage, ReqIdle), TransitionError<Self>>
+    where
+        F: Into<RecvFlags> + Copy,
+    {
+        match self.socket.recv_multipart(flags) {
+            Ok(multipart) => Ok((
+                multipart,
+                ReqIdle {
+                    socket: self.socket,
+                },
+            )),
+            Err(err) => Err(TransitionError::new(err, self)),
+        }
+    }
+
+    /// Polls the underlying socket, see [`poll()`](Socket::poll).
+    pub fn poll<E>(&self, events: E, timeout_ms: i64) -> ZmqResult<PollEvents>
+    where
+        E: Into<PollEvents>,
+    {
+        self.socket.poll(events, timeout_ms)
+    }
+
+    /// Returns the underlying untyped socket, that is still awaiting the reply.
+    pub fn into_inner(self) -> RequestSocket {
+        self.socket
+    }
 }
 
 #[cfg(test)]
 mod request_tests {
     use super::RequestSocket;
-    use crate::socket::{Context, Receiver, RecvFlags, ReplySocket, SendFlags, Sender, ZmqResult};
+    use crate::socket::{
+        Context, Message, MultipartMessage, MultipartReceiver, MultipartSender, PollEvents,
+        Receiver, RecvFlags, ReplySocket, RoutingId, SendFlags, Sender, ZmqError, ZmqResult,
+    };
 
     #[test]
     fn set_correlate_sets_correlate() -> ZmqResult<()> {
@@ -162,7 +334,7 @@ mod request_tests {
         let socket = RequestSocket::from_context(&context)?;
         socket.set_routing_id("asdf")?;
 
-        assert_eq!(socket.routing_id()?, "asdf");
+        assert_eq!(socket.routing_id()?, RoutingId::from("asdf"));
 
         Ok(())
     }
@@ -183,7 +355,7 @@ mod request_tests {
 
         let reply = ReplySocket::from_context(&context)?;
         reply.bind("tcp://127.0.0.1:*")?;
-        let request_endpoint = reply.last_endpoint()?;
+        let request_endpoint = reply.last_endpoint()?.unwrap();
 
         std::thread::spawn(move || {
             let msg = reply.recv_msg(RecvFlags::empty()).unwrap();
@@ -202,6 +374,96 @@ mod request_tests {
         Ok(())
     }
 
+    #[test]
+    fn typed_request_reply() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let reply = ReplySocket::from_context(&context)?;
+        reply.bind("inproc://typed-request-reply")?;
+
+        let request = RequestSocket::from_context(&context)?;
+        request.connect("inproc://typed-request-reply")?;
+
+        let mut idle = request.into_typed();
+        for _ in 0..2 {
+            let awaiting_reply = idle.send_msg("Hello", SendFlags::empty())?;
+
+            let msg = reply.recv_msg(RecvFlags::empty())?;
+            assert_eq!(msg.to_string(), "Hello");
+            let multipart: Vec<Message> = vec!["World".into(), "!".into()];
+            reply.send_multipart(multipart, SendFlags::empty())?;
+
+            let (multipart, next) = awaiting_reply.recv_multipart(RecvFlags::empty())?;
+            assert_eq!(multipart.len(), 2);
+            idle = next;
+        }
+
+        // escape hatch
+        idle.into_inner().send_msg("Hello", SendFlags::empty())
+    }
+
+    #[test]
+    fn typed_request_keeps_state_on_error() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let request = RequestSocket::from_context(&context)?;
+        request.bind("inproc://typed-request-error")?;
+
+        let result = request.into_typed().send_msg("Hello", SendFlags::DONT_WAIT);
+        let (err, idle) = result.unwrap_err().into_parts();
+        assert_eq!(err, ZmqError::Again);
+
+        let result = idle
+            .unwrap()
+            .send_multipart(MultipartMessage::new(), SendFlags::empty());
+        let (err, idle) = result.unwrap_err().into_parts();
+        assert_eq!(err, ZmqError::InvalidArgument);
+
+        let reply = ReplySocket::from_context(&context)?;
+        reply.connect("inproc://typed-request-error")?;
+
+        let multipart: Vec<Message> = vec!["Hello".into(), "World".into()];
+        let awaiting_reply = idle
+            .unwrap()
+            .send_multipart(multipart, SendFlags::empty())?;
+        assert_eq!(reply.recv_multipart(RecvFlags::empty())?.len(), 2);
+
+        let result = awaiting_reply.recv_msg(RecvFlags::DONT_WAIT);
+        let awaiting_reply = result.unwrap_err().into_state().unwrap();
+        assert!(awaiting_reply.poll(PollEvents::POLL_IN, 0)?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn typed_request_discards_remaining_reply_parts() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let reply = ReplySocket::from_context(&context)?;
+        reply.bind("inproc://typed-request-multipart-reply")?;
+
+        let request = RequestSocket::from_context(&context)?;
+        request.connect("inproc://typed-request-multipart-reply")?;
+
+        let awaiting_reply = request.into_typed().send_msg("Hello", SendFlags::empty())?;
+        assert_eq!(reply.recv_msg(RecvFlags::empty())?.to_string(), "Hello");
+
+        let multipart: Vec<Message> = vec!["World".into(), "Again".into()];
+        reply.send_multipart(multipart, SendFlags::empty())?;
+
+        let (msg, idle) = awaiting_reply.recv_msg(RecvFlags::empty())?;
+        assert_eq!(msg.to_string(), "World");
+
+        let awaiting_reply = idle.send_msg("Hello", SendFlags::empty())?;
+        assert_eq!(reply.recv_msg(RecvFlags::empty())?.to_string(), "Hello");
+
+        reply.send_msg("World", SendFlags::empty())?;
+        let (msg, _idle) = awaiting_reply.recv_msg(RecvFlags::empty())?;
+        assert_eq!(msg.to_string(), "World");
+
+        Ok(())
+    }
+
     #[cfg(feature = "futures")]
     #[test]
     fn request_reply_async() -> ZmqResult<()> {
@@ -209,17 +471,16 @@ mod request_tests {
 
         let reply = ReplySocket::from_context(&context)?;
         reply.bind("tcp://127.0.0.1:*")?;
-        let request_endpoint = reply.last_endpoint()?;
+        let request_endpoint = reply.last_endpoint()?.unwrap();
 
         std::thread::spawn(move || {
             futures::executor::block_on(async {
-                loop {
-                    if let Some(msg) = reply.recv_msg_async().await {
-                        assert_eq!(msg.to_string(), "Hello");
-                        reply.send_msg_async("World", SendFlags::empty()).await;
-                        break;
-                    }
-                }
+                let msg = reply.recv_msg_async().await.unwrap();
+                assert_eq!(msg.to_string(), "Hello");
+                reply
+                    .send_msg_async("World", SendFlags::empty())
+                    .await
+                    .unwrap();
             })
         });
 
@@ -227,13 +488,10 @@ mod request_tests {
         request.connect(request_endpoint)?;
 
         futures::executor::block_on(async {
-            request.send_msg_async("Hello", SendFlags::empty()).await;
-            loop {
-                if let Some(msg) = request.recv_msg_async().await {
-                    assert_eq!(msg.to_string(), "World");
-                    break;
-                }
-            }
+            request.send_msg_async("Hello", SendFlags::empty()).await?;
+
+            let msg = request.recv_msg_async().await?;
+            assert_eq!(msg.to_string(), "World");
 
             Ok(())
         })
@@ -248,7 +506,7 @@ pub(crate) mod builder {
     use serde::{Deserialize, Serialize};
 
     use super::RequestSocket;
-    use crate::{ZmqResult, context::Context, socket::SocketBuilder};
+    use crate::{ZmqError, ZmqResult, context::Context, message::RoutingId, socket::SocketBuilder};
 
     #[derive(Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Builder)]
     #[builder(
@@ -267,7 +525,7 @@ pub(crate) mod builder {
         #[builder(default = false)]
         relaxed: bool,
         #[builder(setter(into), default = "Default::default()")]
-        routing_id: String,
+        routing_id: RoutingId,
     }
 
     impl RequestBuilder {
@@ -300,10 +558,23 @@ pub(crate) mod builder {
         }
     }
 
+    impl TryFrom<&RequestSocket> for RequestBuilder {
+        type Error = ZmqError;
+
+        /// Reads back all options of the socket that can be retrieved.
+        fn try_from(socket: &RequestSocket) -> Result<Self, Self::Error> {
+            Ok(Self {
+                socket_builder: Some(SocketBuilder::try_from(socket)?),
+                routing_id: Some(socket.routing_id()?).filter(RoutingId::is_valid),
+                ..Default::default()
+            })
+        }
+    }
+
     #[cfg(test)]
     mod request_builder_tests {
         use super::RequestBuilder;
-        use crate::socket::{Context, SocketBuilder, ZmqResult};
+        use crate::socket::{Context, RoutingId, SocketBuilder, ZmqResult};
 
         #[test]
         fn default_request_builder() -> ZmqResult<()> {
@@ -311,7 +582,7 @@ pub(crate) mod builder {
 
             let socket = RequestBuilder::default().build_from_context(&context)?;
 
-            assert_eq!(socket.routing_id()?, "");
+            assert_eq!(socket.routing_id()?, RoutingId::default());
 
             Ok(())
         }
@@ -327,7 +598,7 @@ pub(crate) mod builder {
                 .routing_id("asdf")
                 .build_from_context(&context)?;
 
-            assert_eq!(socket.routing_id()?, "asdf");
+            assert_eq!(socket.routing_id()?, RoutingId::from("asdf"));
 
             Ok(())
         }
@@ -2,8 +2,12 @@
 use bitflags::bitflags;
 
 use crate::{
-    ZmqResult, sealed,
-    socket::{MultipartReceiver, MultipartSender, Socket, SocketOption, SocketType},
+    ZmqError, ZmqResult,
+    message::{Envelope, MultipartMessage, RoutingId},
+    sealed,
+    socket::{
+        MultipartReceiver, MultipartSender, RecvFlags, SendFlags, Socket, SocketOption, SocketType,
+    },
 };
 
 /// # A router socket `ZMQ_ROUTER`
@@ -99,9 +103,9 @@ impl Socket<Router> {
     /// [`set_router_handover()`]: #method.set_router_handover
     pub fn set_routing_id<V>(&self, value: V) -> ZmqResult<()>
     where
-        V: AsRef<str>,
+        V: Into<RoutingId>,
     {
-        self.set_sockopt_string(SocketOption::RoutingId, value)
+        self.set_sockopt_routing_id(SocketOption::RoutingId, value.into())
     }
 
     /// # Retrieve socket routing id `ZMQ_ROUTING_ID`
@@ -115,8 +119,8 @@ impl Socket<Router> {
     ///
     /// [`routing_id()`]: #method.routing_id
     /// [`Router`]: RouterSocket
-    pub fn routing_id(&self) -> ZmqResult<String> {
-        self.get_sockopt_string(SocketOption::RoutingId)
+    pub fn routing_id(&self) -> ZmqResult<RoutingId> {
+        self.get_sockopt_routing_id(SocketOption::RoutingId)
     }
 
     /// # Assign the next outbound routing id `ZMQ_CONNECT_ROUTING_ID`
@@ -143,9 +147,9 @@ impl Socket<Router> {
     /// [`set_connect_routing_id()`]: #method.set_connect_routing_id
     pub fn set_connect_routing_id<V>(&self, value: V) -> ZmqResult<()>
     where
-        V: AsRef<str>,
+        V: Into<RoutingId>,
     {
-        self.set_sockopt_string(SocketOption::ConnectRoutingId, value)
+        self.set_sockopt_routing_id(SocketOption::ConnectRoutingId, value.into())
     }
 
     /// # bootstrap connections to ROUTER sockets `ZMQ_PROBE_ROUTER`
@@ -279,6 +283,81 @@ impl Socket<Router> {
         self.get_sockopt_int(SocketOption::RouterNotify)
             .map(RouterNotify::from_bits_truncate)
     }
+
+    /// # Receive a message with its envelope
+    ///
+    /// Receives a multipart message from a [`Request`] peer, or a peer relaying its requests, and
+    /// splits it into its [`Envelope`] and body. The routing id of the originating peer makes up
+    /// the last hop of the envelope, followed by all routing ids up to the first empty delimiter
+    /// part, which results in a delimited envelope. Messages without a delimiter result in an
+    /// envelope with the routing id of the peer only.
+    ///
+    /// Messages from [`Dealer`] peers may contain empty parts as part of their body, that would be
+    /// mistaken for the delimiter. Use [`recv_routed()`] for them instead.
+    ///
+    /// [`Request`]: super::RequestSocket
+    /// [`Dealer`]: super::DealerSocket
+    /// [`recv_routed()`]: #method.recv_routed
+    pub fn recv_envelope<F>(&self, flags: F) -> ZmqResult<(Envelope, MultipartMessage)>
+    where
+        F: Into<RecvFlags> + Copy,
+    {
+        let mut multipart = self.recv_multipart(flags)?;
+        let routing_id = multipart
+            .pop_front()
+            .map(RoutingId::from)
+            .ok_or(ZmqError::InvalidArgument)?;
+
+        let (mut envelope, body) = multipart.split_envelope();
+        envelope.push_routing_id(routing_id);
+
+        Ok((envelope, body))
+    }
+
+    /// # Receive a message with the routing id of its peer
+    ///
+    /// Receives a multipart message from a [`Dealer`] peer, and splits off the routing id of the
+    /// peer only. The returned [`Envelope`] is not delimited, and the body contains all other
+    /// parts unchanged, including empty ones.
+    ///
+    /// [`Dealer`]: super::DealerSocket
+    pub fn recv_routed<F>(&self, flags: F) -> ZmqResult<(Envelope, MultipartMessage)>
+    where
+        F: Into<RecvFlags> + Copy,
+    {
+        let mut body = self.recv_multipart(flags)?;
+        let routing_id = body
+            .pop_front()
+            .map(RoutingId::from)
+            .ok_or(ZmqError::InvalidArgument)?;
+
+        Ok((Envelope::from(routing_id), body))
+    }
+
+    /// # Send a message with an envelope
+    ///
+    /// Wraps the body into the provided [`Envelope`], and sends it to the peer with the routing id
+    /// of the last hop. Replies to [`Request`] peers need a delimited envelope, as returned by
+    /// [`recv_envelope()`] for their requests, while replies to [`Dealer`] peers use the envelope
+    /// returned by [`recv_routed()`]. Fails with [`InvalidArgument`] for envelopes without any
+    /// routing id.
+    ///
+    /// [`Request`]: super::RequestSocket
+    /// [`Dealer`]: super::DealerSocket
+    /// [`recv_envelope()`]: #method.recv_envelope
+    /// [`recv_routed()`]: #method.recv_routed
+    /// [`InvalidArgument`]: ZmqError::InvalidArgument
+    pub fn send_with_envelope<M, F>(&self, envelope: &Envelope, body: M, flags: F) -> ZmqResult<()>
+    where
+        M: Into<MultipartMessage>,
+        F: Into<SendFlags> + Copy,
+    {
+        if envelope.is_empty() {
+            return Err(ZmqError::InvalidArgument);
+        }
+
+        self.send_multipart(envelope.wrap(body), flags)
+    }
 }
 
 #[cfg(test)]
@@ -287,8 +366,9 @@ mod router_tests {
     use super::RouterNotify;
     use super::RouterSocket;
     use crate::prelude::{
-        Context, DealerSocket, Message, MultipartReceiver, MultipartSender, RecvFlags, SendFlags,
-        ZmqResult,
+        Context, DealerSocket, Envelope, Message, MultipartMessage, MultipartReceiver,
+        MultipartSender, Receiver, RecvFlags, RequestSocket, RoutingId, SendFlags, Sender,
+        ZmqError, ZmqResult,
     };
 
     #[test]
@@ -298,7 +378,62 @@ mod router_tests {
         let socket = RouterSocket::from_context(&context)?;
         socket.set_routing_id("asdf")?;
 
-        assert_eq!(socket.routing_id()?, "asdf");
+        assert_eq!(socket.routing_id()?, RoutingId::from("asdf"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_routing_id_with_binary_routing_id() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let socket = RouterSocket::from_context(&context)?;
+        socket.set_routing_id(&[0x01, 0x00, 0xff])?;
+
+        assert_eq!(socket.routing_id()?.as_bytes(), &[0x01, 0x00, 0xff]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_routing_id_with_invalid_routing_id() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let socket = RouterSocket::from_context(&context)?;
+
+        assert!(
+            socket
+                .set_routing_id(&[0x00, 0x01])
+                .is_err_and(|err| err == ZmqError::InvalidArgument)
+        );
+        assert!(
+            socket
+                .set_routing_id(vec![0x01; 256])
+                .is_err_and(|err| err == ZmqError::InvalidArgument)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn router_receives_binary_routing_id_of_peer() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let router = RouterSocket::from_context(&context)?;
+        router.bind("inproc://router-binary-routing-id")?;
+
+        let dealer = DealerSocket::from_context(&context)?;
+        dealer.set_routing_id(&[0xde, 0xad, 0xbe, 0xef])?;
+        dealer.connect("inproc://router-binary-routing-id")?;
+
+        let mut multipart = MultipartMessage::new();
+        multipart.push_back("Hello".into());
+        dealer.send_multipart(multipart, SendFlags::empty())?;
+
+        let mut received = router.recv_multipart(RecvFlags::empty())?;
+        let routing_id = RoutingId::from(received.pop_front().unwrap());
+        assert_eq!(routing_id, RoutingId::from(&[0xde, 0xad, 0xbe, 0xef]));
+        assert_eq!(routing_id.to_string(), "deadbeef");
 
         Ok(())
     }
@@ -387,7 +522,7 @@ mod router_tests {
 
         let router = RouterSocket::from_context(&context)?;
         router.bind("tcp://127.0.0.1:*")?;
-        let dealer_endpoint = router.last_endpoint()?;
+        let dealer_endpoint = router.last_endpoint()?.unwrap();
 
         std::thread::spawn(move || {
             let mut multipart = router.recv_multipart(RecvFlags::empty()).unwrap();
@@ -410,6 +545,74 @@ mod router_tests {
         Ok(())
     }
 
+    #[test]
+    fn envelope_with_request_peer() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let router = RouterSocket::from_context(&context)?;
+        router.bind("inproc://router-envelope-request")?;
+
+        let request = RequestSocket::from_context(&context)?;
+        request.set_routing_id("request")?;
+        request.connect("inproc://router-envelope-request")?;
+        request.send_msg("Hello", SendFlags::empty())?;
+
+        let (envelope, body) = router.recv_envelope(RecvFlags::empty())?;
+        assert_eq!(envelope.routing_ids(), &[RoutingId::from("request")]);
+        assert!(envelope.is_delimited());
+        assert_eq!(body.len(), 1);
+        assert_eq!(body.get(0).unwrap().to_string(), "Hello");
+
+        router.send_with_envelope(&envelope, Message::from("World"), SendFlags::empty())?;
+        assert_eq!(request.recv_msg(RecvFlags::empty())?.to_string(), "World");
+
+        Ok(())
+    }
+
+    #[test]
+    fn envelope_with_dealer_peer() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let router = RouterSocket::from_context(&context)?;
+        router.bind("inproc://router-envelope-dealer")?;
+
+        let dealer = DealerSocket::from_context(&context)?;
+        dealer.set_routing_id("dealer")?;
+        dealer.connect("inproc://router-envelope-dealer")?;
+        let request: MultipartMessage = vec!["a".into(), vec![].into(), "b".into()].into();
+        dealer.send_multipart(request, SendFlags::empty())?;
+
+        let (envelope, body) = router.recv_routed(RecvFlags::empty())?;
+        assert_eq!(envelope.routing_ids(), &[RoutingId::from("dealer")]);
+        assert!(!envelope.is_delimited());
+        assert_eq!(
+            body.iter().map(Message::bytes).collect::<Vec<_>>(),
+            vec![b"a".to_vec(), vec![], b"b".to_vec()]
+        );
+
+        router.send_with_envelope(&envelope, body, SendFlags::empty())?;
+        let reply = dealer.recv_multipart(RecvFlags::empty())?;
+        assert_eq!(
+            reply.iter().map(Message::bytes).collect::<Vec<_>>(),
+            vec![b"a".to_vec(), vec![], b"b".to_vec()]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn send_with_empty_envelope() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let router = RouterSocket::from_context(&context)?;
+
+        let result =
+            router.send_with_envelope(&Envelope::new(), Message::from("Hello"), SendFlags::empty());
+        assert!(result.is_err_and(|err| err == ZmqError::InvalidArgument));
+
+        Ok(())
+    }
+
     #[cfg(feature = "futures")]
     #[test]
     fn dealer_router_async() -> ZmqResult<()> {
@@ -417,17 +620,18 @@ mod router_tests {
 
         let router = RouterSocket::from_context(&context)?;
         router.bind("tcp://127.0.0.1:*")?;
-        let dealer_endpoint = router.last_endpoint()?;
+        let dealer_endpoint = router.last_endpoint()?.unwrap();
 
         std::thread::spawn(move || {
             futures::executor::block_on(async {
-                let mut multipart = router.recv_multipart_async().await;
+                let mut multipart = router.recv_multipart_async().await.unwrap();
                 let msg = multipart.pop_back().unwrap();
                 assert_eq!(msg.to_string(), "Hello");
                 multipart.push_back("World".into());
                 router
                     .send_multipart_async(multipart, SendFlags::empty())
-                    .await;
+                    .await
+                    .unwrap();
             })
         });
 
@@ -438,9 +642,9 @@ mod router_tests {
             let multipart: Vec<Message> = vec![vec![].into(), "Hello".into()];
             dealer
                 .send_multipart_async(multipart, SendFlags::empty())
-                .await;
+                .await?;
 
-            let mut msg = dealer.recv_multipart_async().await;
+            let mut msg = dealer.recv_multipart_async().await?;
             assert_eq!(msg.pop_back().unwrap().to_string(), "World");
 
             Ok(())
@@ -458,7 +662,7 @@ pub(crate) mod builder {
     #[cfg(feature = "draft-api")]
     use super::RouterNotify;
     use super::RouterSocket;
-    use crate::{ZmqResult, context::Context, socket::SocketBuilder};
+    use crate::{ZmqError, ZmqResult, context::Context, message::RoutingId, socket::SocketBuilder};
 
     #[derive(Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Builder)]
     #[builder(
@@ -482,13 +686,13 @@ pub(crate) mod builder {
         #[builder(setter(into), default = "RouterNotify::empty()")]
         router_notify: RouterNotify,
         #[builder(setter(into), default = "Default::default()")]
-        routing_id: String,
+        routing_id: RoutingId,
         #[builder(default = false)]
         router_mandatory: bool,
         #[builder(default = false)]
         router_handover: bool,
         #[builder(setter(into), default = "Default::default()")]
-        connect_routing_id: String,
+        connect_routing_id: RoutingId,
     }
 
     impl RouterBuilder {
@@ -542,12 +746,27 @@ pub(crate) mod builder {
         }
     }
 
+    impl TryFrom<&RouterSocket> for RouterBuilder {
+        type Error = ZmqError;
+
+        /// Reads back all options of the socket that can be retrieved.
+        fn try_from(socket: &RouterSocket) -> Result<Self, Self::Error> {
+            Ok(Self {
+                socket_builder: Some(SocketBuilder::try_from(socket)?),
+                #[cfg(feature = "draft-api")]
+                router_notify: Some(socket.router_notify()?),
+                routing_id: Some(socket.routing_id()?).filter(RoutingId::is_valid),
+                ..Default::default()
+            })
+        }
+    }
+
     #[cfg(test)]
     mod router_builder_tests {
         use super::RouterBuilder;
         #[cfg(feature = "draft-api")]
         use super::RouterNotify;
-        use crate::prelude::{Context, SocketBuilder, ZmqResult};
+        use crate::prelude::{Context, RoutingId, SocketBuilder, ZmqResult};
 
         #[test]
         fn default_router_builder() -> ZmqResult<()> {
@@ -555,7 +774,7 @@ pub(crate) mod builder {
 
             let socket = RouterBuilder::default().build_from_context(&context)?;
 
-            assert_eq!(socket.routing_id()?, "");
+            assert_eq!(socket.routing_id()?, RoutingId::default());
             #[cfg(feature = "draft-api")]
             assert_eq!(socket.router_notify()?, RouterNotify::empty());
 
@@ -581,7 +800,7 @@ pub(crate) mod builder {
 
             let socket = builder.build_from_context(&context)?;
 
-            assert_eq!(socket.routing_id()?, "asdf");
+            assert_eq!(socket.routing_id()?, RoutingId::from("asdf"));
             #[cfg(feature = "draft-api")]
             assert_eq!(
                 socket.router_notify()?,
@@ -590,5 +809,39 @@ pub(crate) mod builder {
 
             Ok(())
         }
+
+        #[test]
+        fn router_builder_from_socket() -> ZmqResult<()> {
+            let context = Context::new()?;
+
+            let socket = RouterBuilder::default()
+                .socket_builder(SocketBuilder::default().linger(42))
+                .routing_id("asdf")
+                .router_mandatory(true)
+                .build_from_context(&context)?;
+
+            let builder = RouterBuilder::try_from(&socket)?;
+            assert!(
+                builder == RouterBuilder::try_from(&builder.clone().build_from_context(&context)?)?
+            );
+
+            let other_socket = builder.build_from_context(&context)?;
+            assert_eq!(other_socket.linger()?, 42);
+            assert_eq!(other_socket.routing_id()?, RoutingId::from("asdf"));
+
+            Ok(())
+        }
+
+        #[test]
+        fn router_builder_from_socket_skips_unset_routing_id() -> ZmqResult<()> {
+            let context = Context::new()?;
+
+            let socket = RouterBuilder::default().build_from_context(&context)?;
+
+            let builder = RouterBuilder::try_from(&socket)?;
+            builder.build_from_context(&context)?;
+
+            Ok(())
+        }
     }
 }
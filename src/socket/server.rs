@@ -116,7 +116,7 @@ pub(crate) mod builder {
     use serde::{Deserialize, Serialize};
 
     use super::ServerSocket;
-    use crate::{ZmqResult, context::Context, socket::SocketBuilder};
+    use crate::{ZmqError, ZmqResult, context::Context, socket::SocketBuilder};
 
     #[derive(Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Builder)]
     #[builder(
@@ -164,6 +164,18 @@ pub(crate) mod builder {
         }
     }
 
+    impl TryFrom<&ServerSocket> for ServerBuilder {
+        type Error = ZmqError;
+
+        /// Reads back all options of the socket that can be retrieved.
+        fn try_from(socket: &ServerSocket) -> Result<Self, Self::Error> {
+            Ok(Self {
+                socket_builder: Some(SocketBuilder::try_from(socket)?),
+                ..Default::default()
+            })
+        }
+    }
+
     #[cfg(test)]
     mod server_builder_tests {
         use super::ServerBuilder;
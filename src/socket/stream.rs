@@ -1,5 +1,7 @@
 use crate::{
-    ZmqResult, sealed,
+    ZmqResult,
+    message::RoutingId,
+    sealed,
     socket::{MultipartReceiver, MultipartSender, Socket, SocketOption, SocketType},
 };
 
@@ -74,9 +76,9 @@ impl Socket<Stream> {
     /// [`set_router_handover()`]: super::RouterSocket::set_router_handover
     pub fn set_routing_id<V>(&self, value: V) -> ZmqResult<()>
     where
-        V: AsRef<str>,
+        V: Into<RoutingId>,
     {
-        self.set_sockopt_string(SocketOption::RoutingId, value)
+        self.set_sockopt_routing_id(SocketOption::RoutingId, value.into())
     }
 
     /// # Retrieve socket routing id `ZMQ_ROUTING_ID`
@@ -90,8 +92,8 @@ impl Socket<Stream> {
     ///
     /// [`routing_id()`]: #method.routing_id
     /// [`Router`]: super::RouterSocket
-    pub fn routing_id(&self) -> ZmqResult<String> {
-        self.get_sockopt_string(SocketOption::RoutingId)
+    pub fn routing_id(&self) -> ZmqResult<RoutingId> {
+        self.get_sockopt_routing_id(SocketOption::RoutingId)
     }
 
     /// # Assign the next outbound routing id `ZMQ_CONNECT_ROUTING_ID`
@@ -118,9 +120,9 @@ impl Socket<Stream> {
     /// [`set_connect_routing_id()`]: #method.set_connect_routing_id
     pub fn set_connect_routing_id<V>(&self, value: V) -> ZmqResult<()>
     where
-        V: AsRef<str>,
+        V: Into<RoutingId>,
     {
-        self.set_sockopt_string(SocketOption::ConnectRoutingId, value)
+        self.set_sockopt_routing_id(SocketOption::ConnectRoutingId, value.into())
     }
 
     /// # send connect and disconnect notifications `ZMQ_STREAM_NOTIFY`
@@ -146,7 +148,7 @@ mod stream_tests {
 
     use super::StreamSocket;
     use crate::prelude::{
-        Context, MultipartReceiver, MultipartSender, RecvFlags, SendFlags, ZmqResult,
+        Context, MultipartReceiver, MultipartSender, RecvFlags, RoutingId, SendFlags, ZmqResult,
     };
 
     #[test]
@@ -156,7 +158,7 @@ mod stream_tests {
         let socket = StreamSocket::from_context(&context)?;
         socket.set_routing_id("asdf")?;
 
-        assert_eq!(socket.routing_id()?, "asdf");
+        assert_eq!(socket.routing_id()?, RoutingId::from("asdf"));
 
         Ok(())
     }
@@ -189,7 +191,7 @@ mod stream_tests {
 
         let socket = StreamSocket::from_context(&context)?;
         socket.bind("tcp://127.0.0.1:*")?;
-        let tcp_endpoint = socket.last_endpoint()?;
+        let tcp_endpoint = socket.last_endpoint()?.unwrap();
 
         std::thread::spawn(move || {
             let _routing_id = socket.recv_multipart(RecvFlags::empty()).unwrap();
@@ -203,7 +205,7 @@ mod stream_tests {
                 .unwrap();
         });
 
-        let mut tcp_stream = TcpStream::connect(tcp_endpoint.strip_prefix("tcp://").unwrap())?;
+        let mut tcp_stream = TcpStream::connect(("127.0.0.1", tcp_endpoint.port().unwrap()))?;
         tcp_stream.write_all(b"Hello")?;
 
         let mut buffer = [0; 256];
@@ -225,23 +227,24 @@ mod stream_tests {
 
         let socket = StreamSocket::from_context(&context)?;
         socket.bind("tcp://127.0.0.1:*")?;
-        let tcp_endpoint = socket.last_endpoint()?;
+        let tcp_endpoint = socket.last_endpoint()?.unwrap();
 
         std::thread::spawn(move || {
             futures::executor::block_on(async {
-                let _routing_id = socket.recv_multipart_async().await;
-                let mut multipart = socket.recv_multipart_async().await;
+                let _routing_id = socket.recv_multipart_async().await.unwrap();
+                let mut multipart = socket.recv_multipart_async().await.unwrap();
                 let msg = multipart.pop_back().unwrap();
                 assert_eq!(msg.to_string(), "Hello");
 
                 multipart.push_back("World".into());
                 socket
                     .send_multipart_async(multipart, SendFlags::empty())
-                    .await;
+                    .await
+                    .unwrap();
             })
         });
 
-        let mut tcp_stream = TcpStream::connect(tcp_endpoint.strip_prefix("tcp://").unwrap())?;
+        let mut tcp_stream = TcpStream::connect(("127.0.0.1", tcp_endpoint.port().unwrap()))?;
         tcp_stream.write_all(b"Hello")?;
 
         let mut buffer = [0; 256];
@@ -264,7 +267,7 @@ pub(crate) mod builder {
     use serde::{Deserialize, Serialize};
 
     use super::StreamSocket;
-    use crate::{ZmqResult, context::Context, socket::SocketBuilder};
+    use crate::{ZmqError, ZmqResult, context::Context, message::RoutingId, socket::SocketBuilder};
 
     #[derive(Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Builder)]
     #[builder(
@@ -279,9 +282,9 @@ pub(crate) mod builder {
     struct StreamConfig {
         socket_builder: SocketBuilder,
         #[builder(setter(into), default = "Default::default()")]
-        routing_id: String,
+        routing_id: RoutingId,
         #[builder(setter(into), default = "Default::default()")]
-        connect_routing_id: String,
+        connect_routing_id: RoutingId,
         #[cfg(feature = "draft-api")]
         #[builder(default = false)]
         stream_notify: bool,
@@ -320,10 +323,23 @@ pub(crate) mod builder {
         }
     }
 
+    impl TryFrom<&StreamSocket> for StreamBuilder {
+        type Error = ZmqError;
+
+        /// Reads back all options of the socket that can be retrieved.
+        fn try_from(socket: &StreamSocket) -> Result<Self, Self::Error> {
+            Ok(Self {
+                socket_builder: Some(SocketBuilder::try_from(socket)?),
+                routing_id: Some(socket.routing_id()?).filter(RoutingId::is_valid),
+                ..Default::default()
+            })
+        }
+    }
+
     #[cfg(test)]
     mod stream_builder_tests {
         use super::StreamBuilder;
-        use crate::prelude::{Context, SocketBuilder, ZmqResult};
+        use crate::prelude::{Context, RoutingId, SocketBuilder, ZmqResult};
 
         #[test]
         fn default_stream_builder() -> ZmqResult<()> {
@@ -331,7 +347,7 @@ pub(crate) mod builder {
 
             let socket = StreamBuilder::default().build_from_context(&context)?;
 
-            assert_eq!(socket.routing_id()?, "");
+            assert_eq!(socket.routing_id()?, RoutingId::default());
 
             Ok(())
         }
@@ -350,7 +366,7 @@ pub(crate) mod builder {
 
             let socket = builder.build_from_context(&context)?;
 
-            assert_eq!(socket.routing_id()?, "asdf");
+            assert_eq!(socket.routing_id()?, RoutingId::from("asdf"));
 
             Ok(())
         }
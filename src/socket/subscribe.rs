@@ -268,7 +268,7 @@ mod subscribe_tests {
 
         let publish = PublishSocket::from_context(&context)?;
         publish.bind("tcp://127.0.0.1:*")?;
-        let subscribe_endpoint = publish.last_endpoint()?;
+        let subscribe_endpoint = publish.last_endpoint()?.unwrap();
 
         std::thread::spawn(move || {
             loop {
@@ -294,14 +294,15 @@ mod subscribe_tests {
 
         let publish = PublishSocket::from_context(&context)?;
         publish.bind("tcp://127.0.0.1:*")?;
-        let subscribe_endpoint = publish.last_endpoint()?;
+        let subscribe_endpoint = publish.last_endpoint()?.unwrap();
 
         std::thread::spawn(move || {
             futures::executor::block_on(async {
                 loop {
                     publish
                         .send_msg_async("topic asdf", SendFlags::empty())
-                        .await;
+                        .await
+                        .unwrap();
                 }
             })
         });
@@ -311,12 +312,9 @@ mod subscribe_tests {
         subscribe.subscribe("topic")?;
 
         futures::executor::block_on(async {
-            loop {
-                if let Some(msg) = subscribe.recv_msg_async().await {
-                    assert_eq!(msg.to_string().split_once(' ').unwrap(), ("topic", "asdf"));
-                    break;
-                }
-            }
+            let msg = subscribe.recv_msg_async().await?;
+            assert_eq!(msg.to_string().split_once(' ').unwrap(), ("topic", "asdf"));
+
             Ok(())
         })
     }
@@ -330,7 +328,7 @@ pub(crate) mod builder {
     use serde::{Deserialize, Serialize};
 
     use super::SubscribeSocket;
-    use crate::{ZmqResult, context::Context, socket::SocketBuilder};
+    use crate::{ZmqError, ZmqResult, context::Context, socket::SocketBuilder};
 
     #[derive(Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Builder)]
     #[builder(
@@ -382,6 +380,20 @@ pub(crate) mod builder {
         }
     }
 
+    impl TryFrom<&SubscribeSocket> for SubscribeBuilder {
+        type Error = ZmqError;
+
+        /// Reads back all options of the socket that can be retrieved.
+        fn try_from(socket: &SubscribeSocket) -> Result<Self, Self::Error> {
+            Ok(Self {
+                socket_builder: Some(SocketBuilder::try_from(socket)?),
+                conflate: Some(socket.conflate()?),
+                invert_matching: Some(socket.invert_matching()?),
+                ..Default::default()
+            })
+        }
+    }
+
     #[cfg(test)]
     mod subscribe_builder_tests {
         use super::SubscribeBuilder;
@@ -415,5 +427,25 @@ pub(crate) mod builder {
 
             Ok(())
         }
+
+        #[test]
+        fn subscribe_builder_from_socket() -> ZmqResult<()> {
+            let context = Context::new()?;
+
+            let socket = SubscribeBuilder::default()
+                .socket_builder(SocketBuilder::default().linger(42))
+                .conflate(true)
+                .subscribe("topic")
+                .build_from_context(&context)?;
+
+            let builder = SubscribeBuilder::try_from(&socket)?;
+            let other_socket = builder.build_from_context(&context)?;
+
+            assert_eq!(other_socket.linger()?, 42);
+            assert!(other_socket.conflate()?);
+            assert!(!other_socket.invert_matching()?);
+
+            Ok(())
+        }
     }
 }
@@ -1,5 +1,158 @@
-use super::{MultipartReceiver, MultipartSender, Socket, SocketOption, SocketType};
-use crate::{ZmqResult, sealed};
+use alloc::collections::{BTreeMap, btree_map::Entry};
+
+use super::{
+    MultipartReceiver, MultipartSender, Receiver, RecvFlags, Socket, SocketOption, SocketType,
+};
+use crate::{ZmqError, ZmqResult, ffi::RawMessage, message::Message, sealed};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// # Subscription message of [`XPublish`] and [`XSubscribe`] sockets
+///
+/// Subscription messages consist of a byte 1 (for subscriptions) or byte 0 (for unsubscriptions)
+/// followed by the topic. [`XPublish`] sockets receive them from their peers through
+/// [`recv_subscription()`], and [`XSubscribe`] sockets send them through
+/// [`send_subscription()`].
+///
+/// [`XPublish`]: XPublishSocket
+/// [`XSubscribe`]: super::XSubscribeSocket
+/// [`recv_subscription()`]: XPublishSocket::recv_subscription
+/// [`send_subscription()`]: super::XSubscribeSocket::send_subscription
+pub enum SubscriptionEvent {
+    /// subscription to the topic
+    Subscribe(Vec<u8>),
+    /// unsubscription from the topic
+    Unsubscribe(Vec<u8>),
+}
+
+impl SubscriptionEvent {
+    /// The topic of the subscription message.
+    pub fn topic(&self) -> &[u8] {
+        match self {
+            Self::Subscribe(topic) | Self::Unsubscribe(topic) => topic,
+        }
+    }
+
+    /// Encodes the event into the bytes of a subscription message.
+    pub fn encode(&self) -> Vec<u8> {
+        let prefix = match self {
+            Self::Subscribe(_) => 1,
+            Self::Unsubscribe(_) => 0,
+        };
+
+        let mut bytes = Vec::with_capacity(self.topic().len() + 1);
+        bytes.push(prefix);
+        bytes.extend_from_slice(self.topic());
+        bytes
+    }
+
+    /// Decodes the bytes of a subscription message. Messages without a sub/unsub prefix are
+    /// rejected with [`InvalidArgument`].
+    ///
+    /// [`InvalidArgument`]: ZmqError::InvalidArgument
+    pub fn decode(bytes: &[u8]) -> ZmqResult<Self> {
+        match bytes.split_first() {
+            Some((1, topic)) => Ok(Self::Subscribe(topic.to_vec())),
+            Some((0, topic)) => Ok(Self::Unsubscribe(topic.to_vec())),
+            _ => Err(ZmqError::InvalidArgument),
+        }
+    }
+}
+
+impl TryFrom<&Message> for SubscriptionEvent {
+    type Error = ZmqError;
+
+    fn try_from(value: &Message) -> Result<Self, Self::Error> {
+        Self::decode(&value.as_slice())
+    }
+}
+
+impl From<SubscriptionEvent> for RawMessage {
+    fn from(value: SubscriptionEvent) -> Self {
+        RawMessage::from(value.encode())
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+/// # Active topics of an [`XPublish`] socket
+///
+/// Tracks the active topics with their reference counts from the received
+/// [`SubscriptionEvent`]s, independent of whether they were received blocking or async. As
+/// [`XPublish`] sockets only pass on the first subscription and the last unsubscription of a topic
+/// by default, the reference counts reflect the number of subscribers only with
+/// [`set_verboser()`] enabled.
+///
+/// ```
+/// # use arzmq::socket::{SubscriptionEvent, Subscriptions};
+/// #
+/// let mut subscriptions = Subscriptions::default();
+///
+/// assert_eq!(subscriptions.track(&SubscriptionEvent::Subscribe(b"topic".to_vec())), 1);
+/// assert_eq!(subscriptions.track(&SubscriptionEvent::Subscribe(b"topic".to_vec())), 2);
+/// assert_eq!(subscriptions.track(&SubscriptionEvent::Unsubscribe(b"topic".to_vec())), 1);
+/// assert!(subscriptions.is_active(b"topic"));
+/// ```
+///
+/// [`XPublish`]: XPublishSocket
+/// [`set_verboser()`]: XPublishSocket::set_verboser
+pub struct Subscriptions {
+    topics: BTreeMap<Vec<u8>, usize>,
+}
+
+impl Subscriptions {
+    /// Applies the event, and returns the resulting reference count of its topic.
+    /// Unsubscriptions from inactive topics are ignored.
+    pub fn track(&mut self, event: &SubscriptionEvent) -> usize {
+        match (event, self.topics.entry(event.topic().to_vec())) {
+            (SubscriptionEvent::Subscribe(_), Entry::Occupied(mut entry)) => {
+                *entry.get_mut() += 1;
+                *entry.get()
+            }
+            (SubscriptionEvent::Subscribe(_), Entry::Vacant(entry)) => *entry.insert(1),
+            (SubscriptionEvent::Unsubscribe(_), Entry::Occupied(mut entry)) => {
+                if *entry.get() <= 1 {
+                    entry.remove();
+                    return 0;
+                }
+                *entry.get_mut() -= 1;
+                *entry.get()
+            }
+            (SubscriptionEvent::Unsubscribe(_), Entry::Vacant(_)) => 0,
+        }
+    }
+
+    /// The reference count of the topic.
+    pub fn count<V>(&self, topic: V) -> usize
+    where
+        V: AsRef<[u8]>,
+    {
+        self.topics.get(topic.as_ref()).copied().unwrap_or_default()
+    }
+
+    /// Whether the topic has at least one subscription.
+    pub fn is_active<V>(&self, topic: V) -> bool
+    where
+        V: AsRef<[u8]>,
+    {
+        self.topics.contains_key(topic.as_ref())
+    }
+
+    /// Iterates over the active topics and their reference counts.
+    pub fn iter(&self) -> impl Iterator<Item = (&[u8], usize)> {
+        self.topics
+            .iter()
+            .map(|(topic, count)| (topic.as_slice(), *count))
+    }
+
+    /// The number of active topics.
+    pub fn len(&self) -> usize {
+        self.topics.len()
+    }
+
+    /// Whether there are no active topics.
+    pub fn is_empty(&self) -> bool {
+        self.topics.is_empty()
+    }
+}
 
 /// # A XSubscriber socket `ZMQ_XPUB`
 ///
@@ -38,6 +191,33 @@ impl MultipartSender for Socket<XPublish> {}
 impl MultipartReceiver for Socket<XPublish> {}
 
 impl Socket<XPublish> {
+    /// # Receive a subscription message
+    ///
+    /// Receives the next message, and decodes it into a [`SubscriptionEvent`]. Messages without a
+    /// sub/unsub prefix are rejected with [`InvalidArgument`], use [`recv_msg()`] with
+    /// [`SubscriptionEvent::decode()`] to handle them as well.
+    ///
+    /// [`InvalidArgument`]: ZmqError::InvalidArgument
+    /// [`recv_msg()`]: #method.recv_msg
+    pub fn recv_subscription<F>(&self, flags: F) -> ZmqResult<SubscriptionEvent>
+    where
+        F: Into<RecvFlags> + Copy,
+    {
+        let msg = self.recv_msg(flags)?;
+        SubscriptionEvent::try_from(&msg)
+    }
+
+    /// # Receive a subscription message
+    ///
+    /// This is the async variant of [`recv_subscription()`].
+    ///
+    /// [`recv_subscription()`]: #method.recv_subscription
+    #[cfg(feature = "futures")]
+    pub async fn recv_subscription_async(&self) -> ZmqResult<SubscriptionEvent> {
+        let msg = self.recv_msg_async().await?;
+        SubscriptionEvent::try_from(&msg)
+    }
+
     /// # Establish message filter `ZMQ_SUBSCRIBE`
     ///
     /// The [`subscribe()`] option shall establish a new message filter on a [`XPublish`] socket
@@ -53,6 +233,21 @@ impl Socket<XPublish> {
         self.set_sockopt_bytes(SocketOption::Subscribe, topic.as_ref())
     }
 
+    /// # Remove message filter `ZMQ_UNSUBSCRIBE`
+    ///
+    /// The [`unsubscribe()`] option shall remove an existing message filter on a [`XPublish`]
+    /// socket if subscription management is set to manual via [`set_manual()`].
+    ///
+    /// [`XPublish`]: XPublishSocket
+    /// [`set_manual()`]: #method.set_manual
+    /// [`unsubscribe()`]: #method.unsubscribe
+    pub fn unsubscribe<V>(&self, topic: V) -> ZmqResult<()>
+    where
+        V: AsRef<[u8]>,
+    {
+        self.set_sockopt_bytes(SocketOption::Unsubscribe, topic.as_ref())
+    }
+
     /// Invert message filtering `ZMQ_INVERT_MATCHING`
     /// Reverses the filtering behavior of [`Publish`]-[`Subscribe`] sockets, when set to `true`.
     ///
@@ -220,9 +415,10 @@ impl Socket<XPublish> {
 
 #[cfg(test)]
 mod xpublish_tests {
-    use super::XPublishSocket;
+    use super::{SubscriptionEvent, Subscriptions, XPublishSocket};
     use crate::prelude::{
-        Context, Receiver, RecvFlags, SendFlags, Sender, SubscribeSocket, ZmqResult,
+        Context, Message, Receiver, RecvFlags, SendFlags, Sender, SubscribeSocket,
+        XSubscribeSocket, ZmqError, ZmqResult,
     };
 
     #[test]
@@ -231,7 +427,7 @@ mod xpublish_tests {
 
         let xpublish = XPublishSocket::from_context(&context)?;
         xpublish.bind("tcp://127.0.0.1:*")?;
-        let subscribe_endpoint = xpublish.last_endpoint()?;
+        let subscribe_endpoint = xpublish.last_endpoint()?.unwrap();
         xpublish.set_manual(true)?;
 
         std::thread::spawn(move || {
@@ -255,6 +451,25 @@ mod xpublish_tests {
         Ok(())
     }
 
+    #[test]
+    fn unsubscribe_removes_manual_subscriptions() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let xpublish = XPublishSocket::from_context(&context)?;
+        xpublish.set_manual(true)?;
+        xpublish.bind("inproc://xpublish-unsubscribe")?;
+
+        let subscribe = SubscribeSocket::from_context(&context)?;
+        subscribe.connect("inproc://xpublish-unsubscribe")?;
+        subscribe.subscribe("topic")?;
+
+        let event = xpublish.recv_subscription(RecvFlags::empty())?;
+        xpublish.subscribe(event.topic())?;
+        xpublish.unsubscribe(event.topic())?;
+
+        Ok(())
+    }
+
     #[test]
     fn set_invert_matching_sets_invert_matching() -> ZmqResult<()> {
         let context = Context::new()?;
@@ -360,7 +575,7 @@ mod xpublish_tests {
 
         let xpublish = XPublishSocket::from_context(&context)?;
         xpublish.bind("tcp://127.0.0.1:*")?;
-        let subscribe_endpoint = xpublish.last_endpoint()?;
+        let subscribe_endpoint = xpublish.last_endpoint()?.unwrap();
         xpublish.set_manual(true)?;
 
         let subscribe = SubscribeSocket::from_context(&context)?;
@@ -388,6 +603,125 @@ mod xpublish_tests {
 
         Ok(())
     }
+
+    #[test]
+    fn subscription_event_round_trips_through_message() -> ZmqResult<()> {
+        let event = SubscriptionEvent::Unsubscribe(b"topic".to_vec());
+
+        let msg: Message = event.clone().into();
+        assert_eq!(msg.bytes(), b"\x00topic");
+        assert_eq!(SubscriptionEvent::try_from(&msg)?, event);
+
+        Ok(())
+    }
+
+    #[test]
+    fn subscription_event_rejects_messages_without_prefix() {
+        let msg: Message = "topic".into();
+
+        assert_eq!(
+            SubscriptionEvent::try_from(&msg),
+            Err(ZmqError::InvalidArgument)
+        );
+        assert_eq!(
+            SubscriptionEvent::decode(&[]),
+            Err(ZmqError::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn subscriptions_track_reference_counts() {
+        let mut subscriptions = Subscriptions::default();
+
+        assert_eq!(
+            subscriptions.track(&SubscriptionEvent::Subscribe(b"topic".to_vec())),
+            1
+        );
+        assert_eq!(
+            subscriptions.track(&SubscriptionEvent::Subscribe(b"topic".to_vec())),
+            2
+        );
+        assert_eq!(
+            subscriptions.track(&SubscriptionEvent::Subscribe(b"other".to_vec())),
+            1
+        );
+        assert_eq!(subscriptions.len(), 2);
+
+        assert_eq!(
+            subscriptions.track(&SubscriptionEvent::Unsubscribe(b"topic".to_vec())),
+            1
+        );
+        assert_eq!(
+            subscriptions.track(&SubscriptionEvent::Unsubscribe(b"topic".to_vec())),
+            0
+        );
+        assert!(!subscriptions.is_active(b"topic"));
+        assert_eq!(
+            subscriptions.track(&SubscriptionEvent::Unsubscribe(b"unknown".to_vec())),
+            0
+        );
+
+        assert_eq!(
+            subscriptions.iter().collect::<Vec<_>>(),
+            vec![(b"other".as_slice(), 1)]
+        );
+    }
+
+    #[test]
+    fn recv_subscription_receives_subscription_events() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let xpublish = XPublishSocket::from_context(&context)?;
+        xpublish.set_verboser(true)?;
+        xpublish.bind("inproc://xpublish-recv-subscription")?;
+
+        let xsubscribe1 = XSubscribeSocket::from_context(&context)?;
+        xsubscribe1.connect("inproc://xpublish-recv-subscription")?;
+        let xsubscribe2 = XSubscribeSocket::from_context(&context)?;
+        xsubscribe2.connect("inproc://xpublish-recv-subscription")?;
+
+        let mut subscriptions = Subscriptions::default();
+
+        xsubscribe1.send_subscription(
+            SubscriptionEvent::Subscribe(b"topic".to_vec()),
+            SendFlags::empty(),
+        )?;
+        let event = xpublish.recv_subscription(RecvFlags::empty())?;
+        assert_eq!(event, SubscriptionEvent::Subscribe(b"topic".to_vec()));
+        subscriptions.track(&event);
+
+        xsubscribe2.subscribe("topic")?;
+        subscriptions.track(&xpublish.recv_subscription(RecvFlags::empty())?);
+        assert_eq!(subscriptions.count("topic"), 2);
+
+        xsubscribe1.unsubscribe("topic")?;
+        let event = xpublish.recv_subscription(RecvFlags::empty())?;
+        assert_eq!(event, SubscriptionEvent::Unsubscribe(b"topic".to_vec()));
+        assert_eq!(subscriptions.track(&event), 1);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "futures")]
+    #[test]
+    fn recv_subscription_async_receives_subscription_events() -> ZmqResult<()> {
+        let context = Context::new()?;
+
+        let xpublish = XPublishSocket::from_context(&context)?;
+        xpublish.bind("inproc://xpublish-recv-subscription-async")?;
+
+        let xsubscribe = XSubscribeSocket::from_context(&context)?;
+        xsubscribe.connect("inproc://xpublish-recv-subscription-async")?;
+
+        futures::executor::block_on(async {
+            xsubscribe.subscribe_async("topic").await?;
+
+            let event = xpublish.recv_subscription_async().await?;
+            assert_eq!(event, SubscriptionEvent::Subscribe(b"topic".to_vec()));
+
+            Ok(())
+        })
+    }
 }
 
 #[cfg(feature = "builder")]
@@ -398,7 +732,7 @@ pub(crate) mod builder {
     use serde::{Deserialize, Serialize};
 
     use super::XPublishSocket;
-    use crate::{ZmqResult, context::Context, socket::SocketBuilder};
+    use crate::{ZmqError, ZmqResult, context::Context, socket::SocketBuilder};
 
     #[derive(Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Builder)]
     #[builder(
@@ -490,6 +824,19 @@ pub(crate) mod builder {
         }
     }
 
+    impl TryFrom<&XPublishSocket> for XPublishBuilder {
+        type Error = ZmqError;
+
+        /// Reads back all options of the socket that can be retrieved.
+        fn try_from(socket: &XPublishSocket) -> Result<Self, Self::Error> {
+            Ok(Self {
+                socket_builder: Some(SocketBuilder::try_from(socket)?),
+                invert_matching: Some(socket.invert_matching()?),
+                ..Default::default()
+            })
+        }
+    }
+
     #[cfg(test)]
     mod xpublish_builder_tests {
         use super::XPublishBuilder;
@@ -1,6 +1,8 @@
 #[cfg(feature = "draft-api")]
 use super::SocketOption;
-use super::{MultipartReceiver, MultipartSender, SendFlags, Sender, Socket, SocketType};
+use super::{
+    MultipartReceiver, MultipartSender, SendFlags, Sender, Socket, SocketType, SubscriptionEvent,
+};
 use crate::{ZmqResult, sealed};
 
 /// # A XSubscribe socket `ZMQ_XSUB`
@@ -67,9 +69,10 @@ impl Socket<XSubscribe> {
     where
         V: AsRef<[u8]>,
     {
-        let mut byte_string = vec![1];
-        byte_string.extend_from_slice(topic.as_ref());
-        self.send_msg(byte_string, SendFlags::empty())
+        self.send_subscription(
+            SubscriptionEvent::Subscribe(topic.as_ref().to_vec()),
+            SendFlags::empty(),
+        )
     }
 
     /// # Establish message filter `ZMQ_SUBSCRIBE`
@@ -78,13 +81,15 @@ impl Socket<XSubscribe> {
     ///
     /// [`subscribe()`]: #method.subscribe
     #[cfg(feature = "futures")]
-    pub async fn subscribe_async<V>(&self, topic: V)
+    pub async fn subscribe_async<V>(&self, topic: V) -> ZmqResult<()>
     where
         V: AsRef<[u8]>,
     {
-        let mut byte_string = vec![1];
-        byte_string.extend_from_slice(topic.as_ref());
-        self.send_msg_async(byte_string, SendFlags::empty()).await;
+        self.send_subscription_async(
+            SubscriptionEvent::Subscribe(topic.as_ref().to_vec()),
+            SendFlags::empty(),
+        )
+        .await
     }
 
     /// # Remove message filter `ZMQ_UNSUBSCRIBE`
@@ -102,9 +107,10 @@ impl Socket<XSubscribe> {
     where
         V: AsRef<[u8]>,
     {
-        let mut byte_string = vec![0];
-        byte_string.extend_from_slice(topic.as_ref());
-        self.send_msg(byte_string, SendFlags::empty())
+        self.send_subscription(
+            SubscriptionEvent::Unsubscribe(topic.as_ref().to_vec()),
+            SendFlags::empty(),
+        )
     }
 
     /// # Remove message filter `ZMQ_UNSUBSCRIBE`
@@ -113,13 +119,45 @@ impl Socket<XSubscribe> {
     ///
     /// [`unsubscribe()`]: #method.unsubscribe
     #[cfg(feature = "futures")]
-    pub async fn unsubscribe_async<V>(&self, topic: V)
+    pub async fn unsubscribe_async<V>(&self, topic: V) -> ZmqResult<()>
     where
         V: AsRef<[u8]>,
     {
-        let mut byte_string = vec![0];
-        byte_string.extend_from_slice(topic.as_ref());
-        self.send_msg_async(byte_string, SendFlags::empty()).await;
+        self.send_subscription_async(
+            SubscriptionEvent::Unsubscribe(topic.as_ref().to_vec()),
+            SendFlags::empty(),
+        )
+        .await
+    }
+
+    /// # Send a subscription message
+    ///
+    /// Encodes the [`SubscriptionEvent`] into a subscription message, and sends it to the
+    /// connected [`XPublish`] sockets.
+    ///
+    /// [`XPublish`]: super::XPublishSocket
+    pub fn send_subscription<F>(&self, event: SubscriptionEvent, flags: F) -> ZmqResult<()>
+    where
+        F: Into<SendFlags> + Copy,
+    {
+        self.send_msg(event, flags)
+    }
+
+    /// # Send a subscription message
+    ///
+    /// This is the async variant of [`send_subscription()`].
+    ///
+    /// [`send_subscription()`]: #method.send_subscription
+    #[cfg(feature = "futures")]
+    pub async fn send_subscription_async<F>(
+        &self,
+        event: SubscriptionEvent,
+        flags: F,
+    ) -> ZmqResult<()>
+    where
+        F: Into<SendFlags> + Copy + Send,
+    {
+        self.send_msg_async(event, flags).await
     }
 
     /// # Number of topic subscriptions received `ZMQ_TOPICS_COUNT`
@@ -166,7 +204,7 @@ mod xsubscribe_tests {
 
         let publish = PublishSocket::from_context(&context)?;
         publish.bind("tcp://127.0.0.1:*")?;
-        let xsubscribe_endpoint = publish.last_endpoint()?;
+        let xsubscribe_endpoint = publish.last_endpoint()?.unwrap();
 
         std::thread::spawn(move || {
             loop {
@@ -223,7 +261,7 @@ mod xsubscribe_tests {
 
         let publish = PublishSocket::from_context(&context)?;
         publish.bind("tcp://127.0.0.1:*")?;
-        let xsubscribe_endpoint = publish.last_endpoint()?;
+        let xsubscribe_endpoint = publish.last_endpoint()?.unwrap();
 
         std::thread::spawn(move || {
             loop {
@@ -259,7 +297,7 @@ mod xsubscribe_tests {
 
         let xpublish = XPublishSocket::from_context(&context)?;
         xpublish.bind("tcp://127.0.0.1:*")?;
-        let xsubscribe_endpoint = xpublish.last_endpoint()?;
+        let xsubscribe_endpoint = xpublish.last_endpoint()?.unwrap();
 
         std::thread::spawn(move || {
             let msg = xpublish.recv_msg(RecvFlags::empty()).unwrap();
@@ -288,22 +326,19 @@ mod xsubscribe_tests {
 
         let xpublish = XPublishSocket::from_context(&context)?;
         xpublish.bind("tcp://127.0.0.1:*")?;
-        let xsubscribe_endpoint = xpublish.last_endpoint()?;
+        let xsubscribe_endpoint = xpublish.last_endpoint()?.unwrap();
 
         std::thread::spawn(move || {
             futures::executor::block_on(async {
-                loop {
-                    if let Some(msg) = xpublish.recv_msg_async().await {
-                        assert_eq!(msg.bytes()[0], 1);
-                        assert_eq!(&msg.to_string()[1..], "topic");
-                        break;
-                    }
-                }
+                let msg = xpublish.recv_msg_async().await.unwrap();
+                assert_eq!(msg.bytes()[0], 1);
+                assert_eq!(&msg.to_string()[1..], "topic");
 
                 loop {
                     xpublish
                         .send_msg_async("topic asdf", SendFlags::empty())
-                        .await;
+                        .await
+                        .unwrap();
                 }
             })
         });
@@ -312,18 +347,12 @@ mod xsubscribe_tests {
         xsubscribe.connect(xsubscribe_endpoint)?;
 
         futures::executor::block_on(async {
-            xsubscribe.subscribe_async("topic").await;
-
-            loop {
-                if let Some(msg) = xsubscribe.recv_msg_async().await {
-                    assert_eq!(msg.to_string(), "topic asdf");
-                    break;
-                }
-            }
+            xsubscribe.subscribe_async("topic").await?;
 
-            xsubscribe.unsubscribe_async("topic").await;
+            let msg = xsubscribe.recv_msg_async().await?;
+            assert_eq!(msg.to_string(), "topic asdf");
 
-            Ok(())
+            xsubscribe.unsubscribe_async("topic").await
         })
     }
 }
@@ -336,7 +365,7 @@ pub(crate) mod builder {
     use serde::{Deserialize, Serialize};
 
     use super::XSubscribeSocket;
-    use crate::{ZmqResult, context::Context, socket::SocketBuilder};
+    use crate::{ZmqError, ZmqResult, context::Context, socket::SocketBuilder};
 
     #[derive(Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Builder)]
     #[builder(
@@ -386,6 +415,18 @@ pub(crate) mod builder {
         }
     }
 
+    impl TryFrom<&XSubscribeSocket> for XSubscribeBuilder {
+        type Error = ZmqError;
+
+        /// Reads back all options of the socket that can be retrieved.
+        fn try_from(socket: &XSubscribeSocket) -> Result<Self, Self::Error> {
+            Ok(Self {
+                socket_builder: Some(SocketBuilder::try_from(socket)?),
+                ..Default::default()
+            })
+        }
+    }
+
     #[cfg(test)]
     mod xsubscribe_builder_tests {
         use super::XSubscribeBuilder;
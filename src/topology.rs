@@ -0,0 +1,749 @@
+//! # Declarative topologies
+//!
+//! A [`Topology`] declares a [`Context`] and a set of named sockets, each with its socket type,
+//! the options of the corresponding builder, the endpoints to bind and connect to, and an
+//! optional [`SecurityMechanism`]. As the topology is deserialized with `serde`, the wiring of an
+//! application can be changed through a configuration file without recompiling it.
+//!
+//! [`load()`] creates the context, all sockets, binds them, and connects them afterward. The
+//! resulting [`LoadedTopology`] hands out the sockets by name, either typed through [`take()`],
+//! or as [`AnySocket`] through [`take_any()`].
+//!
+//! Configuration errors, like unknown socket types, invalid option values, and unknown options,
+//! are reported as [`TopologyError`] together with the path of the offending field.
+//!
+//! The configuration format mirrors the serialization of the builders, i.e. in TOML:
+//!
+//! ```toml
+//! [context]
+//! io_threads = 2
+//!
+//! [sockets.sink]
+//! bind = ["tcp://127.0.0.1:5555"]
+//! socket = { pull = { socket_builder = { linger = 0 } } }
+//!
+//! [sockets.source]
+//! connect = ["tcp://127.0.0.1:5555"]
+//! security = { Plain = { username = "user", password = "secret" } }
+//! socket = { push = {} }
+//! ```
+//!
+//! Topologies are read with [`from_deserializer()`] from any `serde` format, or with
+//! `from_toml_str()` and `from_json_str()` behind the `toml` and `json` features. They can be
+//! declared in code as well:
+//!
+//! ```
+//! # use arzmq::{
+//! #     prelude::{ContextBuilder, Receiver, RecvFlags, SendFlags, Sender},
+//! #     socket::{PullSocket, PushSocket},
+//! #     topology::{SocketDefinition, SocketOptions, Topology},
+//! # };
+//! #
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let topology = Topology::default()
+//!         .context(ContextBuilder::default().io_threads(2))
+//!         .socket(
+//!             "sink",
+//!             SocketDefinition::new(SocketOptions::Pull(Default::default()))
+//!                 .bind("inproc://topology-example")?,
+//!         )
+//!         .socket(
+//!             "source",
+//!             SocketDefinition::new(SocketOptions::Push(Default::default()))
+//!                 .connect("inproc://topology-example")?,
+//!         );
+//!
+//!     let mut sockets = topology.load()?;
+//!     let sink: PullSocket = sockets.take("sink")?;
+//!     let source: PushSocket = sockets.take("source")?;
+//!
+//!     source.send_msg("Hello", SendFlags::empty())?;
+//!     assert_eq!(sink.recv_msg(RecvFlags::empty())?.to_string(), "Hello");
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! [`from_deserializer()`]: Topology::from_deserializer
+//! [`load()`]: Topology::load
+//! [`take()`]: LoadedTopology::take
+//! [`take_any()`]: LoadedTopology::take_any
+use alloc::collections::BTreeMap;
+use core::fmt::Display;
+
+use serde::{Deserialize, Deserializer, Serialize};
+use thiserror::Error;
+
+#[cfg(feature = "draft-api")]
+use crate::socket::{
+    ChannelBuilder, ChannelSocket, ClientBuilder, ClientSocket, DatagramBuilder, DatagramSocket,
+    DishBuilder, DishSocket, GatherBuilder, GatherSocket, PeerBuilder, PeerSocket, RadioBuilder,
+    RadioSocket, ScatterBuilder, ScatterSocket, ServerBuilder, ServerSocket,
+};
+use crate::{
+    ZmqError,
+    context::{Context, ContextBuilder},
+    endpoint::Endpoint,
+    security::SecurityMechanism,
+    socket::{
+        DealerBuilder, DealerSocket, PairBuilder, PairSocket, PublishBuilder, PublishSocket,
+        PullBuilder, PullSocket, PushBuilder, PushSocket, ReplyBuilder, ReplySocket,
+        RequestBuilder, RequestSocket, RouterBuilder, RouterSocket, SocketType, StreamBuilder,
+        StreamSocket, SubscribeBuilder, SubscribeSocket, XPublishBuilder, XPublishSocket,
+        XSubscribeBuilder, XSubscribeSocket,
+    },
+};
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+/// Errors of loading a topology
+pub enum TopologyError {
+    /// the configuration could not be deserialized
+    #[error("Invalid configuration at `{path}`: {message}")]
+    InvalidConfiguration {
+        /// path of the offending field
+        path: String,
+        /// description of the error
+        message: String,
+    },
+    /// the configuration contains an option that is not known
+    #[error("Unknown option at `{path}`")]
+    UnknownOption {
+        /// path of the unknown option
+        path: String,
+    },
+    /// creating, configuring, binding or connecting a socket failed
+    #[error("Configuring `{path}` failed: {error}")]
+    Zmq {
+        /// path of the failing part of the configuration
+        path: String,
+        /// the underlying error
+        error: ZmqError,
+    },
+    /// no socket with the requested name was declared, or it was already taken
+    #[error("No socket named `{0}`")]
+    UnknownSocket(String),
+    /// the socket with the requested name has a different socket type
+    #[error("Socket `{name}` is of type {socket_type:?}")]
+    SocketTypeMismatch {
+        /// name of the socket
+        name: String,
+        /// declared socket type
+        socket_type: SocketType,
+    },
+}
+
+impl TopologyError {
+    fn zmq<P: Display>(path: P) -> impl FnOnce(ZmqError) -> Self {
+        move |error| Self::Zmq {
+            path: path.to_string(),
+            error,
+        }
+    }
+}
+
+macro_rules! any_socket {
+    ($($(#[$meta:meta])* $variant:ident($socket:ident, $builder:ident),)+) => {
+        #[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+        #[serde(rename_all = "lowercase")]
+        #[non_exhaustive]
+        /// Socket type and builder options of a [`SocketDefinition`]
+        pub enum SocketOptions {
+            $(
+                $(#[$meta])*
+                #[doc = concat!("[`", stringify!($socket), "`] configured through a [`", stringify!($builder), "`]")]
+                $variant($builder),
+            )+
+        }
+
+        impl SocketOptions {
+            /// The socket type of these options.
+            pub fn socket_type(&self) -> SocketType {
+                match self {
+                    $(
+                        $(#[$meta])*
+                        Self::$variant(_) => SocketType::$variant,
+                    )+
+                }
+            }
+
+            fn build_from_context(self, context: &Context) -> Result<AnySocket, ZmqError> {
+                match self {
+                    $(
+                        $(#[$meta])*
+                        Self::$variant(builder) => {
+                            let socket: $socket = builder.build_from_context(context)?;
+                            Ok(AnySocket::$variant(socket))
+                        }
+                    )+
+                }
+            }
+        }
+
+        #[non_exhaustive]
+        /// # Socket of any type
+        ///
+        /// Returned by [`take_any()`], and converts into the typed socket with [`TryFrom`].
+        ///
+        /// [`take_any()`]: LoadedTopology::take_any
+        pub enum AnySocket {
+            $(
+                $(#[$meta])*
+                #[doc = concat!("[`", stringify!($socket), "`]")]
+                $variant($socket),
+            )+
+        }
+
+        impl AnySocket {
+            /// The socket type of the wrapped socket.
+            pub fn socket_type(&self) -> SocketType {
+                match self {
+                    $(
+                        $(#[$meta])*
+                        Self::$variant(_) => SocketType::$variant,
+                    )+
+                }
+            }
+
+            fn bind(&self, endpoint: &Endpoint) -> Result<(), ZmqError> {
+                match self {
+                    $(
+                        $(#[$meta])*
+                        Self::$variant(socket) => socket.bind(endpoint),
+                    )+
+                }
+            }
+
+            fn connect(&self, endpoint: &Endpoint) -> Result<(), ZmqError> {
+                match self {
+                    $(
+                        $(#[$meta])*
+                        Self::$variant(socket) => socket.connect(endpoint),
+                    )+
+                }
+            }
+
+            fn set_security_mechanism(
+                &self,
+                security_mechanism: &SecurityMechanism,
+            ) -> Result<(), ZmqError> {
+                match self {
+                    $(
+                        $(#[$meta])*
+                        Self::$variant(socket) => socket.set_security_mechanism(security_mechanism),
+                    )+
+                }
+            }
+        }
+
+        $(
+            $(#[$meta])*
+            impl From<$socket> for AnySocket {
+                fn from(value: $socket) -> Self {
+                    Self::$variant(value)
+                }
+            }
+
+            $(#[$meta])*
+            impl TryFrom<AnySocket> for $socket {
+                type Error = AnySocket;
+
+                fn try_from(value: AnySocket) -> Result<Self, Self::Error> {
+                    match value {
+                        AnySocket::$variant(socket) => Ok(socket),
+                        #[allow(unreachable_patterns)]
+                        other => Err(other),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+any_socket! {
+    Pair(PairSocket, PairBuilder),
+    Publish(PublishSocket, PublishBuilder),
+    Subscribe(SubscribeSocket, SubscribeBuilder),
+    Request(RequestSocket, RequestBuilder),
+    Reply(ReplySocket, ReplyBuilder),
+    Dealer(DealerSocket, DealerBuilder),
+    Router(RouterSocket, RouterBuilder),
+    Pull(PullSocket, PullBuilder),
+    Push(PushSocket, PushBuilder),
+    XPublish(XPublishSocket, XPublishBuilder),
+    XSubscribe(XSubscribeSocket, XSubscribeBuilder),
+    Stream(StreamSocket, StreamBuilder),
+    #[cfg(feature = "draft-api")]
+    Server(ServerSocket, ServerBuilder),
+    #[cfg(feature = "draft-api")]
+    Client(ClientSocket, ClientBuilder),
+    #[cfg(feature = "draft-api")]
+    Radio(RadioSocket, RadioBuilder),
+    #[cfg(feature = "draft-api")]
+    Dish(DishSocket, DishBuilder),
+    #[cfg(feature = "draft-api")]
+    Gather(GatherSocket, GatherBuilder),
+    #[cfg(feature = "draft-api")]
+    Scatter(ScatterSocket, ScatterBuilder),
+    #[cfg(feature = "draft-api")]
+    Peer(PeerSocket, PeerBuilder),
+    #[cfg(feature = "draft-api")]
+    Channel(ChannelSocket, ChannelBuilder),
+    #[cfg(feature = "draft-api")]
+    Datagram(DatagramSocket, DatagramBuilder),
+}
+
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+/// # Declaration of a single socket
+///
+/// Consists of the socket type with the builder options, the endpoints to bind and connect to,
+/// and an optional [`SecurityMechanism`], that is applied after the builder options.
+pub struct SocketDefinition {
+    socket: SocketOptions,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    bind: Vec<Endpoint>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    connect: Vec<Endpoint>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    security: Option<SecurityMechanism>,
+}
+
+impl SocketDefinition {
+    /// Declares a socket with the provided type and builder options.
+    pub fn new(socket: SocketOptions) -> Self {
+        Self {
+            socket,
+            bind: Vec::new(),
+            connect: Vec::new(),
+            security: None,
+        }
+    }
+
+    /// Adds an endpoint to bind the socket to.
+    pub fn bind<E>(mut self, endpoint: E) -> Result<Self, ZmqError>
+    where
+        E: TryInto<Endpoint>,
+        ZmqError: From<E::Error>,
+    {
+        self.bind.push(endpoint.try_into()?);
+        Ok(self)
+    }
+
+    /// Adds an endpoint to connect the socket to.
+    pub fn connect<E>(mut self, endpoint: E) -> Result<Self, ZmqError>
+    where
+        E: TryInto<Endpoint>,
+        ZmqError: From<E::Error>,
+    {
+        self.connect.push(endpoint.try_into()?);
+        Ok(self)
+    }
+
+    /// Sets the security mechanism of the socket.
+    pub fn security(mut self, security_mechanism: SecurityMechanism) -> Self {
+        self.security = Some(security_mechanism);
+        self
+    }
+
+    /// The socket type of the declared socket.
+    pub fn socket_type(&self) -> SocketType {
+        self.socket.socket_type()
+    }
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+/// # Declaration of a context and its named sockets
+///
+/// See the [module documentation] for the configuration format.
+///
+/// [module documentation]: self
+pub struct Topology {
+    #[serde(default)]
+    context: ContextBuilder,
+    #[serde(default)]
+    sockets: BTreeMap<String, SocketDefinition>,
+}
+
+impl Topology {
+    /// # Reads a topology from any `serde` format
+    ///
+    /// Deserialization errors are reported as [`InvalidConfiguration`], and options that are not
+    /// known for the declared socket type as [`UnknownOption`], both with the path of the
+    /// offending field.
+    ///
+    /// [`InvalidConfiguration`]: TopologyError::InvalidConfiguration
+    /// [`UnknownOption`]: TopologyError::UnknownOption
+    pub fn from_deserializer<'de, D>(deserializer: D) -> Result<Self, TopologyError>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut unknown_options = Vec::new();
+        let mut track = serde_path_to_error::Track::new();
+
+        let deserializer = serde_path_to_error::Deserializer::new(deserializer, &mut track);
+        let result =
+            serde_ignored::deserialize(deserializer, |path| unknown_options.push(path.to_string()));
+
+        let topology: Self = result.map_err(|err| TopologyError::InvalidConfiguration {
+            path: track.path().to_string(),
+            message: err.to_string(),
+        })?;
+
+        match unknown_options.into_iter().next() {
+            Some(path) => Err(TopologyError::UnknownOption { path }),
+            None => Ok(topology),
+        }
+    }
+
+    #[cfg(feature = "toml")]
+    /// Reads a topology from a TOML document, see [`from_deserializer()`].
+    ///
+    /// [`from_deserializer()`]: Self::from_deserializer
+    pub fn from_toml_str(config: &str) -> Result<Self, TopologyError> {
+        let deserializer = toml::Deserializer::parse(config).map_err(|err| {
+            TopologyError::InvalidConfiguration {
+                path: ".".to_string(),
+                message: err.to_string(),
+            }
+        })?;
+
+        Self::from_deserializer(deserializer)
+    }
+
+    #[cfg(feature = "json")]
+    /// Reads a topology from a JSON document, see [`from_deserializer()`].
+    ///
+    /// [`from_deserializer()`]: Self::from_deserializer
+    pub fn from_json_str(config: &str) -> Result<Self, TopologyError> {
+        let mut deserializer = serde_json::Deserializer::from_str(config);
+        let topology = Self::from_deserializer(&mut deserializer)?;
+
+        deserializer
+            .end()
+            .map_err(|err| TopologyError::InvalidConfiguration {
+                path: ".".to_string(),
+                message: err.to_string(),
+            })?;
+
+        Ok(topology)
+    }
+
+    /// Sets the options of the context.
+    pub fn context(mut self, context: ContextBuilder) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Declares a socket with the provided name, replacing a previous declaration.
+    pub fn socket<N>(mut self, name: N, socket: SocketDefinition) -> Self
+    where
+        N: Into<String>,
+    {
+        self.sockets.insert(name.into(), socket);
+        self
+    }
+
+    /// # Creates the context and all declared sockets
+    ///
+    /// Every socket is created with its builder options and security mechanism, and bound to
+    /// its endpoints. Once all sockets are bound, they are connected to their endpoints.
+    pub fn load(&self) -> Result<LoadedTopology, TopologyError> {
+        let context = self
+            .context
+            .clone()
+            .build()
+            .map_err(TopologyError::zmq("context"))?;
+
+        let mut sockets = BTreeMap::new();
+        for (name, definition) in &self.sockets {
+            let path = format!("sockets.{name}");
+
+            let socket = definition
+                .socket
+                .clone()
+                .build_from_context(&context)
+                .map_err(TopologyError::zmq(format!("{path}.socket")))?;
+
+            if let Some(security_mechanism) = &definition.security {
+                socket
+                    .set_security_mechanism(security_mechanism)
+                    .map_err(TopologyError::zmq(format!("{path}.security")))?;
+            }
+
+            definition
+                .bind
+                .iter()
+                .enumerate()
+                .try_for_each(|(index, endpoint)| {
+                    socket
+                        .bind(endpoint)
+                        .map_err(TopologyError::zmq(format!("{path}.bind[{index}]")))
+                })?;
+
+            sockets.insert(name.clone(), socket);
+        }
+
+        for (name, definition) in &self.sockets {
+            let socket = &sockets[name];
+            definition
+                .connect
+                .iter()
+                .enumerate()
+                .try_for_each(|(index, endpoint)| {
+                    socket.connect(endpoint).map_err(TopologyError::zmq(format!(
+                        "sockets.{name}.connect[{index}]"
+                    )))
+                })?;
+        }
+
+        Ok(LoadedTopology { context, sockets })
+    }
+}
+
+/// # Context and sockets of a loaded [`Topology`]
+///
+/// Hands out the sockets by their declared name. Every socket can be taken only once.
+pub struct LoadedTopology {
+    context: Context,
+    sockets: BTreeMap<String, AnySocket>,
+}
+
+impl LoadedTopology {
+    /// The context all sockets were created from.
+    pub fn context(&self) -> &Context {
+        &self.context
+    }
+
+    /// Names of the sockets that were not taken, yet.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.sockets.keys().map(String::as_str)
+    }
+
+    /// Takes the socket with the provided name as [`AnySocket`].
+    pub fn take_any(&mut self, name: &str) -> Result<AnySocket, TopologyError> {
+        self.sockets
+            .remove(name)
+            .ok_or_else(|| TopologyError::UnknownSocket(name.to_string()))
+    }
+
+    /// # Takes the typed socket with the provided name
+    ///
+    /// When the socket was declared with a different socket type, it is kept and a
+    /// [`SocketTypeMismatch`] returned.
+    ///
+    /// [`SocketTypeMismatch`]: TopologyError::SocketTypeMismatch
+    pub fn take<S>(&mut self, name: &str) -> Result<S, TopologyError>
+    where
+        S: TryFrom<AnySocket, Error = AnySocket>,
+    {
+        let socket = self.take_any(name)?;
+        S::try_from(socket).map_err(|socket| {
+            let socket_type = socket.socket_type();
+            self.sockets.insert(name.to_string(), socket);
+            TopologyError::SocketTypeMismatch {
+                name: name.to_string(),
+                socket_type,
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod topology_tests {
+    use super::*;
+    use crate::socket::{Receiver, RecvFlags, SendFlags, Sender, SocketBuilder};
+
+    #[test]
+    fn loads_declared_sockets() -> Result<(), Box<dyn core::error::Error>> {
+        let topology = Topology::default()
+            .context(ContextBuilder::default().io_threads(2))
+            .socket(
+                "sink",
+                SocketDefinition::new(SocketOptions::Pull(
+                    PullBuilder::default().socket_builder(SocketBuilder::default().linger(42)),
+                ))
+                .bind("inproc://topology-loads-declared-sockets")?,
+            )
+            .socket(
+                "source",
+                SocketDefinition::new(SocketOptions::Push(Default::default()))
+                    .connect("inproc://topology-loads-declared-sockets")?
+                    .security(SecurityMechanism::Null),
+            );
+
+        let mut sockets = topology.load()?;
+        assert_eq!(sockets.context().io_threads()?, 2);
+        assert_eq!(sockets.names().collect::<Vec<_>>(), ["sink", "source"]);
+
+        let sink: PullSocket = sockets.take("sink")?;
+        assert_eq!(sink.linger()?, 42);
+
+        let source = sockets.take_any("source")?;
+        assert_eq!(source.socket_type(), SocketType::Push);
+        let source = PushSocket::try_from(source).ok().unwrap();
+
+        source.send_msg("Hello", SendFlags::empty())?;
+        assert_eq!(sink.recv_msg(RecvFlags::empty())?.to_string(), "Hello");
+
+        assert_eq!(
+            sockets.take::<PushSocket>("source").err(),
+            Some(TopologyError::UnknownSocket("source".to_string()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn take_keeps_socket_with_other_type() -> Result<(), Box<dyn core::error::Error>> {
+        let topology = Topology::default().socket(
+            "router",
+            SocketDefinition::new(SocketOptions::Router(Default::default())),
+        );
+
+        let mut sockets = topology.load()?;
+        assert_eq!(
+            sockets.take::<DealerSocket>("router").err(),
+            Some(TopologyError::SocketTypeMismatch {
+                name: "router".to_string(),
+                socket_type: SocketType::Router,
+            })
+        );
+        assert!(sockets.take::<RouterSocket>("router").is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn reports_failing_endpoint_with_path() -> Result<(), Box<dyn core::error::Error>> {
+        let topology = Topology::default()
+            .socket(
+                "first",
+                SocketDefinition::new(SocketOptions::Pair(Default::default()))
+                    .bind("inproc://topology-failing-endpoint")?,
+            )
+            .socket(
+                "second",
+                SocketDefinition::new(SocketOptions::Pair(Default::default()))
+                    .bind("inproc://topology-failing-endpoint")?,
+            );
+
+        assert_eq!(
+            topology.load().err(),
+            Some(TopologyError::Zmq {
+                path: "sockets.second.bind[0]".to_string(),
+                error: ZmqError::AddressInUse,
+            })
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn reads_topology_from_json() -> Result<(), Box<dyn core::error::Error>> {
+        let topology = Topology::from_json_str(
+            r#"{
+                "context": { "io_threads": 2 },
+                "sockets": {
+                    "frontend": {
+                        "socket": { "router": { "router_mandatory": true } },
+                        "bind": ["inproc://topology-from-json"]
+                    }
+                }
+            }"#,
+        )?;
+
+        let expected = Topology::default()
+            .context(ContextBuilder::default().io_threads(2))
+            .socket(
+                "frontend",
+                SocketDefinition::new(SocketOptions::Router(
+                    RouterBuilder::default().router_mandatory(true),
+                ))
+                .bind("inproc://topology-from-json")?,
+            );
+        assert!(topology == expected);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn reports_unknown_socket_type_with_path() {
+        let result = Topology::from_json_str(
+            r#"{ "sockets": { "frontend": { "socket": { "unknown": {} } } } }"#,
+        );
+
+        assert!(matches!(
+            result.err(),
+            Some(TopologyError::InvalidConfiguration { path, .. }) if path == "sockets.frontend.socket"
+        ));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn reports_invalid_option_with_path() {
+        let result = Topology::from_json_str(
+            r#"{ "sockets": { "frontend": { "socket": { "router": { "router_mandatory": 42 } } } } }"#,
+        );
+
+        assert!(matches!(
+            result.err(),
+            Some(TopologyError::InvalidConfiguration { path, .. })
+                if path == "sockets.frontend.socket.router.router_mandatory"
+        ));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn reports_invalid_endpoint_with_path() {
+        let result = Topology::from_json_str(
+            r#"{ "sockets": { "frontend": { "socket": { "pair": {} }, "bind": ["invalid"] } } }"#,
+        );
+
+        assert!(matches!(
+            result.err(),
+            Some(TopologyError::InvalidConfiguration { path, .. })
+                if path == "sockets.frontend.bind[0]"
+        ));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn reports_unknown_option() {
+        let result = Topology::from_json_str(
+            r#"{ "sockets": { "frontend": { "socket": { "pair": { "lingering": 0 } } } } }"#,
+        );
+
+        assert!(matches!(
+            result.err(),
+            Some(TopologyError::UnknownOption { path }) if path.ends_with("lingering")
+        ));
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn reads_topology_from_toml() -> Result<(), Box<dyn core::error::Error>> {
+        let topology = Topology::from_toml_str(
+            r#"
+            [context]
+            io_threads = 2
+
+            [sockets.frontend]
+            bind = ["inproc://topology-from-toml"]
+            socket = { dealer = {} }
+            "#,
+        )?;
+
+        let expected = Topology::default()
+            .context(ContextBuilder::default().io_threads(2))
+            .socket(
+                "frontend",
+                SocketDefinition::new(SocketOptions::Dealer(Default::default()))
+                    .bind("inproc://topology-from-toml")?,
+            );
+        assert!(topology == expected);
+
+        Ok(())
+    }
+}